@@ -0,0 +1,231 @@
+//! Asynchronous readers for programs running on tokio, enabled with the `tokio` feature.
+//!
+//! They work like the readers with the same name at the root of the crate, but stdin is read
+//! with tokio::io::stdin, so waiting for the user does not block the runtime, and no
+//! spawn_blocking is needed:
+//!
+//! ```no_run
+//! # async fn run() {
+//! use quick_input::asynchronous;
+//!
+//! let name = asynchronous::read_string(Some("Name: ")).await;
+//! let age = asynchronous::read_u32(Some("Age: "), Some("Please enter your age.")).await;
+//! # }
+//! ```
+//!
+//! Answers go through the post-processors and dry-run mode works as usual, but prompts are
+//! always printed to stdout: renderers, the protocol mode and Readers are not used. Stdin
+//! should not be read by the synchronous readers while these are in use, since lines
+//! buffered by one side would not be seen by the other.
+
+use std::io;
+use std::str::FromStr;
+use std::sync::OnceLock;
+
+use tokio::io::{AsyncBufRead, AsyncBufReadExt, AsyncWrite, AsyncWriteExt, BufReader, Stdin};
+use tokio::sync::Mutex;
+
+use crate::{dry_run, eof, parse_real, postprocess};
+
+/// Stdin, shared by every reader so the lines buffered by one are not lost by the next.
+static STDIN: OnceLock<Mutex<BufReader<Stdin>>> = OnceLock::new();
+
+/// Works like read_string, without blocking the runtime.
+pub async fn read_string(msg: Option<&str>) -> String {
+    read_until_valid(msg, None, |input| Ok(input.to_string())).await
+}
+
+/// Works like read_char, without blocking the runtime.
+pub async fn read_char(msg: Option<&str>) -> char {
+    read_until_valid(msg, None, |input| {
+        input
+            .chars()
+            .next()
+            .ok_or_else(|| String::from("Please enter a character."))
+    })
+    .await
+}
+
+/// Works like read_bool, without blocking the runtime.
+pub async fn read_bool(msg: Option<&str>, err_msg: Option<&str>) -> bool {
+    read_parsed(
+        msg,
+        err_msg,
+        "Please enter a valid boolean value (true / false).",
+    )
+    .await
+}
+
+/// Works like read_i32, without blocking the runtime.
+pub async fn read_i32(msg: Option<&str>, err_msg: Option<&str>) -> i32 {
+    read_parsed(msg, err_msg, "Please enter a valid number (32 bits).").await
+}
+
+/// Works like read_u32, without blocking the runtime.
+pub async fn read_u32(msg: Option<&str>, err_msg: Option<&str>) -> u32 {
+    read_parsed(
+        msg,
+        err_msg,
+        "Please enter a valid positive number (32 bits).",
+    )
+    .await
+}
+
+/// Works like read_i64, without blocking the runtime.
+pub async fn read_i64(msg: Option<&str>, err_msg: Option<&str>) -> i64 {
+    read_parsed(msg, err_msg, "Please enter a valid number (64 bits).").await
+}
+
+/// Works like read_u64, without blocking the runtime.
+pub async fn read_u64(msg: Option<&str>, err_msg: Option<&str>) -> u64 {
+    read_parsed(
+        msg,
+        err_msg,
+        "Please enter a valid positive number (64 bits).",
+    )
+    .await
+}
+
+/// Works like read_usize, without blocking the runtime.
+pub async fn read_usize(msg: Option<&str>, err_msg: Option<&str>) -> usize {
+    read_parsed(
+        msg,
+        err_msg,
+        "Please enter a valid positive number (32/64 bits).",
+    )
+    .await
+}
+
+/// Works like read_f64, without blocking the runtime.
+pub async fn read_f64(msg: Option<&str>, err_msg: Option<&str>) -> f64 {
+    read_until_valid(msg, err_msg, |input| {
+        parse_real(input, true, "Please enter a valid real number (64 bits).")
+    })
+    .await
+}
+
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as the input prompt. Must be set to Some("...") or None.
+///
+/// 'err_msg' (Option<&str>) - an optional error message which will be printed
+/// if the user inputs an invalid value. Must be set to Some("...") or None.
+///
+/// # DESCRIPTION #
+/// Prompts the user until the input can be parsed as T, without blocking the runtime.
+///
+/// If err_msg is set to None, a default message will be shown.
+///
+/// # RETURNS #
+/// A value of type T provided by the user.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// # async fn run() {
+/// use quick_input::asynchronous;
+/// use std::net::IpAddr;
+/// let server: IpAddr = asynchronous::read(Some("Server IP: "), None).await;
+/// # }
+/// ```
+pub async fn read<T: FromStr>(msg: Option<&str>, err_msg: Option<&str>) -> T {
+    read_parsed(msg, err_msg, "Please enter a valid value.").await
+}
+
+async fn read_parsed<T: FromStr>(msg: Option<&str>, err_msg: Option<&str>, def_err_msg: &str) -> T {
+    read_until_valid(msg, err_msg, |input| {
+        input.parse().map_err(|_| def_err_msg.to_string())
+    })
+    .await
+}
+
+/// # Arguments #
+/// 'msg' (Option<&str>) - Optional message of the prompt.
+///
+/// 'err_msg' (Option<&str>) - Custom error message shown after an invalid answer.
+///
+/// 'parse' (impl Fn(&str) -> Result<T, String>) - Closure receiving the trimmed input which
+/// returns either the accepted value or the default error message for that input.
+///
+/// # Description #
+/// Private function shared by the asynchronous readers, like crate::read_until_valid.
+/// If the input ends, the policy set with set_eof_policy is applied.
+async fn read_until_valid<T>(
+    msg: Option<&str>,
+    err_msg: Option<&str>,
+    parse: impl Fn(&str) -> Result<T, String>,
+) -> T {
+    if dry_run::is_dry_run() {
+        return dry_run::placeholder(msg, &[], parse);
+    }
+
+    let stdin = STDIN.get_or_init(|| Mutex::new(BufReader::new(tokio::io::stdin())));
+    let mut stdin = stdin.lock().await;
+    let msg = msg.unwrap_or_default();
+
+    match ask(&mut *stdin, &mut tokio::io::stdout(), msg, err_msg, parse).await {
+        Ok(Some(value)) => value,
+        Ok(None) => eof::reached(msg),
+        Err(err) => panic!("Unable to read from stdin: {err}"),
+    }
+}
+
+/// Prompts on 'output' until a line of 'input' is accepted by 'parse', returning None once
+/// the input ends.
+async fn ask<T>(
+    input: &mut (impl AsyncBufRead + Unpin),
+    output: &mut (impl AsyncWrite + Unpin),
+    msg: &str,
+    err_msg: Option<&str>,
+    parse: impl Fn(&str) -> Result<T, String>,
+) -> io::Result<Option<T>> {
+    loop {
+        output.write_all(msg.as_bytes()).await?;
+        output.flush().await?;
+
+        let mut line = String::new();
+        if input.read_line(&mut line).await? == 0 {
+            return Ok(None);
+        }
+
+        let mut line = line.trim().to_string();
+        match postprocess::accept(msg, &mut line, &parse) {
+            Ok(value) => return Ok(Some(value)),
+            Err(def_err_msg) => {
+                let message = err_msg.unwrap_or(&def_err_msg);
+                output
+                    .write_all(format!("{message}\n---\n").as_bytes())
+                    .await?;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn lines_are_read_until_valid() {
+        let mut input: &[u8] = b"abc\n 42 \n";
+        let mut output = Vec::new();
+        let parse = |input: &str| {
+            input
+                .parse::<u8>()
+                .map_err(|_| String::from("Not a number."))
+        };
+
+        let value = ask(&mut input, &mut output, "Age: ", None, parse).await;
+
+        assert_eq!(value.unwrap(), Some(42));
+        assert_eq!(
+            String::from_utf8(output).unwrap(),
+            "Age: Not a number.\n---\nAge: "
+        );
+        assert_eq!(
+            ask(&mut input, &mut Vec::new(), "", None, parse)
+                .await
+                .unwrap(),
+            None
+        );
+    }
+}
@@ -0,0 +1,357 @@
+//! Numeric readers re-prompting until the value is within a range.
+
+use std::fmt::Display;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+use crate::prompt::describe_bounds;
+use crate::{dry_run, parse_real, read_until_valid};
+
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as the input prompt. Must be set to Some("...") or None.
+///
+/// 'err_msg' (Option<&str>) - an optional error message which will be printed
+/// if the user inputs an invalid value. Must be set to Some("...") or None.
+///
+/// 'range' (RangeInclusive<T>) - range the value must be within (Ex: 1..=10).
+///
+/// # DESCRIPTION #
+/// Prompts the user to type a value of type T which will then be returned. If the value
+/// cannot be parsed or is outside the range, they will be prompted to try again.
+/// The typed readers (Ex: read_i32_in_range) should be preferred for the usual number types;
+/// this one suits any other type which can be parsed and compared (Ex: u16, char).
+///
+/// Provides an information message on the same line as the prompt if Some("...")
+/// is provided, and just the prompt if None is provided.
+///
+/// If err_msg is set to None, a default message showing the allowed bounds will be shown
+/// (Ex: "Please enter a value between 1 and 10.").
+///
+/// # RETURNS #
+/// A value of type T within the range, provided by the user.
+///
+/// # PANICS #
+/// If the range is empty (Ex: 10..=1).
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::read_in_range;
+/// let port: u16 = read_in_range(Some("Port: "), None, 1024..=49151);
+///
+/// let grade = read_in_range(Some("Grade (A-F): "), None, 'A'..='F');
+/// ```
+pub fn read_in_range<T: FromStr + PartialOrd + Display>(
+    msg: Option<&str>,
+    err_msg: Option<&str>,
+    range: RangeInclusive<T>,
+) -> T {
+    read_bounded(msg, err_msg, range, |input| input.parse().ok())
+}
+
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as the input prompt. Must be set to Some("...") or None.
+///
+/// 'err_msg' (Option<&str>) - an optional error message which will be printed
+/// if the user inputs an invalid value. Must be set to Some("...") or None.
+///
+/// 'range' (RangeInclusive<i32>) - range the number must be within (Ex: -10..=10).
+///
+/// # DESCRIPTION #
+/// Prompts the user to type an integer value (i32) within the range, which will then be returned.
+/// In case the user writes an invalid value or one outside the range, they will be prompted
+/// to try again.
+///
+/// Provides an information message on the same line as the prompt if Some("...")
+/// is provided, and just the prompt if None is provided.
+///
+/// If err_msg is set to None, a default message showing the allowed bounds will be shown.
+///
+/// # RETURNS #
+/// An integer value of type i32 within the range, provided by the user.
+///
+/// # PANICS #
+/// If the range is empty (Ex: 10..=1).
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::read_i32_in_range;
+/// let offset = read_i32_in_range(Some("UTC offset: "), None, -12..=14);
+///
+/// let level: i32 = read_i32_in_range(None, Some("Choose a level from 1 to 5."), 1..=5);
+/// ```
+pub fn read_i32_in_range(
+    msg: Option<&str>,
+    err_msg: Option<&str>,
+    range: RangeInclusive<i32>,
+) -> i32 {
+    read_in_range(msg, err_msg, range)
+}
+
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as the input prompt. Must be set to Some("...") or None.
+///
+/// 'err_msg' (Option<&str>) - an optional error message which will be printed
+/// if the user inputs an invalid value. Must be set to Some("...") or None.
+///
+/// 'range' (RangeInclusive<u32>) - range the number must be within (Ex: 1..=100).
+///
+/// # DESCRIPTION #
+/// Prompts the user to type an integer value (u32) within the range, which will then be returned.
+/// In case the user writes an invalid value or one outside the range, they will be prompted
+/// to try again.
+///
+/// Provides an information message on the same line as the prompt if Some("...")
+/// is provided, and just the prompt if None is provided.
+///
+/// If err_msg is set to None, a default message showing the allowed bounds will be shown.
+///
+/// # RETURNS #
+/// An integer value of type u32 within the range, provided by the user.
+///
+/// # PANICS #
+/// If the range is empty (Ex: 10..=1).
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::read_u32_in_range;
+/// let copies = read_u32_in_range(Some("Copies (1-100): "), None, 1..=100);
+/// ```
+pub fn read_u32_in_range(
+    msg: Option<&str>,
+    err_msg: Option<&str>,
+    range: RangeInclusive<u32>,
+) -> u32 {
+    read_in_range(msg, err_msg, range)
+}
+
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as the input prompt. Must be set to Some("...") or None.
+///
+/// 'err_msg' (Option<&str>) - an optional error message which will be printed
+/// if the user inputs an invalid value. Must be set to Some("...") or None.
+///
+/// 'range' (RangeInclusive<i64>) - range the number must be within.
+///
+/// # DESCRIPTION #
+/// Works like read_i32_in_range, for integer values of type i64.
+///
+/// # RETURNS #
+/// An integer value of type i64 within the range, provided by the user.
+///
+/// # PANICS #
+/// If the range is empty (Ex: 10..=1).
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::read_i64_in_range;
+/// let year = read_i64_in_range(Some("Year: "), None, -9999..=9999);
+/// ```
+pub fn read_i64_in_range(
+    msg: Option<&str>,
+    err_msg: Option<&str>,
+    range: RangeInclusive<i64>,
+) -> i64 {
+    read_in_range(msg, err_msg, range)
+}
+
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as the input prompt. Must be set to Some("...") or None.
+///
+/// 'err_msg' (Option<&str>) - an optional error message which will be printed
+/// if the user inputs an invalid value. Must be set to Some("...") or None.
+///
+/// 'range' (RangeInclusive<u64>) - range the number must be within.
+///
+/// # DESCRIPTION #
+/// Works like read_u32_in_range, for integer values of type u64.
+///
+/// # RETURNS #
+/// An integer value of type u64 within the range, provided by the user.
+///
+/// # PANICS #
+/// If the range is empty (Ex: 10..=1).
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::read_u64_in_range;
+/// let size = read_u64_in_range(Some("Disk size (GB): "), None, 8..=4096);
+/// ```
+pub fn read_u64_in_range(
+    msg: Option<&str>,
+    err_msg: Option<&str>,
+    range: RangeInclusive<u64>,
+) -> u64 {
+    read_in_range(msg, err_msg, range)
+}
+
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as the input prompt. Must be set to Some("...") or None.
+///
+/// 'err_msg' (Option<&str>) - an optional error message which will be printed
+/// if the user inputs an invalid value. Must be set to Some("...") or None.
+///
+/// 'range' (RangeInclusive<usize>) - range the number must be within (Ex: 1..=items.len()).
+///
+/// # DESCRIPTION #
+/// Works like read_u32_in_range, for integer values of type usize, which suits indices
+/// and counts.
+///
+/// # RETURNS #
+/// An integer value of type usize within the range, provided by the user.
+///
+/// # PANICS #
+/// If the range is empty (Ex: 10..=1).
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::read_usize_in_range;
+/// let items = ["tea", "coffee", "water"];
+/// let choice = read_usize_in_range(Some("Item number: "), None, 1..=items.len());
+/// ```
+pub fn read_usize_in_range(
+    msg: Option<&str>,
+    err_msg: Option<&str>,
+    range: RangeInclusive<usize>,
+) -> usize {
+    read_in_range(msg, err_msg, range)
+}
+
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as the input prompt. Must be set to Some("...") or None.
+///
+/// 'err_msg' (Option<&str>) - an optional error message which will be printed
+/// if the user inputs an invalid value. Must be set to Some("...") or None.
+///
+/// 'range' (RangeInclusive<f32>) - range the number must be within (Ex: 0.0..=1.0).
+///
+/// # DESCRIPTION #
+/// Prompts the user to type a real number (f32) within the range, which will then be returned.
+/// Like read_f32, both '.' and ',' are accepted as separators for the decimal part.
+/// In case the user writes an invalid value or one outside the range, they will be prompted
+/// to try again.
+///
+/// If err_msg is set to None, a default message showing the allowed bounds will be shown.
+///
+/// # RETURNS #
+/// A floating point value of type f32 within the range, provided by the user.
+///
+/// # PANICS #
+/// If the range is empty (Ex: 10..=1).
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::read_f32_in_range;
+/// let opacity = read_f32_in_range(Some("Opacity (0-1): "), None, 0.0..=1.0);
+/// ```
+pub fn read_f32_in_range(
+    msg: Option<&str>,
+    err_msg: Option<&str>,
+    range: RangeInclusive<f32>,
+) -> f32 {
+    read_bounded(msg, err_msg, range, |input| {
+        parse_real(input, true, "").ok()
+    })
+}
+
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as the input prompt. Must be set to Some("...") or None.
+///
+/// 'err_msg' (Option<&str>) - an optional error message which will be printed
+/// if the user inputs an invalid value. Must be set to Some("...") or None.
+///
+/// 'range' (RangeInclusive<f64>) - range the number must be within (Ex: -90.0..=90.0).
+///
+/// # DESCRIPTION #
+/// Works like read_f32_in_range, for real numbers with double precision (f64).
+///
+/// # RETURNS #
+/// A floating point value of type f64 within the range, provided by the user.
+///
+/// # PANICS #
+/// If the range is empty (Ex: 10..=1).
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::read_f64_in_range;
+/// let latitude = read_f64_in_range(Some("Latitude: "), None, -90.0..=90.0);
+/// ```
+pub fn read_f64_in_range(
+    msg: Option<&str>,
+    err_msg: Option<&str>,
+    range: RangeInclusive<f64>,
+) -> f64 {
+    read_bounded(msg, err_msg, range, |input| {
+        parse_real(input, true, "").ok()
+    })
+}
+
+/// # Arguments #
+/// 'range' (RangeInclusive<T>) - Range the value must be within.
+///
+/// 'parse' (impl Fn(&str) -> Option<T>) - Closure parsing the trimmed input.
+///
+/// # Description #
+/// Private function shared by the readers of this module. Invalid and out of range values get
+/// the same default error message, since it tells which values are accepted.
+fn read_bounded<T: PartialOrd + Display>(
+    msg: Option<&str>,
+    err_msg: Option<&str>,
+    range: RangeInclusive<T>,
+    parse: impl Fn(&str) -> Option<T>,
+) -> T {
+    assert!(
+        !range.is_empty(),
+        "The range {}..={} is empty.",
+        range.start(),
+        range.end()
+    );
+
+    let accept = |input: &str| check_bounds(parse(input), &range);
+
+    if dry_run::is_dry_run() {
+        return dry_run::placeholder(msg, &[range.start().to_string()], accept);
+    }
+
+    read_until_valid(msg, err_msg, accept)
+}
+
+fn check_bounds<T: PartialOrd + Display>(
+    value: Option<T>,
+    range: &RangeInclusive<T>,
+) -> Result<T, String> {
+    match value {
+        Some(value) if range.contains(&value) => Ok(value),
+        _ => Err(format!("Please enter a value {}.", describe_bounds(range))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn values_outside_the_range_are_rejected() {
+        let range = 1..=10;
+
+        assert_eq!(check_bounds(Some(10), &range), Ok(10));
+        assert_eq!(
+            check_bounds(Some(11), &range),
+            Err(String::from("Please enter a value between 1 and 10."))
+        );
+        assert_eq!(check_bounds(None, &range), check_bounds(Some(0), &range));
+    }
+
+    #[test]
+    #[should_panic(expected = "The range 10..=1 is empty.")]
+    fn empty_ranges_are_rejected() {
+        read_in_range(None, None, RangeInclusive::new(10, 1));
+    }
+}
@@ -0,0 +1,309 @@
+//! Answers remembered between runs, offered as the default of the same prompt next time.
+
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::defaults::show_default;
+use crate::prompt::{Prompt, sealed};
+use crate::read_choice_map_with_default;
+use crate::reader::outln;
+
+/// First line of the files written by AnswerCache::save.
+const FILE_HEADER: &str = "# Answers cached by quick_input. One id=value per line.";
+
+/// Answers given to prompts identified by an id, which become their defaults the next time
+/// they are asked. The cache can live in memory only, or be loaded from and saved to a file
+/// so tools run repeatedly with mostly the same inputs only need Enter to be pressed.
+///
+/// Answers of sensitive (or hidden) prompts are never cached.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::{AnswerCache, Prompt};
+/// let mut cache = AnswerCache::load(".deploy-answers").unwrap();
+///
+/// let host: String = cache.ask("host", Prompt::new("Host: ").text());
+/// let port: u16 = cache.ask("port", Prompt::new("Port: ").value().range(1..));
+///
+/// cache.save().unwrap();
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AnswerCache {
+    entries: BTreeMap<String, String>,
+    path: Option<PathBuf>,
+    compare_defaults: bool,
+}
+
+impl AnswerCache {
+    /// Creates an empty cache kept in memory, which lasts as long as the program runs.
+    pub fn in_memory() -> Self {
+        AnswerCache::default()
+    }
+
+    /// # ARGUMENTS #
+    /// 'path' (impl AsRef<Path>) - file the answers are read from and saved to.
+    ///
+    /// # DESCRIPTION #
+    /// Creates a cache backed by a file, loading the answers it contains. A missing file
+    /// is not an error, since it will be created by save().
+    ///
+    /// # RETURNS #
+    /// The AnswerCache, or the error found reading the file.
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(err) => return Err(err),
+        };
+
+        let entries = contents
+            .lines()
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .filter_map(parse_line)
+            .collect();
+
+        Ok(AnswerCache {
+            entries,
+            path: Some(path),
+            compare_defaults: false,
+        })
+    }
+
+    /// # DESCRIPTION #
+    /// Makes ask() check the cached answers against the defaults of the prompts. When a
+    /// Prompt has a default which differs from its cached answer (Ex: a default computed from
+    /// the current project, which changed since the last run), both are shown and the user
+    /// chooses which one becomes the default of the Prompt, instead of the cached answer
+    /// silently winning.
+    ///
+    /// # EXAMPLES #
+    /// ```no_run
+    /// use quick_input::{AnswerCache, Prompt};
+    /// let mut cache = AnswerCache::load(".release-answers").unwrap().compare_defaults();
+    ///
+    /// let branch = std::env::var("BRANCH").unwrap_or_else(|_| String::from("main"));
+    /// let target: String = cache.ask("branch", Prompt::new("Branch: ").text().default(branch));
+    /// ```
+    pub fn compare_defaults(mut self) -> Self {
+        self.compare_defaults = true;
+        self
+    }
+
+    /// # DESCRIPTION #
+    /// Writes the cached answers to the file the cache was loaded from.
+    /// Caches kept in memory have nothing to save.
+    ///
+    /// # RETURNS #
+    /// The error found writing the file, if any.
+    pub fn save(&self) -> io::Result<()> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+
+        let mut contents = format!("{FILE_HEADER}\n");
+        for (id, value) in &self.entries {
+            contents.push_str(&format!("{}={}\n", escape(id), escape(value)));
+        }
+        fs::write(path, contents)
+    }
+
+    /// # ARGUMENTS #
+    /// 'id' (&str) - identifier of the prompt, which must be the same on every run.
+    ///
+    /// 'prompt' (Prompt<T, S>) - a text or value Prompt.
+    ///
+    /// # DESCRIPTION #
+    /// Reads from the Prompt, like Prompt::read. If an answer was cached for 'id' and the
+    /// Prompt still accepts it, it replaces the default of the Prompt and is shown between
+    /// brackets in its message (Ex: "Port [8080]: "), so pressing Enter reuses it.
+    /// If compare_defaults() was called and the Prompt has a different default, the user
+    /// chooses between both first. The answer given is then cached for the next time.
+    ///
+    /// # RETURNS #
+    /// The value typed by the user, or the default value of the Prompt.
+    pub fn ask<T: Clone + Display, S: sealed::Typed>(
+        &mut self,
+        id: &str,
+        prompt: Prompt<T, S>,
+    ) -> T {
+        if prompt.is_sensitive() {
+            return prompt.read();
+        }
+
+        let prompt = match self.cached_value(id, &prompt) {
+            Some(cached) => {
+                let default = match prompt.default_value() {
+                    Some(new) if self.compare_defaults => choose_default(id, cached, new),
+                    _ => cached,
+                };
+                let msg = show_default(prompt.msg(), &default.to_string());
+                prompt.with_msg(msg).default(default)
+            }
+            None => prompt,
+        };
+
+        let value = prompt.read();
+        let answer = value.to_string();
+        if !answer.is_empty() {
+            self.entries.insert(id.to_string(), answer);
+        }
+        value
+    }
+
+    /// Returns the answer cached for 'id', if any.
+    pub fn get(&self, id: &str) -> Option<&str> {
+        self.entries.get(id).map(String::as_str)
+    }
+
+    /// Forgets the answer cached for 'id', so its prompt has no default next time.
+    pub fn remove(&mut self, id: &str) {
+        self.entries.remove(id);
+    }
+
+    /// Forgets every cached answer.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Returns the answer cached for 'id' as accepted by the Prompt, or None if there is none
+    /// or it is no longer valid (Ex: the range of the Prompt changed).
+    fn cached_value<T: Clone, S: sealed::Typed>(
+        &self,
+        id: &str,
+        prompt: &Prompt<T, S>,
+    ) -> Option<T> {
+        self.entries
+            .get(id)
+            .and_then(|cached| prompt.accept(cached).ok())
+    }
+}
+
+/// # Arguments #
+/// 'id' (&str) - Identifier of the prompt.
+///
+/// 'previous' (T) - Answer cached for the prompt.
+///
+/// 'new' (&T) - Default value of the prompt.
+///
+/// # Description #
+/// Private function asking the user whether the previous answer or the new default
+/// should be offered as the default of the prompt. Nothing is asked if both are equal.
+fn choose_default<T: Clone + Display>(id: &str, previous: T, new: &T) -> T {
+    let (previous_text, new_text) = (previous.to_string(), new.to_string());
+    if previous_text == new_text {
+        return previous;
+    }
+
+    outln!("'{id}' was {previous_text} last time, but the default is now {new_text}.");
+    let options = [('p', "previous"), ('n', "new")];
+    match read_choice_map_with_default(Some("Which one should be used? "), None, &options, 'p') {
+        'n' => new.clone(),
+        _ => previous,
+    }
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '=' => escaped.push_str("\\="),
+            '#' => escaped.push_str("\\#"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Splits a line on its first unescaped '=', unescaping both sides.
+fn parse_line(line: &str) -> Option<(String, String)> {
+    let mut id = String::new();
+    let mut value = String::new();
+    let mut in_value = false;
+    let mut chars = line.chars();
+
+    while let Some(c) = chars.next() {
+        let target = if in_value { &mut value } else { &mut id };
+        match c {
+            '\\' => match chars.next()? {
+                'n' => target.push('\n'),
+                'r' => target.push('\r'),
+                escaped => target.push(escaped),
+            },
+            '=' if !in_value => in_value = true,
+            c => target.push(c),
+        }
+    }
+
+    in_value.then_some((id, value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::MockInput;
+
+    const CHOICE: &str = "Which one should be used? ";
+
+    fn ask_branch(cache: &mut AnswerCache, default: &str) -> String {
+        let prompt = Prompt::new("Branch: ").text().default(default.to_string());
+        cache.ask("branch", prompt)
+    }
+
+    #[test]
+    fn lines_are_escaped() {
+        let (id, value) = ("#a=b", "line 1\nC:\\temp");
+        let line = format!("{}={}", escape(id), escape(value));
+
+        assert!(!line.starts_with('#') && !line.contains('\n'));
+        assert_eq!(parse_line(&line), Some((id.to_string(), value.to_string())));
+    }
+
+    #[test]
+    fn previous_or_new_default_is_chosen() {
+        let mut cache = AnswerCache::in_memory().compare_defaults();
+        cache
+            .entries
+            .insert("branch".to_string(), "dev".to_string());
+
+        let mut mock = MockInput::new(["n", "", "p", ""]);
+        let (new, previous) = mock.run(|| {
+            let new = ask_branch(&mut cache, "main");
+            cache
+                .entries
+                .insert("branch".to_string(), "dev".to_string());
+            (new, ask_branch(&mut cache, "main"))
+        });
+
+        assert_eq!((new.as_str(), previous.as_str()), ("main", "dev"));
+        assert!(
+            mock.output()
+                .contains("'branch' was dev last time, but the default is now main.")
+        );
+        mock.assert_prompted_times(CHOICE, 2);
+        mock.assert_prompted("Branch [main]: ");
+        mock.assert_prompted("Branch [dev]: ");
+        mock.assert_all_answered();
+    }
+
+    #[test]
+    fn equal_defaults_are_not_compared() {
+        let mut cache = AnswerCache::in_memory().compare_defaults();
+        cache
+            .entries
+            .insert("branch".to_string(), "main".to_string());
+
+        let mut mock = MockInput::new([""]);
+        let branch = mock.run(|| ask_branch(&mut cache, "main"));
+
+        assert_eq!(branch, "main");
+        assert_eq!(mock.prompts(), ["Branch [main]: "]);
+        mock.assert_all_answered();
+    }
+}
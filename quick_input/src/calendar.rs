@@ -0,0 +1,275 @@
+//! Readers for weekdays and months, without any date parsing.
+
+use crate::read_until_valid;
+
+/// Minimum number of letters an abbreviation must have (Ex: mon, sep).
+const MIN_ABBREVIATION_LEN: usize = 3;
+
+/// A day of the week, numbered from Monday (1) to Sunday (7) as in ISO 8601.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Weekday {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+impl Weekday {
+    /// Every weekday, starting on Monday.
+    pub const ALL: [Weekday; 7] = [
+        Weekday::Monday,
+        Weekday::Tuesday,
+        Weekday::Wednesday,
+        Weekday::Thursday,
+        Weekday::Friday,
+        Weekday::Saturday,
+        Weekday::Sunday,
+    ];
+
+    /// Number of the weekday, from 1 (Monday) to 7 (Sunday).
+    pub fn number(self) -> u8 {
+        self as u8 + 1
+    }
+}
+
+/// A month of the year, numbered from January (1) to December (12).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Month {
+    January,
+    February,
+    March,
+    April,
+    May,
+    June,
+    July,
+    August,
+    September,
+    October,
+    November,
+    December,
+}
+
+impl Month {
+    /// Every month, starting on January.
+    pub const ALL: [Month; 12] = [
+        Month::January,
+        Month::February,
+        Month::March,
+        Month::April,
+        Month::May,
+        Month::June,
+        Month::July,
+        Month::August,
+        Month::September,
+        Month::October,
+        Month::November,
+        Month::December,
+    ];
+
+    /// Number of the month, from 1 (January) to 12 (December).
+    pub fn number(self) -> u8 {
+        self as u8 + 1
+    }
+}
+
+/// Names of the weekdays (starting on Monday) and months (starting on January)
+/// accepted by read_weekday and read_month. Custom lists can be built for any language.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CalendarNames<'a> {
+    pub weekdays: [&'a str; 7],
+    pub months: [&'a str; 12],
+}
+
+impl CalendarNames<'static> {
+    /// English names.
+    pub const ENGLISH: CalendarNames<'static> = CalendarNames {
+        weekdays: [
+            "monday",
+            "tuesday",
+            "wednesday",
+            "thursday",
+            "friday",
+            "saturday",
+            "sunday",
+        ],
+        months: [
+            "january",
+            "february",
+            "march",
+            "april",
+            "may",
+            "june",
+            "july",
+            "august",
+            "september",
+            "october",
+            "november",
+            "december",
+        ],
+    };
+
+    /// Spanish names.
+    pub const SPANISH: CalendarNames<'static> = CalendarNames {
+        weekdays: [
+            "lunes",
+            "martes",
+            "miércoles",
+            "jueves",
+            "viernes",
+            "sábado",
+            "domingo",
+        ],
+        months: [
+            "enero",
+            "febrero",
+            "marzo",
+            "abril",
+            "mayo",
+            "junio",
+            "julio",
+            "agosto",
+            "septiembre",
+            "octubre",
+            "noviembre",
+            "diciembre",
+        ],
+    };
+}
+
+impl Default for CalendarNames<'static> {
+    fn default() -> Self {
+        CalendarNames::ENGLISH
+    }
+}
+
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as the input prompt. Must be set to Some("...") or None.
+///
+/// 'err_msg' (Option<&str>) - an optional error message which will be printed
+/// if the user inputs an invalid value. Must be set to Some("...") or None.
+///
+/// 'names' (&CalendarNames) - names of the weekdays the user may type
+/// (Ex: &CalendarNames::ENGLISH).
+///
+/// # DESCRIPTION #
+/// Prompts the user to type a weekday which will then be returned. The full name,
+/// an abbreviation of at least three letters (Ex: mon, thu) or its number from
+/// 1 (Monday) to 7 (Sunday) are accepted. This function is not case-sensitive.
+/// In case the user writes an invalid value, they will be prompted to try again.
+///
+/// Provides an information message on the same line as the prompt if Some("...")
+/// is provided, and just the prompt if None is provided.
+///
+/// If err_msg is set to None, a default message will be shown.
+///
+/// # RETURNS #
+/// The Weekday provided by the user.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::{read_weekday, CalendarNames};
+/// let user_day_with_msg = read_weekday(Some("Backup day: "), None, &CalendarNames::ENGLISH);
+///
+/// let user_day = read_weekday(None, None, &CalendarNames::SPANISH);
+/// ```
+pub fn read_weekday(msg: Option<&str>, err_msg: Option<&str>, names: &CalendarNames) -> Weekday {
+    read_until_valid(msg, err_msg, |input| {
+        find_name(&names.weekdays, input)
+            .map(|index| Weekday::ALL[index])
+            .ok_or_else(|| {
+                String::from("Please enter a weekday, its first three letters or its number (1-7).")
+            })
+    })
+}
+
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as the input prompt. Must be set to Some("...") or None.
+///
+/// 'err_msg' (Option<&str>) - an optional error message which will be printed
+/// if the user inputs an invalid value. Must be set to Some("...") or None.
+///
+/// 'names' (&CalendarNames) - names of the months the user may type
+/// (Ex: &CalendarNames::ENGLISH).
+///
+/// # DESCRIPTION #
+/// Prompts the user to type a month which will then be returned. The full name,
+/// an abbreviation of at least three letters (Ex: jan, sept) or its number from
+/// 1 (January) to 12 (December) are accepted. This function is not case-sensitive.
+/// In case the user writes an invalid value, they will be prompted to try again.
+///
+/// Provides an information message on the same line as the prompt if Some("...")
+/// is provided, and just the prompt if None is provided.
+///
+/// If err_msg is set to None, a default message will be shown.
+///
+/// # RETURNS #
+/// The Month provided by the user.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::{read_month, CalendarNames};
+/// let user_month_with_msg = read_month(Some("Billing month: "), None, &CalendarNames::ENGLISH);
+///
+/// let user_month = read_month(None, None, &CalendarNames::default());
+/// ```
+pub fn read_month(msg: Option<&str>, err_msg: Option<&str>, names: &CalendarNames) -> Month {
+    read_until_valid(msg, err_msg, |input| {
+        find_name(&names.months, input)
+            .map(|index| Month::ALL[index])
+            .ok_or_else(|| {
+                String::from("Please enter a month, its first three letters or its number (1-12).")
+            })
+    })
+}
+
+/// Index of the name typed in full, abbreviated or as its number (starting at 1).
+/// Abbreviations must match a single name.
+fn find_name(names: &[&str], input: &str) -> Option<usize> {
+    if let Ok(number) = input.parse::<usize>() {
+        return number.checked_sub(1).filter(|&index| index < names.len());
+    }
+
+    let input = input.to_lowercase();
+    if let Some(index) = names.iter().position(|name| name.to_lowercase() == input) {
+        return Some(index);
+    }
+
+    if input.chars().count() < MIN_ABBREVIATION_LEN {
+        return None;
+    }
+
+    let mut matches = names
+        .iter()
+        .enumerate()
+        .filter(|(_, name)| name.to_lowercase().starts_with(&input));
+
+    match (matches.next(), matches.next()) {
+        (Some((index, _)), None) => Some(index),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn names_abbreviations_and_numbers_are_found() {
+        let months = &CalendarNames::ENGLISH.months;
+        assert_eq!(find_name(months, "September"), Some(8));
+        assert_eq!(find_name(months, "sept"), Some(8));
+        assert_eq!(find_name(months, "12"), Some(11));
+        assert_eq!(find_name(months, "13"), None);
+        assert_eq!(find_name(months, "0"), None);
+        assert_eq!(find_name(months, "ju"), None);
+
+        let weekdays = &CalendarNames::SPANISH.weekdays;
+        assert_eq!(find_name(weekdays, "MIÉ"), Some(2));
+        assert_eq!(find_name(weekdays, "7"), Some(6));
+    }
+}
@@ -0,0 +1,270 @@
+//! Readers for single characters of a given class.
+
+use crate::{dry_run, read_until_valid};
+
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as the input prompt. Must be set to Some("...") or None.
+///
+/// 'err_msg' (Option<&str>) - an optional error message which will be printed
+/// if the user inputs an invalid value. Must be set to Some("...") or None.
+///
+/// # DESCRIPTION #
+/// Prompts the user to type a single letter (char) which will then be returned.
+/// Letters from any alphabet are accepted (Ex: a, Ñ, ß), but digits, punctuation
+/// and longer inputs are not. In case the user writes an invalid value,
+/// they will be prompted to try again.
+///
+/// Provides an information message on the same line as the prompt if Some("...")
+/// is provided, and just the prompt if None is provided.
+///
+/// If err_msg is set to None, a default message will be shown.
+///
+/// # RETURNS #
+/// A single letter (char) provided by the user.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::read_letter;
+/// let user_letter_with_msg = read_letter(Some("Guess a letter: "), Some("Please input a letter."));
+///
+/// let user_letter: char = read_letter(None, None);
+/// ```
+pub fn read_letter(msg: Option<&str>, err_msg: Option<&str>) -> char {
+    read_char_where(
+        msg,
+        err_msg,
+        char::is_alphabetic,
+        "Please enter a single letter.",
+    )
+}
+
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as the input prompt. Must be set to Some("...") or None.
+///
+/// 'err_msg' (Option<&str>) - an optional error message which will be printed
+/// if the user inputs an invalid value. Must be set to Some("...") or None.
+///
+/// # DESCRIPTION #
+/// Prompts the user to type a single uppercase letter (char) which will then be returned.
+/// Lowercase letters are rejected rather than converted, so the user knows the case matters.
+/// In case the user writes an invalid value, they will be prompted to try again.
+///
+/// Provides an information message on the same line as the prompt if Some("...")
+/// is provided, and just the prompt if None is provided.
+///
+/// If err_msg is set to None, a default message will be shown.
+///
+/// # RETURNS #
+/// A single uppercase letter (char) provided by the user.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::read_uppercase_letter;
+/// let user_column_with_msg = read_uppercase_letter(Some("Column (A-H): "), None);
+///
+/// let user_column: char = read_uppercase_letter(None, None);
+/// ```
+pub fn read_uppercase_letter(msg: Option<&str>, err_msg: Option<&str>) -> char {
+    read_char_where(
+        msg,
+        err_msg,
+        char::is_uppercase,
+        "Please enter a single uppercase letter.",
+    )
+}
+
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as the input prompt. Must be set to Some("...") or None.
+///
+/// 'err_msg' (Option<&str>) - an optional error message which will be printed
+/// if the user inputs an invalid value. Must be set to Some("...") or None.
+///
+/// # DESCRIPTION #
+/// Prompts the user to type a single digit from 0 to 9 (char) which will then be returned.
+/// In case the user writes an invalid value, they will be prompted to try again.
+///
+/// Provides an information message on the same line as the prompt if Some("...")
+/// is provided, and just the prompt if None is provided.
+///
+/// If err_msg is set to None, a default message will be shown.
+///
+/// # RETURNS #
+/// A single digit (char) provided by the user. Use char::to_digit to get its value.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::read_digit_char;
+/// let user_digit_with_msg = read_digit_char(Some("Row (1-8): "), None);
+///
+/// let user_digit: char = read_digit_char(None, None);
+/// ```
+pub fn read_digit_char(msg: Option<&str>, err_msg: Option<&str>) -> char {
+    read_char_where(
+        msg,
+        err_msg,
+        |c| c.is_ascii_digit(),
+        "Please enter a single digit (0-9).",
+    )
+}
+
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as the input prompt. Must be set to Some("...") or None.
+///
+/// 'err_msg' (Option<&str>) - an optional error message which will be printed
+/// if the user inputs an invalid value. Must be set to Some("...") or None.
+///
+/// 'allowed' (&[char]) - characters the user may type (Ex: &['a', 'd', 'q']).
+///
+/// # DESCRIPTION #
+/// Prompts the user to type one of the allowed characters, which will then be returned.
+/// In case the user writes any other character, more than one or none at all,
+/// they will be prompted to try again. This suits menus such as "[a]dd [d]elete [q]uit".
+///
+/// Provides an information message on the same line as the prompt if Some("...")
+/// is provided, and just the prompt if None is provided.
+///
+/// If err_msg is set to None, a default message listing the allowed characters will be shown.
+///
+/// # RETURNS #
+/// One of the allowed characters (char), as typed by the user.
+///
+/// # PANICS #
+/// If 'allowed' is empty.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::read_char_in;
+/// let action = read_char_in(Some("[a]dd [d]elete [q]uit: "), None, &['a', 'd', 'q']);
+/// ```
+pub fn read_char_in(msg: Option<&str>, err_msg: Option<&str>, allowed: &[char]) -> char {
+    let def_err_msg = describe_allowed(allowed);
+
+    read_allowed_char(msg, err_msg, allowed, |input| {
+        parse_char_where(input, |c| allowed.contains(&c)).ok_or_else(|| def_err_msg.clone())
+    })
+}
+
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as the input prompt. Must be set to Some("...") or None.
+///
+/// 'err_msg' (Option<&str>) - an optional error message which will be printed
+/// if the user inputs an invalid value. Must be set to Some("...") or None.
+///
+/// 'allowed' (&[char]) - characters the user may type, in any case (Ex: &['y', 'n']).
+///
+/// # DESCRIPTION #
+/// Works like read_char_in, but the case of the typed character is ignored (Ex: 'A' is
+/// accepted if 'a' is allowed).
+///
+/// # RETURNS #
+/// One of the allowed characters (char), as written in 'allowed' rather than as typed,
+/// so the result can be matched without converting its case.
+///
+/// # PANICS #
+/// If 'allowed' is empty.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::read_char_in_ignore_case;
+/// match read_char_in_ignore_case(Some("[a]dd [q]uit: "), None, &['a', 'q']) {
+///     'a' => println!("Adding..."),
+///     _ => println!("Bye!"),
+/// }
+/// ```
+pub fn read_char_in_ignore_case(
+    msg: Option<&str>,
+    err_msg: Option<&str>,
+    allowed: &[char],
+) -> char {
+    let def_err_msg = describe_allowed(allowed);
+
+    read_allowed_char(msg, err_msg, allowed, |input| {
+        parse_char_where(input, |_| true)
+            .and_then(|typed| {
+                allowed
+                    .iter()
+                    .copied()
+                    .find(|c| c.to_lowercase().eq(typed.to_lowercase()))
+            })
+            .ok_or_else(|| def_err_msg.clone())
+    })
+}
+
+fn read_char_where(
+    msg: Option<&str>,
+    err_msg: Option<&str>,
+    accept: impl Fn(char) -> bool,
+    def_err_msg: &str,
+) -> char {
+    read_until_valid(msg, err_msg, |input| {
+        parse_char_where(input, &accept).ok_or_else(|| def_err_msg.to_string())
+    })
+}
+
+fn parse_char_where(input: &str, accept: impl Fn(char) -> bool) -> Option<char> {
+    let mut chars = input.chars();
+
+    match (chars.next(), chars.next()) {
+        (Some(c), None) if accept(c) => Some(c),
+        _ => None,
+    }
+}
+
+/// Reads a character with read_until_valid. A dry run answers with the first allowed
+/// character, which the usual placeholders may not be.
+fn read_allowed_char(
+    msg: Option<&str>,
+    err_msg: Option<&str>,
+    allowed: &[char],
+    parse: impl Fn(&str) -> Result<char, String>,
+) -> char {
+    assert!(!allowed.is_empty(), "At least one character must be allowed.");
+
+    if dry_run::is_dry_run() {
+        let hints: Vec<String> = allowed.iter().map(char::to_string).collect();
+        return dry_run::placeholder(msg, &hints, parse);
+    }
+
+    read_until_valid(msg, err_msg, parse)
+}
+
+fn describe_allowed(allowed: &[char]) -> String {
+    let allowed: Vec<String> = allowed.iter().map(char::to_string).collect();
+    format!("Please enter one of: {}.", allowed.join(", "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::MockInput;
+
+    #[test]
+    fn only_single_characters_of_the_class_are_accepted() {
+        assert_eq!(parse_char_where("Ñ", char::is_alphabetic), Some('Ñ'));
+        assert_eq!(parse_char_where("ab", char::is_alphabetic), None);
+        assert_eq!(parse_char_where("!", char::is_alphabetic), None);
+        assert_eq!(parse_char_where("b", char::is_uppercase), None);
+        assert_eq!(parse_char_where("", |c| c.is_ascii_digit()), None);
+    }
+
+    #[test]
+    fn only_allowed_characters_are_accepted() {
+        let mut mock = MockInput::new(["", "x", "A", "a", "Q"]);
+
+        assert_eq!(mock.run(|| read_char_in(None, None, &['a', 'q'])), 'a');
+        assert_eq!(mock.run(|| read_char_in_ignore_case(None, None, &['a', 'q'])), 'q');
+        mock.assert_rejected("Please enter one of: a, q.");
+        mock.assert_all_answered();
+    }
+
+    #[test]
+    #[should_panic(expected = "At least one character must be allowed.")]
+    fn an_allowed_character_is_required() {
+        read_char_in_ignore_case(None, None, &[]);
+    }
+}
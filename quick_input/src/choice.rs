@@ -0,0 +1,200 @@
+//! Choices mapped directly to the variants of an enum.
+
+use crate::suggest::{closest_matches, did_you_mean};
+use crate::{dry_run, read_until_valid};
+
+/// A type whose values can be chosen by typing their label (usually an enum).
+///
+/// With the `derive` feature, it can be derived for enums whose variants have no fields.
+/// Labels are then the names of the variants, unless they are changed with
+/// `#[choice(label = "...")]`:
+///
+/// ```
+/// # #[cfg(feature = "derive")]
+/// # {
+/// use quick_input::testing::MockInput;
+/// use quick_input::{Choice, read_choice};
+///
+/// #[derive(Debug, Clone, Copy, PartialEq, Choice)]
+/// enum Difficulty {
+///     Easy,
+///     Normal,
+///     #[choice(label = "nightmare")]
+///     Hard,
+/// }
+///
+/// let mut mock = MockInput::new(["night"]);
+/// let difficulty: Difficulty = mock.run(|| read_choice(None, None));
+/// assert_eq!(difficulty, Difficulty::Hard);
+/// assert_eq!(Difficulty::Easy.label(), "Easy");
+/// # }
+/// ```
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::{Choice, read_choice};
+///
+/// #[derive(Debug, Clone, Copy)]
+/// enum Difficulty {
+///     Easy,
+///     Normal,
+///     Hard,
+/// }
+///
+/// impl Choice for Difficulty {
+///     fn variants() -> &'static [Self] {
+///         &[Difficulty::Easy, Difficulty::Normal, Difficulty::Hard]
+///     }
+///
+///     fn label(&self) -> &str {
+///         match self {
+///             Difficulty::Easy => "easy",
+///             Difficulty::Normal => "normal",
+///             Difficulty::Hard => "hard",
+///         }
+///     }
+/// }
+///
+/// let difficulty: Difficulty = read_choice(Some("Difficulty (easy / normal / hard): "), None);
+/// ```
+pub trait Choice: Clone + 'static {
+    /// Every value which can be chosen, in the order they are listed.
+    fn variants() -> &'static [Self];
+
+    /// Label typed by the user to choose this value.
+    fn label(&self) -> &str;
+}
+
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as the input prompt. Must be set to Some("...") or None.
+///
+/// 'err_msg' (Option<&str>) - an optional error message which will be printed
+/// if the user inputs an invalid value. Must be set to Some("...") or None.
+///
+/// # DESCRIPTION #
+/// Prompts the user to choose a value of T by typing its label (not case-sensitive) or the
+/// beginning of it, as long as no other label starts the same way (Ex: "h" for "hard").
+/// In case the user writes an invalid value, they will be prompted to try again, and the
+/// closest labels are suggested.
+///
+/// Provides an information message on the same line as the prompt if Some("...")
+/// is provided, and just the prompt if None is provided.
+///
+/// If err_msg is set to None, a default message explaining the problem will be shown.
+///
+/// # RETURNS #
+/// The value of T chosen by the user.
+///
+/// # PANICS #
+/// If T has no variants.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::{Choice, read_choice};
+///
+/// #[derive(Clone)]
+/// enum Shape {
+///     Circle,
+///     Square,
+/// }
+///
+/// impl Choice for Shape {
+///     fn variants() -> &'static [Self] {
+///         &[Shape::Circle, Shape::Square]
+///     }
+///
+///     fn label(&self) -> &str {
+///         match self {
+///             Shape::Circle => "circle",
+///             Shape::Square => "square",
+///         }
+///     }
+/// }
+///
+/// let shape: Shape = read_choice(Some("Shape: "), None);
+/// ```
+pub fn read_choice<T: Choice>(msg: Option<&str>, err_msg: Option<&str>) -> T {
+    let variants = T::variants();
+    assert!(!variants.is_empty(), "A choice needs at least one variant.");
+
+    let labels: Vec<&str> = variants.iter().map(Choice::label).collect();
+    let parse = |input: &str| find_label(input, &labels).map(|index| variants[index].clone());
+
+    if dry_run::is_dry_run() {
+        return dry_run::placeholder(msg, &[labels[0].to_string()], parse);
+    }
+
+    read_until_valid(msg, err_msg, parse)
+}
+
+/// # Arguments #
+/// 'input' (&str) - Trimmed input typed by the user.
+///
+/// 'labels' (&[&str]) - Label of every variant.
+///
+/// # Description #
+/// Private function returning the index of the label typed in full (not case-sensitive)
+/// or of the only label starting with the input.
+fn find_label(input: &str, labels: &[&str]) -> Result<usize, String> {
+    if input.is_empty() {
+        return Err(format!("Please enter one of: {}.", labels.join(", ")));
+    }
+
+    if let Some(index) = labels
+        .iter()
+        .position(|label| label.eq_ignore_ascii_case(input))
+    {
+        return Ok(index);
+    }
+
+    let prefix = input.to_lowercase();
+    let matches: Vec<usize> = (0..labels.len())
+        .filter(|&index| labels[index].to_lowercase().starts_with(&prefix))
+        .collect();
+
+    match matches[..] {
+        [index] => Ok(index),
+        [] => match closest_matches(input, labels.iter().copied(), 3)[..] {
+            [] => Err(format!(
+                "Unknown option '{input}'. Please enter one of: {}.",
+                labels.join(", ")
+            )),
+            ref suggestions => Err(format!(
+                "Unknown option '{input}'.{}",
+                did_you_mean(suggestions)
+            )),
+        },
+        _ => {
+            let candidates: Vec<&str> = matches.iter().map(|&index| labels[index]).collect();
+            Err(format!(
+                "'{input}' could be {}. Please type more of it.",
+                candidates.join(", ")
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn labels_are_matched_in_full_or_by_prefix() {
+        let labels = ["easy", "normal", "nightmare"];
+
+        assert_eq!(find_label("EASY", &labels), Ok(0));
+        assert_eq!(find_label("e", &labels), Ok(0));
+        assert_eq!(find_label("nor", &labels), Ok(1));
+        assert_eq!(
+            find_label("n", &labels),
+            Err(String::from(
+                "'n' could be normal, nightmare. Please type more of it."
+            ))
+        );
+        assert_eq!(
+            find_label("eazy", &labels),
+            Err(String::from("Unknown option 'eazy'. Did you mean easy?"))
+        );
+    }
+}
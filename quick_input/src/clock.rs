@@ -0,0 +1,131 @@
+//! Readers for durations written in clock notation.
+
+use std::time::Duration;
+
+use crate::{dry_run, read_until_valid};
+
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as the input prompt. Must be set to Some("...") or None.
+///
+/// 'err_msg' (Option<&str>) - an optional error message which will be printed
+/// if the user inputs an invalid value. Must be set to Some("...") or None.
+///
+/// # DESCRIPTION #
+/// Prompts the user to type a duration as a clock would show it, either HH:MM:SS or MM:SS
+/// (Ex: 1:30:00 or 4:05). The first component has no upper limit (Ex: 90:00 is an hour and
+/// a half), while the following ones must be below 60. The seconds may have a fractional
+/// part (Ex: 0:07.25), which is useful for media timestamps.
+/// In case the user writes an invalid value, they will be prompted to try again.
+///
+/// Provides an information message on the same line as the prompt if Some("...")
+/// is provided, and just the prompt if None is provided.
+///
+/// If err_msg is set to None, a default message explaining the expected format will be shown.
+///
+/// # RETURNS #
+/// The Duration typed by the user.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::read_duration_hms;
+/// let timer = read_duration_hms(Some("Countdown (MM:SS): "), None);
+///
+/// println!("Ringing in {} seconds.", timer.as_secs());
+/// ```
+pub fn read_duration_hms(msg: Option<&str>, err_msg: Option<&str>) -> Duration {
+    if dry_run::is_dry_run() {
+        return dry_run::placeholder(msg, &[String::from("0:00")], parse_hms);
+    }
+
+    read_until_valid(msg, err_msg, parse_hms)
+}
+
+/// # Arguments #
+/// 'input' (&str) - Trimmed input typed by the user.
+///
+/// # Description #
+/// Private function parsing a duration written as HH:MM:SS or MM:SS.
+fn parse_hms(input: &str) -> Result<Duration, String> {
+    const FORMAT_ERROR: &str = "Please enter a duration as HH:MM:SS or MM:SS (Ex: 1:30:00).";
+
+    let components: Vec<&str> = input.split(':').collect();
+    if !(2..=3).contains(&components.len()) {
+        return Err(String::from(FORMAT_ERROR));
+    }
+
+    let (whole, seconds) = components.split_at(components.len() - 1);
+    let seconds = parse_seconds(seconds[0]).ok_or(FORMAT_ERROR)?;
+    if seconds.as_secs() >= 60 {
+        return Err(String::from("The seconds must be below 60."));
+    }
+
+    let mut total: u64 = 0;
+    for (index, component) in whole.iter().enumerate() {
+        if component.is_empty() || !component.bytes().all(|byte| byte.is_ascii_digit()) {
+            return Err(String::from(FORMAT_ERROR));
+        }
+
+        let value: u64 = component
+            .parse()
+            .map_err(|_| String::from("The duration is too long."))?;
+        if index > 0 && value >= 60 {
+            return Err(String::from("The minutes must be below 60."));
+        }
+
+        total = total
+            .checked_mul(60)
+            .and_then(|total| total.checked_add(value))
+            .ok_or("The duration is too long.")?;
+    }
+
+    let whole_seconds = total.checked_mul(60).ok_or("The duration is too long.")?;
+    Ok(Duration::from_secs(whole_seconds) + seconds)
+}
+
+/// Parses the seconds component, made of two digits and an optional fractional part
+/// of up to nine digits (nanoseconds).
+fn parse_seconds(seconds: &str) -> Option<Duration> {
+    let (integer, fraction) = match seconds.split_once('.') {
+        Some((integer, fraction)) => (integer, Some(fraction)),
+        None => (seconds, None),
+    };
+
+    let digits = |text: &str| !text.is_empty() && text.bytes().all(|byte| byte.is_ascii_digit());
+    if integer.len() != 2 || !digits(integer) {
+        return None;
+    }
+
+    let nanos = match fraction {
+        Some(fraction) if digits(fraction) && fraction.len() <= 9 => {
+            format!("{fraction:0<9}").parse().ok()?
+        }
+        Some(_) => return None,
+        None => 0,
+    };
+
+    Some(Duration::new(integer.parse().ok()?, nanos))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clock_durations_are_parsed() {
+        assert_eq!(parse_hms("4:05"), Ok(Duration::from_secs(245)));
+        assert_eq!(parse_hms("1:30:00"), Ok(Duration::from_secs(5400)));
+        assert_eq!(parse_hms("90:00"), Ok(Duration::from_secs(5400)));
+        assert_eq!(parse_hms("0:07.25"), Ok(Duration::from_millis(7250)));
+        assert_eq!(parse_hms("0:00.1"), Ok(Duration::from_millis(100)));
+    }
+
+    #[test]
+    fn invalid_durations_are_rejected() {
+        for input in [
+            "", "45", "1:2", "1:60", "1:60:00", "1::00", "1:2:3:04", "-1:00", "1:05.",
+        ] {
+            assert!(parse_hms(input).is_err(), "{input} was accepted");
+        }
+    }
+}
@@ -0,0 +1,273 @@
+//! Readers for ISO language, country, locale and currency codes, validated against embedded tables.
+
+mod tables;
+
+use tables::{COUNTRIES, CURRENCIES, LANGUAGES};
+
+use crate::read_until_valid;
+use crate::suggest::{closest_matches, did_you_mean};
+
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as the input prompt. Must be set to Some("...") or None.
+///
+/// 'err_msg' (Option<&str>) - an optional error message which will be printed
+/// if the user inputs an invalid value. Must be set to Some("...") or None.
+///
+/// # DESCRIPTION #
+/// Prompts the user to type an ISO 639-1 language code (Ex: es) which will then be returned.
+/// This function is not case-sensitive, and the English name of the language (Ex: Spanish)
+/// is accepted as well. In case the user writes an unknown language, they will be prompted to try again.
+///
+/// Provides an information message on the same line as the prompt if Some("...")
+/// is provided, and just the prompt if None is provided.
+///
+/// If err_msg is set to None, a default message suggesting similar languages will be shown.
+///
+/// # RETURNS #
+/// A lowercase two letter language code (String) provided by the user.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::read_language_code;
+/// let user_lang_with_msg = read_language_code(Some("Language: "), Some("Please input a valid language."));
+///
+/// let user_lang: String = read_language_code(None, None);
+/// ```
+pub fn read_language_code(msg: Option<&str>, err_msg: Option<&str>) -> String {
+    read_until_valid(msg, err_msg, |input| {
+        find_code(LANGUAGES, input)
+            .map(str::to_string)
+            .ok_or_else(|| unknown_code("language", LANGUAGES, input))
+    })
+}
+
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as the input prompt. Must be set to Some("...") or None.
+///
+/// 'err_msg' (Option<&str>) - an optional error message which will be printed
+/// if the user inputs an invalid value. Must be set to Some("...") or None.
+///
+/// # DESCRIPTION #
+/// Prompts the user to type an ISO 3166-1 alpha-2 country code (Ex: ES) which will then be returned.
+/// This function is not case-sensitive, and the English name of the country (Ex: Spain)
+/// is accepted as well. In case the user writes an unknown country, they will be prompted to try again.
+///
+/// Provides an information message on the same line as the prompt if Some("...")
+/// is provided, and just the prompt if None is provided.
+///
+/// If err_msg is set to None, a default message suggesting similar countries will be shown.
+///
+/// # RETURNS #
+/// An uppercase two letter country code (String) provided by the user.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::read_country_code;
+/// let user_country_with_msg = read_country_code(Some("Country: "), Some("Please input a valid country."));
+///
+/// let user_country: String = read_country_code(None, None);
+/// ```
+pub fn read_country_code(msg: Option<&str>, err_msg: Option<&str>) -> String {
+    read_until_valid(msg, err_msg, |input| {
+        find_code(COUNTRIES, input)
+            .map(str::to_string)
+            .ok_or_else(|| unknown_code("country", COUNTRIES, input))
+    })
+}
+
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as the input prompt. Must be set to Some("...") or None.
+///
+/// 'err_msg' (Option<&str>) - an optional error message which will be printed
+/// if the user inputs an invalid value. Must be set to Some("...") or None.
+///
+/// # DESCRIPTION #
+/// Prompts the user to type a locale made of a language and a country code (Ex: es-ES)
+/// which will then be returned. Both '-' and '_' are accepted as separators, and the
+/// input is not case-sensitive. In case the user writes an invalid locale, they will be
+/// prompted to try again.
+///
+/// Provides an information message on the same line as the prompt if Some("...")
+/// is provided, and just the prompt if None is provided.
+///
+/// If err_msg is set to None, a default message pointing out which part of the locale
+/// is wrong will be shown.
+///
+/// # RETURNS #
+/// A normalized locale (String) provided by the user, such as "es-ES".
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::read_locale;
+/// let user_locale_with_msg = read_locale(Some("Locale: "), Some("Please input a valid locale."));
+///
+/// let user_locale: String = read_locale(None, None);
+/// ```
+pub fn read_locale(msg: Option<&str>, err_msg: Option<&str>) -> String {
+    read_until_valid(msg, err_msg, parse_locale)
+}
+
+/// An ISO 4217 currency, as returned by read_currency_code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Currency {
+    /// Three letter currency code (Ex: EUR).
+    pub code: &'static str,
+    /// English name of the currency (Ex: Euro).
+    pub name: &'static str,
+    /// Number of decimals used by the currency (Ex: 2 for EUR, 0 for JPY).
+    pub minor_units: u8,
+}
+
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as the input prompt. Must be set to Some("...") or None.
+///
+/// 'err_msg' (Option<&str>) - an optional error message which will be printed
+/// if the user inputs an invalid value. Must be set to Some("...") or None.
+///
+/// # DESCRIPTION #
+/// Prompts the user to type an ISO 4217 currency code (Ex: EUR) which will then be returned.
+/// This function is not case-sensitive, and the English name of the currency (Ex: Euro)
+/// is accepted as well. In case the user writes an unknown currency, or a name shared by several
+/// currencies (Ex: Bolívar Soberano, used by VED and VES), they will be prompted to try again.
+///
+/// Codes which are not real currencies (precious metals and testing codes such as XAU or XTS)
+/// are not accepted, since they have no minor units.
+///
+/// Provides an information message on the same line as the prompt if Some("...")
+/// is provided, and just the prompt if None is provided.
+///
+/// If err_msg is set to None, a default message suggesting similar currencies will be shown.
+///
+/// # RETURNS #
+/// The Currency provided by the user, including its code and number of minor units.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::read_currency_code;
+/// let user_currency_with_msg = read_currency_code(Some("Currency: "), Some("Please input a valid currency."));
+///
+/// let user_currency = read_currency_code(None, None);
+/// println!("{} uses {} decimals", user_currency.code, user_currency.minor_units);
+/// ```
+pub fn read_currency_code(msg: Option<&str>, err_msg: Option<&str>) -> Currency {
+    read_until_valid(msg, err_msg, |input| {
+        let found: Vec<_> = CURRENCIES
+            .iter()
+            .filter(|(code, name, _)| {
+                code.eq_ignore_ascii_case(input) || name.eq_ignore_ascii_case(input)
+            })
+            .collect();
+
+        match found.as_slice() {
+            [(code, name, minor_units)] => Ok(Currency {
+                code,
+                name,
+                minor_units: *minor_units,
+            }),
+            [] => {
+                let table: Vec<(&str, &str)> = CURRENCIES
+                    .iter()
+                    .map(|(code, name, _)| (*code, *name))
+                    .collect();
+                Err(unknown_code("currency", &table, input))
+            }
+            // Some currencies share their name (Ex: VED and VES), so only the code tells them apart.
+            several => {
+                let codes: Vec<&str> = several.iter().map(|(code, _, _)| *code).collect();
+                Err(format!(
+                    "Several currencies are called '{input}'.{}",
+                    did_you_mean(&codes)
+                ))
+            }
+        }
+    })
+}
+
+fn parse_locale(input: &str) -> Result<String, String> {
+    let Some((language, country)) = input.split_once(['-', '_']) else {
+        return Err(String::from("Please enter a locale such as es-ES."));
+    };
+
+    let language = LANGUAGES
+        .iter()
+        .find(|(code, _)| code.eq_ignore_ascii_case(language))
+        .ok_or_else(|| unknown_code("language", LANGUAGES, language))?;
+    let country = COUNTRIES
+        .iter()
+        .find(|(code, _)| code.eq_ignore_ascii_case(country))
+        .ok_or_else(|| unknown_code("country", COUNTRIES, country))?;
+
+    Ok(format!("{}-{}", language.0, country.0))
+}
+
+fn find_code(table: &'static [(&'static str, &'static str)], input: &str) -> Option<&'static str> {
+    table
+        .iter()
+        .find(|(code, name)| code.eq_ignore_ascii_case(input) || name.eq_ignore_ascii_case(input))
+        .map(|(code, _)| *code)
+}
+
+fn unknown_code(kind: &str, table: &[(&'static str, &'static str)], input: &str) -> String {
+    let candidates = table.iter().flat_map(|(code, name)| [*code, *name]);
+    let mut suggestions: Vec<String> = Vec::new();
+    for found in closest_matches(input, candidates, 3) {
+        let suggestion = match table
+            .iter()
+            .find(|(code, name)| *code == found || *name == found)
+        {
+            Some((code, name)) => format!("{code} ({name})"),
+            None => found.to_string(),
+        };
+        // The code and the name of an entry may both be close, and not one after the other.
+        if !suggestions.contains(&suggestion) {
+            suggestions.push(suggestion);
+        }
+    }
+    let suggestions: Vec<&str> = suggestions.iter().map(String::as_str).collect();
+
+    format!("Unknown {kind} '{input}'.{}", did_you_mean(&suggestions))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::MockInput;
+
+    #[test]
+    fn locale_is_normalized() {
+        assert_eq!(parse_locale("ES_es"), Ok(String::from("es-ES")));
+        assert_eq!(parse_locale("en-GB"), Ok(String::from("en-GB")));
+    }
+
+    #[test]
+    fn locale_reports_wrong_part() {
+        assert!(parse_locale("es").is_err());
+        assert!(parse_locale("xx-ES").unwrap_err().contains("language"));
+        assert!(parse_locale("es-XX").unwrap_err().contains("country"));
+    }
+
+    #[test]
+    fn ambiguous_currency_names_are_rejected() {
+        let mut mock = MockInput::new(["Bolívar Soberano", "ves"]);
+        let currency = mock.run(|| read_currency_code(None, None));
+
+        assert_eq!(currency.code, "VES");
+        mock.assert_rejected(
+            "Several currencies are called 'Bolívar Soberano'. Did you mean VED or VES?",
+        );
+    }
+
+    #[test]
+    fn suggestions_are_not_repeated() {
+        let table = [("ABC", "Abd"), ("ABE", "Xyz")];
+
+        assert_eq!(
+            unknown_code("code", &table, "abx"),
+            "Unknown code 'abx'. Did you mean ABC (Abd) or ABE (Xyz)?"
+        );
+    }
+}
@@ -0,0 +1,148 @@
+//! Readers collecting several values, one per line.
+
+use std::ops::{Bound, RangeBounds};
+use std::str::FromStr;
+
+use crate::{dry_run, read_until_valid};
+use crate::reader::outln;
+
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as every input prompt. Must be set to Some("...") or None.
+///
+/// 'err_msg' (Option<&str>) - an optional error message which will be printed
+/// if the user inputs an invalid item. Must be set to Some("...") or None.
+///
+/// 'sentinel' (&str) - word which ends the list (Ex: "done"), compared without
+/// case-sensitivity. May be left empty to end the list with an empty line.
+///
+/// 'count' (impl RangeBounds<usize>) - number of items the list must have (Ex: 1..=10, 2..).
+///
+/// # DESCRIPTION #
+/// Prompts the user to type items one per line until the sentinel is typed. Every item
+/// is validated as soon as it is typed, so an invalid item only has to be typed again
+/// instead of the whole list, and the number of items collected so far is shown after each one.
+///
+/// If the sentinel is typed before the minimum number of items is reached, the user is asked
+/// for more. Once the maximum is reached, the list ends without waiting for the sentinel.
+///
+/// If err_msg is set to None, a default message will be shown.
+///
+/// # RETURNS #
+/// A Vec<T> with the items provided by the user, in the order they were typed.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::read_vec_until;
+/// let grades: Vec<f64> = read_vec_until(Some("Grade (or done): "), None, "done", 1..=30);
+///
+/// let hosts: Vec<String> = read_vec_until(Some("Host: "), None, "", ..);
+/// ```
+pub fn read_vec_until<T: FromStr>(
+    msg: Option<&str>,
+    err_msg: Option<&str>,
+    sentinel: &str,
+    count: impl RangeBounds<usize>,
+) -> Vec<T> {
+    let min = match count.start_bound() {
+        Bound::Included(&min) => min,
+        Bound::Excluded(&min) => min + 1,
+        Bound::Unbounded => 0,
+    };
+    let max = match count.end_bound() {
+        Bound::Included(&max) => Some(max),
+        Bound::Excluded(&max) => Some(max.saturating_sub(1)),
+        Bound::Unbounded => None,
+    };
+
+    // A dry run collects just the minimum number of placeholder items.
+    let dry_run = dry_run::is_dry_run();
+
+    let mut items = Vec::new();
+    while max.is_none_or(|max| items.len() < max) {
+        if dry_run && items.len() >= min {
+            outln!("{}{sentinel}", msg.unwrap_or_default());
+            return items;
+        }
+
+        let item = read_until_valid(msg, err_msg, |input| {
+            if input.eq_ignore_ascii_case(sentinel) && !dry_run {
+                Ok(None)
+            } else {
+                input
+                    .parse::<T>()
+                    .map(Some)
+                    .map_err(|_| String::from("Please enter a valid item."))
+            }
+        });
+
+        match item {
+            Some(item) => {
+                items.push(item);
+                outln!("{}", describe_count(items.len()));
+            }
+            None if items.len() < min => {
+                outln!("Please enter at least {}.", describe_items(min));
+            }
+            None => return items,
+        }
+    }
+
+    outln!("The list is full.");
+    items
+}
+
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as every input prompt. Must be set to Some("...") or None.
+///
+/// 'err_msg' (Option<&str>) - an optional error message which will be printed
+/// if the user inputs an invalid item. Must be set to Some("...") or None.
+///
+/// 'sentinel' (&str) - word which ends the list (Ex: "done"), compared without
+/// case-sensitivity. May be left empty to end the list with an empty line.
+///
+/// # DESCRIPTION #
+/// Works like read_vec_until, without limiting the number of items: the list ends as soon as
+/// the sentinel is typed, and may be empty.
+///
+/// # RETURNS #
+/// A Vec<T> with the items provided by the user, in the order they were typed.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::read_until;
+/// let items: Vec<String> = read_until(Some("Item (empty line to finish): "), None, "");
+///
+/// let scores: Vec<u32> = read_until(Some("Score (or done): "), None, "done");
+/// ```
+pub fn read_until<T: FromStr>(msg: Option<&str>, err_msg: Option<&str>, sentinel: &str) -> Vec<T> {
+    read_vec_until(msg, err_msg, sentinel, ..)
+}
+
+fn describe_items(count: usize) -> String {
+    match count {
+        1 => String::from("1 item"),
+        n => format!("{n} items"),
+    }
+}
+
+fn describe_count(count: usize) -> String {
+    format!("{} so far.", describe_items(count))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::MockInput;
+
+    #[test]
+    fn items_are_collected_until_the_sentinel() {
+        let mut mock = MockInput::new(["3", "x", "1", "DONE"]);
+        let items: Vec<u8> = mock.run(|| read_until(Some("Item: "), None, "done"));
+
+        assert_eq!(items, [3, 1]);
+        mock.assert_rejected("Please enter a valid item.");
+        mock.assert_all_answered();
+    }
+}
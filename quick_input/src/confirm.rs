@@ -0,0 +1,100 @@
+//! Iterators asking the user to confirm every item.
+
+use std::fmt::Display;
+
+use crate::read_until_valid;
+
+/// Answer given for one of the items of confirm_each.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Confirmation {
+    Yes,
+    No,
+    All,
+    Quit,
+}
+
+/// # ARGUMENTS #
+/// 'items' (impl IntoIterator) - items to confirm, which must implement Display
+/// (Ex: &Vec<String> or an iterator over file names).
+///
+/// # DESCRIPTION #
+/// Returns an iterator which asks the user about every item before yielding it
+/// ("old.log? (y / n / a / q): "), so only approved items reach the loop using it:
+///
+/// - y / yes: the item is yielded.
+/// - n / no: the item is skipped.
+/// - a / all: the item and every remaining one are yielded without asking again.
+/// - q / quit: the item and every remaining one are skipped.
+///
+/// Items are asked for one at a time, as the loop requests them, so the effect of each
+/// approval (Ex: a deleted file) is visible before the next question. In dry-run mode every
+/// item is skipped.
+///
+/// # RETURNS #
+/// An iterator over the items approved by the user.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::confirm_each;
+/// let files = vec![String::from("old.log"), String::from("cache.tmp")];
+///
+/// for file in confirm_each(&files) {
+///     std::fs::remove_file(file).unwrap();
+/// }
+/// ```
+pub fn confirm_each<I>(items: I) -> impl Iterator<Item = I::Item>
+where
+    I: IntoIterator,
+    I::Item: Display,
+{
+    let mut approve_all = false;
+    let mut quit = false;
+
+    items.into_iter().filter(move |item| {
+        if quit {
+            return false;
+        }
+        if approve_all {
+            return true;
+        }
+
+        let question = format!("{item}? (y / n / a / q): ");
+        match read_until_valid(Some(&question), None, parse_confirmation) {
+            Confirmation::Yes => true,
+            Confirmation::No => false,
+            Confirmation::All => {
+                approve_all = true;
+                true
+            }
+            Confirmation::Quit => {
+                quit = true;
+                false
+            }
+        }
+    })
+}
+
+fn parse_confirmation(input: &str) -> Result<Confirmation, String> {
+    match input.to_lowercase().as_str() {
+        "y" | "yes" => Ok(Confirmation::Yes),
+        "n" | "no" => Ok(Confirmation::No),
+        "a" | "all" => Ok(Confirmation::All),
+        "q" | "quit" => Ok(Confirmation::Quit),
+        _ => Err(String::from(
+            "Please answer yes, no, all or quit (y / n / a / q).",
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn answers_are_parsed() {
+        assert_eq!(parse_confirmation("Y"), Ok(Confirmation::Yes));
+        assert_eq!(parse_confirmation("all"), Ok(Confirmation::All));
+        assert_eq!(parse_confirmation("q"), Ok(Confirmation::Quit));
+        assert!(parse_confirmation("").is_err());
+    }
+}
@@ -0,0 +1,311 @@
+//! Calendar dates written in ISO 8601 format (YYYY-MM-DD) and ranges of them.
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{dry_run, read_until_valid};
+
+/// Separator between both dates of a range typed on a single line.
+const RANGE_SEPARATOR: &str = "..";
+
+/// Date used as the dry-run answer of the readers in this module.
+const DRY_RUN_DATE: &str = "2000-01-01";
+
+/// A date of the Gregorian calendar, between the years 1 and 9999.
+/// Dates are ordered chronologically and parsed from / displayed as YYYY-MM-DD.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date {
+    year: u16,
+    month: u8,
+    day: u8,
+}
+
+impl Date {
+    /// # ARGUMENTS #
+    /// 'year' (u16) - year, from 1 to 9999.
+    ///
+    /// 'month' (u8) - month, from 1 (January) to 12 (December).
+    ///
+    /// 'day' (u8) - day of the month, starting on 1.
+    ///
+    /// # RETURNS #
+    /// The Date, or None if it does not exist (Ex: 2023-02-29).
+    pub fn new(year: u16, month: u8, day: u8) -> Option<Date> {
+        let valid = (1..=9999).contains(&year)
+            && (1..=12).contains(&month)
+            && (1..=days_in_month(year, month)).contains(&day);
+
+        valid.then_some(Date { year, month, day })
+    }
+
+    /// Year of the date.
+    pub fn year(self) -> u16 {
+        self.year
+    }
+
+    /// Month of the date, from 1 (January) to 12 (December).
+    pub fn month(self) -> u8 {
+        self.month
+    }
+
+    /// Day of the month.
+    pub fn day(self) -> u8 {
+        self.day
+    }
+
+    /// # RETURNS #
+    /// The number of days from this date to 'other', which is negative if 'other' is earlier.
+    pub fn days_until(self, other: Date) -> i64 {
+        other.days_since_epoch() - self.days_since_epoch()
+    }
+
+    /// Number of days since 0000-03-01, counted as in the proleptic Gregorian calendar.
+    fn days_since_epoch(self) -> i64 {
+        // Years start in March, so the leap day is the last day of the year.
+        let (year, month) = match self.month {
+            1 | 2 => (i64::from(self.year) - 1, i64::from(self.month) + 9),
+            _ => (i64::from(self.year), i64::from(self.month) - 3),
+        };
+        let day_of_year = (153 * month + 2) / 5 + i64::from(self.day) - 1;
+
+        year * 365 + year / 4 - year / 100 + year / 400 + day_of_year
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+impl FromStr for Date {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Date, String> {
+        let format_error =
+            || format!("'{input}' is not a date in YYYY-MM-DD format (Ex: 2024-01-31).");
+
+        let mut components = input.split('-');
+        let (Some(year), Some(month), Some(day), None) = (
+            components.next(),
+            components.next(),
+            components.next(),
+            components.next(),
+        ) else {
+            return Err(format_error());
+        };
+
+        let digits = |text: &str, len: usize| {
+            text.len() == len && text.bytes().all(|byte| byte.is_ascii_digit())
+        };
+        if !digits(year, 4) || !digits(month, 2) || !digits(day, 2) {
+            return Err(format_error());
+        }
+
+        let (year, month, day) = (
+            year.parse().map_err(|_| format_error())?,
+            month.parse().map_err(|_| format_error())?,
+            day.parse().map_err(|_| format_error())?,
+        );
+
+        Date::new(year, month, day).ok_or_else(|| format!("The date {input} does not exist."))
+    }
+}
+
+/// A range of dates, including both the start and the end.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DateRange {
+    pub start: Date,
+    pub end: Date,
+}
+
+impl DateRange {
+    /// Number of days in the range, counting both the start and the end.
+    pub fn days(&self) -> u32 {
+        u32::try_from(self.start.days_until(self.end) + 1).unwrap_or(0)
+    }
+}
+
+impl fmt::Display for DateRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{RANGE_SEPARATOR}{}", self.start, self.end)
+    }
+}
+
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as the input prompt. Must be set to Some("...") or None.
+///
+/// 'err_msg' (Option<&str>) - an optional error message which will be printed
+/// if the user inputs an invalid value. Must be set to Some("...") or None.
+///
+/// 'max_days' (Option<u32>) - maximum number of days the range may include, counting both
+/// the start and the end. Must be set to Some(...) or None.
+///
+/// # DESCRIPTION #
+/// Prompts the user to type a range of dates on a single line, as START..END in YYYY-MM-DD
+/// format (Ex: 2024-01-01..2024-01-31). Both dates are included in the range, the end cannot
+/// be earlier than the start and, if 'max_days' is set, the range cannot be longer than that.
+/// In case the user writes an invalid value, they will be prompted to try again.
+///
+/// Provides an information message on the same line as the prompt if Some("...")
+/// is provided, and just the prompt if None is provided.
+///
+/// If err_msg is set to None, a default message explaining the problem will be shown.
+///
+/// # RETURNS #
+/// The DateRange typed by the user.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::read_date_range;
+/// let period = read_date_range(Some("Report period (START..END): "), None, Some(366));
+///
+/// println!("Reporting {} days, from {} to {}.", period.days(), period.start, period.end);
+/// ```
+pub fn read_date_range(
+    msg: Option<&str>,
+    err_msg: Option<&str>,
+    max_days: Option<u32>,
+) -> DateRange {
+    let parse = |input: &str| parse_range(input, max_days);
+
+    if dry_run::is_dry_run() {
+        let hint = format!("{DRY_RUN_DATE}{RANGE_SEPARATOR}{DRY_RUN_DATE}");
+        return dry_run::placeholder(msg, &[hint], parse);
+    }
+
+    read_until_valid(msg, err_msg, parse)
+}
+
+/// # ARGUMENTS #
+/// 'start_msg' (Option<&str>) - an optional message printed when asking for the start date.
+/// Must be set to Some("...") or None.
+///
+/// 'end_msg' (Option<&str>) - an optional message printed when asking for the end date.
+/// Must be set to Some("...") or None.
+///
+/// 'err_msg' (Option<&str>) - an optional error message which will be printed
+/// if the user inputs an invalid value. Must be set to Some("...") or None.
+///
+/// 'max_days' (Option<u32>) - maximum number of days the range may include, counting both
+/// the start and the end. Must be set to Some(...) or None.
+///
+/// # DESCRIPTION #
+/// Works like read_date_range, but asks for the start and the end dates with two separate
+/// prompts. If the end date is not valid for the chosen start (Ex: it is earlier), only the
+/// end date is asked again.
+///
+/// # RETURNS #
+/// The DateRange typed by the user.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::read_date_range_split;
+/// let holidays = read_date_range_split(Some("First day: "), Some("Last day: "), None, Some(30));
+/// ```
+pub fn read_date_range_split(
+    start_msg: Option<&str>,
+    end_msg: Option<&str>,
+    err_msg: Option<&str>,
+    max_days: Option<u32>,
+) -> DateRange {
+    let start = read_date(start_msg, err_msg, Date::from_str);
+    let end = read_date(end_msg, err_msg, |input| {
+        let end = input.parse()?;
+        check_range(DateRange { start, end }, max_days).map(|range| range.end)
+    });
+
+    DateRange { start, end }
+}
+
+fn read_date(
+    msg: Option<&str>,
+    err_msg: Option<&str>,
+    parse: impl Fn(&str) -> Result<Date, String>,
+) -> Date {
+    if dry_run::is_dry_run() {
+        return dry_run::placeholder(msg, &[String::from(DRY_RUN_DATE)], parse);
+    }
+
+    read_until_valid(msg, err_msg, parse)
+}
+
+/// # Arguments #
+/// 'input' (&str) - Trimmed input typed by the user.
+///
+/// 'max_days' (Option<u32>) - Maximum number of days of the range, if any.
+///
+/// # Description #
+/// Private function parsing a range written as START..END.
+fn parse_range(input: &str, max_days: Option<u32>) -> Result<DateRange, String> {
+    let (start, end) = input.split_once(RANGE_SEPARATOR).ok_or_else(|| {
+        String::from("Please enter two dates separated by '..' (Ex: 2024-01-01..2024-01-31).")
+    })?;
+
+    let range = DateRange {
+        start: start.trim().parse()?,
+        end: end.trim().parse()?,
+    };
+
+    check_range(range, max_days)
+}
+
+fn check_range(range: DateRange, max_days: Option<u32>) -> Result<DateRange, String> {
+    if range.end < range.start {
+        return Err(format!(
+            "The end date ({}) cannot be earlier than the start date ({}).",
+            range.end, range.start
+        ));
+    }
+
+    match max_days {
+        Some(max_days) if range.days() > max_days => Err(format!(
+            "The range includes {} days, but at most {max_days} are allowed.",
+            range.days()
+        )),
+        _ => Ok(range),
+    }
+}
+
+fn days_in_month(year: u16, month: u8) -> u8 {
+    match month {
+        4 | 6 | 9 | 11 => 30,
+        2 if year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400)) => {
+            29
+        }
+        2 => 28,
+        _ => 31,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dates_are_validated() {
+        assert_eq!(
+            "2024-02-29".parse(),
+            Ok(Date {
+                year: 2024,
+                month: 2,
+                day: 29
+            })
+        );
+        assert!("2023-02-29".parse::<Date>().is_err());
+        assert!("2024-1-31".parse::<Date>().is_err());
+        assert!("0000-01-01".parse::<Date>().is_err());
+    }
+
+    #[test]
+    fn ranges_are_checked() {
+        let range = parse_range("2024-01-01..2024-03-01", None).unwrap();
+        assert_eq!(range.days(), 61);
+        assert_eq!(range.to_string(), "2024-01-01..2024-03-01");
+
+        assert!(parse_range("2024-01-02..2024-01-01", None).is_err());
+        assert!(parse_range("2024-01-01..2024-03-01", Some(60)).is_err());
+        assert!(parse_range("2024-01-01", None).is_err());
+    }
+}
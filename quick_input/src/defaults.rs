@@ -0,0 +1,224 @@
+//! Readers returning a default value when the user just presses Enter.
+
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::{parse_real, read_until_valid};
+
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as the input prompt. Must be set to Some("...") or None.
+///
+/// 'err_msg' (Option<&str>) - an optional error message which will be printed
+/// if the user inputs an invalid value. Must be set to Some("...") or None.
+///
+/// 'default' (T) - value returned if the user presses Enter without typing anything.
+///
+/// # DESCRIPTION #
+/// Prompts the user to type a value of type T which will then be returned, or nothing to
+/// accept the default. The default is shown between brackets before the trailing ':' of the
+/// message (Ex: "Port: " becomes "Port [8080]: "), as installers usually do.
+/// In case the user writes an invalid value, they will be prompted to try again.
+///
+/// If err_msg is set to None, a default message will be shown.
+///
+/// # RETURNS #
+/// The value of type T provided by the user, or the default.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::read_or;
+/// let port: u16 = read_or(Some("Port: "), None, 8080);
+///
+/// let ratio = read_or(Some("Ratio: "), None, 0.5_f32);
+/// ```
+pub fn read_or<T: FromStr + Display>(msg: Option<&str>, err_msg: Option<&str>, default: T) -> T {
+    read_with_default(msg, err_msg, default, |input| {
+        input
+            .parse()
+            .map_err(|_| String::from("Please enter a valid value"))
+    })
+}
+
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as the input prompt. Must be set to Some("...") or None.
+///
+/// 'err_msg' (Option<&str>) - an optional error message which will be printed
+/// if the user inputs an invalid value. Must be set to Some("...") or None.
+///
+/// 'default' (i32) - value returned if the user presses Enter without typing anything.
+///
+/// # DESCRIPTION #
+/// Prompts the user to type an integer value (i32) which will then be returned, or nothing
+/// to accept the default, which is shown in the message as "[42]".
+/// In case the user writes an invalid value, they will be prompted to try again.
+///
+/// If err_msg is set to None, a default message will be shown.
+///
+/// # RETURNS #
+/// An integer value of type i32 provided by the user, or the default.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::read_i32_or;
+/// let offset = read_i32_or(Some("UTC offset: "), None, 0);
+/// ```
+pub fn read_i32_or(msg: Option<&str>, err_msg: Option<&str>, default: i32) -> i32 {
+    read_with_default(msg, err_msg, default, |input| {
+        input
+            .parse()
+            .map_err(|_| String::from("Please enter a valid number (32 bits)"))
+    })
+}
+
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as the input prompt. Must be set to Some("...") or None.
+///
+/// 'err_msg' (Option<&str>) - an optional error message which will be printed
+/// if the user inputs an invalid value. Must be set to Some("...") or None.
+///
+/// 'default' (u32) - value returned if the user presses Enter without typing anything.
+///
+/// # DESCRIPTION #
+/// Prompts the user to type an integer value (u32) which will then be returned, or nothing
+/// to accept the default, which is shown in the message as "[42]".
+/// In case the user writes an invalid value, they will be prompted to try again.
+///
+/// If err_msg is set to None, a default message will be shown.
+///
+/// # RETURNS #
+/// An integer value of type u32 provided by the user, or the default.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::read_u32_or;
+/// let workers = read_u32_or(Some("Number of workers: "), None, 4);
+///
+/// let retries: u32 = read_u32_or(None, Some("Please input a valid number."), 3);
+/// ```
+pub fn read_u32_or(msg: Option<&str>, err_msg: Option<&str>, default: u32) -> u32 {
+    read_with_default(msg, err_msg, default, |input| {
+        input
+            .parse()
+            .map_err(|_| String::from("Please enter a valid positive number (32 bits)"))
+    })
+}
+
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as the input prompt. Must be set to Some("...") or None.
+///
+/// 'err_msg' (Option<&str>) - an optional error message which will be printed
+/// if the user inputs an invalid value. Must be set to Some("...") or None.
+///
+/// 'default' (f64) - value returned if the user presses Enter without typing anything.
+///
+/// # DESCRIPTION #
+/// Prompts the user to type a real number with double precision (f64) which will then be
+/// returned, or nothing to accept the default, which is shown in the message as "[0.5]".
+/// Like read_f64, both '.' and ',' are accepted as separators for the decimal part.
+/// In case the user writes an invalid value, they will be prompted to try again.
+///
+/// If err_msg is set to None, a default message will be shown.
+///
+/// # RETURNS #
+/// A floating point value of type f64 provided by the user, or the default.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::read_f64_or;
+/// let tax = read_f64_or(Some("Tax rate: "), None, 0.21);
+/// ```
+pub fn read_f64_or(msg: Option<&str>, err_msg: Option<&str>, default: f64) -> f64 {
+    read_with_default(msg, err_msg, default, |input| {
+        parse_real(input, true, "Please enter a valid real number (64 bits)")
+    })
+}
+
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as the input prompt. Must be set to Some("...") or None.
+///
+/// 'default' (&str) - text returned if the user presses Enter without typing anything.
+///
+/// # DESCRIPTION #
+/// Prompts the user to type a string of text which will then be returned, or nothing to
+/// accept the default, which is shown in the message between brackets.
+///
+/// # RETURNS #
+/// A trimmed String value provided by the user, or the default.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::read_string_or;
+/// let branch = read_string_or(Some("Branch: "), "main");
+/// ```
+pub fn read_string_or(msg: Option<&str>, default: &str) -> String {
+    read_with_default(
+        msg,
+        None,
+        default.to_string(),
+        |input| Ok(input.to_string()),
+    )
+}
+
+/// # Arguments #
+/// 'default' (T) - Value returned for an empty line.
+///
+/// 'parse' (impl Fn(&str) -> Result<T, String>) - Closure parsing a non-empty input, whose
+/// error is completed with a reminder of the default.
+///
+/// # Description #
+/// Private function shared by the readers of this module.
+fn read_with_default<T: Display>(
+    msg: Option<&str>,
+    err_msg: Option<&str>,
+    default: T,
+    parse: impl Fn(&str) -> Result<T, String>,
+) -> T {
+    let shown = default.to_string();
+    let msg = show_default(msg.unwrap_or_default(), &shown);
+
+    let value = read_until_valid(Some(&msg), err_msg, |input| {
+        if input.is_empty() {
+            return Ok(None);
+        }
+        parse(input)
+            .map(Some)
+            .map_err(|err| format!("{err}, or press Enter for {shown}."))
+    });
+    value.unwrap_or(default)
+}
+
+/// # Arguments #
+/// 'msg' (&str) - Message of the prompt.
+///
+/// 'value' (&str) - Default value to show.
+///
+/// # Description #
+/// Private function adding the default value between brackets before the trailing ':'
+/// of the message, if there is one (Ex: "Port: " becomes "Port [8080]: ").
+pub(crate) fn show_default(msg: &str, value: &str) -> String {
+    let trimmed = msg.trim_end();
+    let trailing = &msg[trimmed.len()..];
+
+    match trimmed.strip_suffix(':') {
+        Some(base) => format!("{base} [{value}]:{trailing}"),
+        None if trimmed.is_empty() => format!("[{value}] "),
+        None => format!("{trimmed} [{value}]{trailing}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn defaults_are_shown_in_the_message() {
+        assert_eq!(show_default("Port: ", "8080"), "Port [8080]: ");
+        assert_eq!(show_default("Port", "8080"), "Port [8080]");
+        assert_eq!(show_default("", "8080"), "[8080] ");
+    }
+}
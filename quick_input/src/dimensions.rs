@@ -0,0 +1,123 @@
+//! Readers for two-dimensional sizes (Ex: 1920x1080).
+
+use std::ops::{Bound, RangeBounds};
+
+use crate::prompt::describe_bounds;
+use crate::{dry_run, read_until_valid};
+
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as the input prompt. Must be set to Some("...") or None.
+///
+/// 'err_msg' (Option<&str>) - an optional error message which will be printed
+/// if the user inputs an invalid value. Must be set to Some("...") or None.
+///
+/// 'width' (impl RangeBounds<u32>) - range the width must be within (Ex: 1..=7680, ..).
+///
+/// 'height' (impl RangeBounds<u32>) - range the height must be within (Ex: 1..=4320, ..).
+///
+/// # DESCRIPTION #
+/// Prompts the user to type a size as WIDTHxHEIGHT (Ex: 1920x1080), which suits image,
+/// video or window dimensions. The separator may also be an uppercase 'X' or '×', and spaces
+/// around it are ignored. Each component is checked against its own range.
+/// In case the user writes an invalid value, they will be prompted to try again.
+///
+/// Provides an information message on the same line as the prompt if Some("...")
+/// is provided, and just the prompt if None is provided.
+///
+/// If err_msg is set to None, a default message explaining the problem will be shown.
+///
+/// # RETURNS #
+/// A tuple (u32, u32) with the width and the height typed by the user.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::read_size2d;
+/// let (width, height) = read_size2d(Some("Resolution (WxH): "), None, 1..=7680, 1..=4320);
+///
+/// let thumbnail = read_size2d(Some("Thumbnail size: "), None, 16..=512, 16..=512);
+/// ```
+pub fn read_size2d(
+    msg: Option<&str>,
+    err_msg: Option<&str>,
+    width: impl RangeBounds<u32>,
+    height: impl RangeBounds<u32>,
+) -> (u32, u32) {
+    let parse = |input: &str| parse_size(input, &width, &height);
+
+    if dry_run::is_dry_run() {
+        let hint = format!("{}x{}", smallest(&width), smallest(&height));
+        return dry_run::placeholder(msg, &[hint], parse);
+    }
+
+    read_until_valid(msg, err_msg, parse)
+}
+
+/// # Arguments #
+/// 'input' (&str) - Trimmed input typed by the user.
+///
+/// 'width' / 'height' (&impl RangeBounds<u32>) - Ranges of both components.
+///
+/// # Description #
+/// Private function parsing a size written as WIDTHxHEIGHT and checking both components.
+fn parse_size(
+    input: &str,
+    width: &impl RangeBounds<u32>,
+    height: &impl RangeBounds<u32>,
+) -> Result<(u32, u32), String> {
+    let (width_text, height_text) = input
+        .split_once(['x', 'X', '×'])
+        .ok_or("Please enter a size as WIDTHxHEIGHT (Ex: 1920x1080).")?;
+
+    let width_value = parse_component(width_text, "width", width)?;
+    let height_value = parse_component(height_text, "height", height)?;
+    Ok((width_value, height_value))
+}
+
+fn parse_component(text: &str, name: &str, range: &impl RangeBounds<u32>) -> Result<u32, String> {
+    let text = text.trim();
+    if text.is_empty() || !text.bytes().all(|byte| byte.is_ascii_digit()) {
+        return Err(format!(
+            "The {name} must be a whole number (Ex: 1920x1080)."
+        ));
+    }
+
+    let value = text
+        .parse()
+        .map_err(|_| format!("The {name} {text} is too large."))?;
+    if !range.contains(&value) {
+        return Err(format!(
+            "The {name} must be {}, but {value} was entered.",
+            describe_bounds(range)
+        ));
+    }
+    Ok(value)
+}
+
+/// Smallest value within the range, used as the dry-run answer.
+fn smallest(range: &impl RangeBounds<u32>) -> u32 {
+    match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start.saturating_add(1),
+        Bound::Unbounded => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sizes_are_parsed_and_checked() {
+        assert_eq!(parse_size("1920x1080", &.., &..), Ok((1920, 1080)));
+        assert_eq!(parse_size("640 X 480", &.., &..), Ok((640, 480)));
+        assert_eq!(parse_size("800×600", &(1..), &(1..)), Ok((800, 600)));
+
+        assert!(parse_size("1920", &.., &..).is_err());
+        assert!(parse_size("1920x", &.., &..).is_err());
+        assert!(parse_size("-1x10", &.., &..).is_err());
+        assert!(parse_size("1920x1080x3", &.., &..).is_err());
+        assert!(parse_size("0x100", &(1..=100), &(1..=100)).is_err());
+        assert!(parse_size("100x101", &(1..=100), &(1..=100)).is_err());
+    }
+}
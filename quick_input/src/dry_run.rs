@@ -0,0 +1,113 @@
+//! Dry-run mode, used to preview the flow of a program without typing any answer.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use crate::reader::outln;
+
+static DRY_RUN: AtomicBool = AtomicBool::new(false);
+
+/// Answers tried, in order, until one is accepted by the reader. There is no "y",
+/// so confirmations asked by the program are declined.
+const PLACEHOLDERS: &[&str] = &[
+    "",
+    "0",
+    "1",
+    "n",
+    "false",
+    "A",
+    "user",
+    "*",
+    "localhost:8080",
+    "127.0.0.1:8080",
+    "UTC",
+    "en",
+    "US",
+    "en-US",
+    "USD",
+];
+
+/// # ARGUMENTS #
+/// 'enabled' (bool) - whether dry-run mode is turned on or off.
+///
+/// # DESCRIPTION #
+/// In dry-run mode, every read function prints its prompt and returns straight away
+/// without waiting for the user. Prompts with a default value return it, and every other
+/// reader returns a placeholder suited to its type (Ex: 0 for numbers, false for booleans),
+/// which is printed after the prompt. This allows previewing the whole flow of a wizard quickly.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::{read_i32, set_dry_run};
+/// set_dry_run(std::env::args().any(|arg| arg == "--dry-run"));
+///
+/// let age = read_i32(Some("Age: "), None);
+/// ```
+pub fn set_dry_run(enabled: bool) {
+    DRY_RUN.store(enabled, Ordering::Relaxed);
+}
+
+/// # RETURNS #
+/// True if dry-run mode is turned on (see set_dry_run).
+pub fn is_dry_run() -> bool {
+    DRY_RUN.load(Ordering::Relaxed)
+}
+
+/// # Arguments #
+/// 'msg' (Option<&str>) - Message printed before the placeholder, like the input prompt.
+///
+/// 'hints' (&[String]) - Answers known to suit this reader (Ex: the start of a range),
+/// tried after the generic placeholders.
+///
+/// 'accept' (impl Fn(&str) -> Result<T, String>) - Closure parsing and validating an answer,
+/// the same one the reader would use with the user's input.
+///
+/// # Description #
+/// Private function returning the first placeholder accepted by the reader, printed after
+/// 'msg' as if the user had typed it.
+///
+/// Panics if no placeholder is accepted, since the program would otherwise loop forever.
+pub(crate) fn placeholder<T>(
+    msg: Option<&str>,
+    hints: &[String],
+    accept: impl Fn(&str) -> Result<T, String>,
+) -> T {
+    let msg = msg.unwrap_or_default();
+    let hints = hints.iter().map(String::as_str);
+
+    for placeholder in PLACEHOLDERS.iter().copied().chain(hints) {
+        if let Ok(value) = accept(placeholder) {
+            outln!("{msg}{placeholder}");
+            return value;
+        }
+    }
+
+    panic!(
+        "Dry run: no placeholder answer is accepted by the prompt '{}'. Try giving it a default value.",
+        msg.trim()
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn first_accepted_placeholder_is_used() {
+        assert_eq!(
+            placeholder(None, &[], |input| input
+                .parse::<i32>()
+                .map_err(|_| String::new())),
+            0
+        );
+        assert!(!placeholder(None, &[], |input| input
+            .parse::<bool>()
+            .map_err(|_| String::new())));
+
+        let hints = [String::from("1024")];
+        let port = placeholder(None, &hints, |input| match input.parse::<u16>() {
+            Ok(port) if port >= 1024 => Ok(port),
+            _ => Err(String::new()),
+        });
+        assert_eq!(port, 1024);
+    }
+}
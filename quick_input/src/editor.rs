@@ -0,0 +1,334 @@
+//! A minimal line editor, used to let the user fix a previous answer instead of retyping it.
+//!
+//! Supported keys: printable characters, Backspace, Delete, Left / Right arrows, Home / End
+//! (also Ctrl+A / Ctrl+E), Ctrl+U (clear the line), Enter, Ctrl+D (end of input on an empty
+//! line) and Ctrl+C (interrupt).
+//!
+//! Masked input (Ex: passwords shown as ****) reads keys the same way, but only supports
+//! printable characters, Backspace, Ctrl+U, Enter, Ctrl+D and Ctrl+C.
+
+use std::io::{self, Read, Write};
+
+use crate::{input, protocol, render, term, tty};
+
+const CTRL_A: u8 = 0x01;
+const CTRL_C: u8 = 0x03;
+const CTRL_D: u8 = 0x04;
+const CTRL_E: u8 = 0x05;
+const CTRL_U: u8 = 0x15;
+const BACKSPACE: u8 = 0x7f;
+const CTRL_H: u8 = 0x08;
+const ESCAPE: u8 = 0x1b;
+
+/// Action requested by a key press.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Key {
+    Insert(char),
+    Backspace,
+    Delete,
+    Left,
+    Right,
+    Home,
+    End,
+    Clear,
+    Enter,
+    EndOfInput,
+    Interrupt,
+    Ignored,
+}
+
+/// # Arguments #
+/// 'msg' (&str) - Prompt message, already printed, which is redrawn along with the line.
+///
+/// 'initial' (&str) - Text the line starts with, with the cursor placed at its end.
+///
+/// 'input' (&mut String) - String the edited line is appended to.
+///
+/// 'cancellable' (bool) - Whether Ctrl+C cancels the prompt (returning an
+/// io::ErrorKind::Interrupted error) instead of interrupting the program.
+///
+/// # Description #
+/// Lets the user edit 'initial' on the terminal until Enter is pressed.
+///
+/// # Returns #
+/// None if the line can't be edited (Ex: stdin is not a terminal), in which case the caller
+/// should read the line as usual. Otherwise, the number of bytes read like input::read_line,
+/// which is 0 if the input was closed.
+pub(crate) fn edit_line(
+    msg: &str,
+    initial: &str,
+    input: &mut String,
+    cancellable: bool,
+) -> Option<io::Result<usize>> {
+    if input::is_background()
+        || render::is_custom()
+        || protocol::is_protocol_mode()
+        || !term::supports_cursor_movement()
+    {
+        return None;
+    }
+
+    let raw_mode = tty::RawMode::start()?;
+    let prompt = msg.rsplit('\n').next().unwrap_or_default();
+
+    let mut stdin = io::stdin().lock();
+    let mut stdout = io::stdout().lock();
+    let mut line = LineBuffer::new(initial);
+
+    let result = (|| loop {
+        line.redraw(prompt, &mut stdout)?;
+
+        match read_key(&mut stdin)? {
+            Key::Enter => return Ok(Some(line.text())),
+            Key::EndOfInput if line.chars.is_empty() => return Ok(None),
+            Key::Interrupt => return Err(io::Error::from(io::ErrorKind::Interrupted)),
+            key => line.apply(key),
+        }
+    })();
+
+    drop(raw_mode);
+    Some(finish(result, input, cancellable, stdout))
+}
+
+/// # Arguments #
+/// 'mask' (char) - Character printed instead of each typed character.
+///
+/// 'input' (&mut String) - String the line typed by the user is appended to.
+///
+/// 'cancellable' (bool) - Whether Ctrl+C cancels the prompt (returning an
+/// io::ErrorKind::Interrupted error) instead of interrupting the program.
+///
+/// # Description #
+/// Reads a line from the terminal, printing 'mask' for every typed character instead of
+/// the character itself. The prompt must already be printed.
+///
+/// # Returns #
+/// None if the keys can't be read one by one (Ex: stdin is not a terminal), in which case the
+/// caller should read the line as usual. Otherwise, the number of bytes read, which is 0 if
+/// the input was closed.
+pub(crate) fn read_masked(
+    mask: char,
+    input: &mut String,
+    cancellable: bool,
+) -> Option<io::Result<usize>> {
+    if input::is_background()
+        || render::is_custom()
+        || protocol::is_protocol_mode()
+        || !term::supports_raw_mode()
+    {
+        return None;
+    }
+
+    let raw_mode = tty::RawMode::start()?;
+
+    let mut stdin = io::stdin().lock();
+    let mut stdout = io::stdout().lock();
+    let mut line = String::new();
+
+    let result = (|| loop {
+        match read_key(&mut stdin)? {
+            Key::Enter => return Ok(Some(line.clone())),
+            Key::EndOfInput if line.is_empty() => return Ok(None),
+            Key::Interrupt => return Err(io::Error::from(io::ErrorKind::Interrupted)),
+            Key::Insert(c) => {
+                line.push(c);
+                write!(stdout, "{mask}")?;
+            }
+            Key::Backspace if line.pop().is_some() => write!(stdout, "\x08 \x08")?,
+            Key::Clear => {
+                for _ in line.drain(..) {
+                    write!(stdout, "\x08 \x08")?;
+                }
+            }
+            _ => {}
+        }
+        stdout.flush()?;
+    })();
+
+    drop(raw_mode);
+    Some(finish(result, input, cancellable, stdout))
+}
+
+/// Ends a line read in raw mode: moves to the next line, appends the line read (if any) to
+/// 'input' and handles Ctrl+C, raising the interrupt unless the prompt is cancellable.
+fn finish(
+    result: io::Result<Option<String>>,
+    input: &mut String,
+    cancellable: bool,
+    mut stdout: io::StdoutLock,
+) -> io::Result<usize> {
+    let _ = write!(stdout, "\r\n").and_then(|_| stdout.flush());
+
+    match result {
+        Ok(Some(line)) => {
+            input.push_str(&line);
+            input.push('\n');
+            Ok(line.len() + 1)
+        }
+        Ok(None) => Ok(0),
+        Err(err) if err.kind() == io::ErrorKind::Interrupted && !cancellable => {
+            // If the program survives the interrupt, it gets an empty answer.
+            drop(stdout);
+            tty::raise_interrupt();
+            input.push('\n');
+            Ok(1)
+        }
+        Err(err) => Err(err),
+    }
+}
+
+/// Characters of the line being edited and the position of the cursor.
+struct LineBuffer {
+    chars: Vec<char>,
+    cursor: usize,
+}
+
+impl LineBuffer {
+    fn new(initial: &str) -> Self {
+        let chars: Vec<char> = initial.chars().collect();
+        LineBuffer {
+            cursor: chars.len(),
+            chars,
+        }
+    }
+
+    fn text(&self) -> String {
+        self.chars.iter().collect()
+    }
+
+    fn apply(&mut self, key: Key) {
+        match key {
+            Key::Insert(c) => {
+                self.chars.insert(self.cursor, c);
+                self.cursor += 1;
+            }
+            Key::Backspace if self.cursor > 0 => {
+                self.cursor -= 1;
+                self.chars.remove(self.cursor);
+            }
+            Key::Delete if self.cursor < self.chars.len() => {
+                self.chars.remove(self.cursor);
+            }
+            Key::Left => self.cursor = self.cursor.saturating_sub(1),
+            Key::Right => self.cursor = (self.cursor + 1).min(self.chars.len()),
+            Key::Home => self.cursor = 0,
+            Key::End => self.cursor = self.chars.len(),
+            Key::Clear => {
+                self.chars.clear();
+                self.cursor = 0;
+            }
+            _ => {}
+        }
+    }
+
+    fn redraw(&self, prompt: &str, out: &mut impl Write) -> io::Result<()> {
+        // Back to the start of the line, print everything and clear what is left.
+        write!(out, "\r{prompt}{}\x1b[K", self.text())?;
+
+        let back = self.chars.len() - self.cursor;
+        if back > 0 {
+            write!(out, "\x1b[{back}D")?;
+        }
+        out.flush()
+    }
+}
+
+fn read_byte(input: &mut impl Read) -> io::Result<Option<u8>> {
+    let mut byte = [0];
+    match input.read(&mut byte)? {
+        0 => Ok(None),
+        _ => Ok(Some(byte[0])),
+    }
+}
+
+fn read_key(input: &mut impl Read) -> io::Result<Key> {
+    let Some(byte) = read_byte(input)? else {
+        return Ok(Key::EndOfInput);
+    };
+
+    Ok(match byte {
+        b'\r' | b'\n' => Key::Enter,
+        BACKSPACE | CTRL_H => Key::Backspace,
+        CTRL_A => Key::Home,
+        CTRL_E => Key::End,
+        CTRL_U => Key::Clear,
+        CTRL_D => Key::EndOfInput,
+        CTRL_C => Key::Interrupt,
+        ESCAPE => read_escape_sequence(input)?,
+        byte if byte < 0x20 => Key::Ignored,
+        byte => read_char(input, byte)?.map_or(Key::Ignored, Key::Insert),
+    })
+}
+
+fn read_escape_sequence(input: &mut impl Read) -> io::Result<Key> {
+    let key = match read_byte(input)? {
+        Some(b'[') => match read_byte(input)? {
+            Some(b'C') => Key::Right,
+            Some(b'D') => Key::Left,
+            Some(b'H') => Key::Home,
+            Some(b'F') => Key::End,
+            Some(digit @ b'0'..=b'9') => {
+                // Sequences such as ESC [ 3 ~ (Delete): skip everything up to the '~'.
+                while !matches!(read_byte(input)?, Some(b'~') | None) {}
+                match digit {
+                    b'1' | b'7' => Key::Home,
+                    b'4' | b'8' => Key::End,
+                    b'3' => Key::Delete,
+                    _ => Key::Ignored,
+                }
+            }
+            _ => Key::Ignored,
+        },
+        Some(b'O') => match read_byte(input)? {
+            Some(b'H') => Key::Home,
+            Some(b'F') => Key::End,
+            _ => Key::Ignored,
+        },
+        _ => Key::Ignored,
+    };
+
+    Ok(key)
+}
+
+/// Reads the rest of a UTF-8 encoded character starting with 'first'.
+fn read_char(input: &mut impl Read, first: u8) -> io::Result<Option<char>> {
+    let len = match first {
+        0x00..=0x7f => 1,
+        0xc0..=0xdf => 2,
+        0xe0..=0xef => 3,
+        0xf0..=0xf7 => 4,
+        _ => return Ok(None),
+    };
+
+    let mut bytes = vec![first];
+    for _ in 1..len {
+        match read_byte(input)? {
+            Some(byte) => bytes.push(byte),
+            None => return Ok(None),
+        }
+    }
+
+    Ok(std::str::from_utf8(&bytes)
+        .ok()
+        .and_then(|text| text.chars().next()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keys_edit_the_line() {
+        let mut input: &[u8] = b"\x1b[D\x1b[DX\x7f\x7f\x1b[3~\x01\xc3\xb1\r";
+        let mut line = LineBuffer::new("port=80800");
+
+        loop {
+            match read_key(&mut input).unwrap() {
+                Key::Enter => break,
+                key => line.apply(key),
+            }
+        }
+
+        assert_eq!(line.text(), "ñport=800");
+    }
+}
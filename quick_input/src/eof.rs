@@ -0,0 +1,101 @@
+//! What the readers which can't return an error do once the input ends.
+
+use std::process;
+use std::sync::RwLock;
+
+/// What the readers which ask until a valid value is typed (read_i32, Prompt::read...) do
+/// when the input ends before the user answers (Ex: Ctrl+D, or the end of a piped file),
+/// since they have no value to return. Fallible readers (try_read, Prompt::try_read...)
+/// return InputError::Eof instead, and read_string and read_string_untrimmed, which never
+/// ask again, return an empty String.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum EofPolicy {
+    /// Panics with a message explaining that the input ended (the default).
+    #[default]
+    Panic,
+    /// Prints a message explaining that the input ended to stderr, and exits the process
+    /// with the given exit code.
+    Exit(i32),
+}
+
+static EOF_POLICY: RwLock<EofPolicy> = RwLock::new(EofPolicy::Panic);
+
+/// # ARGUMENTS #
+/// 'policy' (EofPolicy) - what to do when the input ends before an answer is typed.
+///
+/// # DESCRIPTION #
+/// Changes what every reader which can't return an error does when the input ends, instead
+/// of panicking. Exiting suits command-line programs, whose users expect Ctrl+D to close them
+/// quietly.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::{EofPolicy, read_i32, set_eof_policy};
+/// set_eof_policy(EofPolicy::Exit(1));
+///
+/// let age = read_i32(Some("Age: "), None);
+/// ```
+pub fn set_eof_policy(policy: EofPolicy) {
+    *EOF_POLICY
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner()) = policy;
+}
+
+/// # Arguments #
+/// 'msg' (&str) - Message of the prompt which was not answered, which may be empty.
+///
+/// # Description #
+/// Private function applying the policy set with set_eof_policy once the input ended.
+pub(crate) fn reached(msg: &str) -> ! {
+    let policy = *EOF_POLICY
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    let message = match msg.trim() {
+        "" => String::from("The input ended before an answer was typed."),
+        msg => format!("The input ended before the prompt '{msg}' was answered."),
+    };
+
+    match policy {
+        EofPolicy::Panic => panic!("{message}"),
+        EofPolicy::Exit(code) => {
+            eprintln!("\n{message}");
+            process::exit(code);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{InputError, Prompt, Reader, read_string, read_string_untrimmed};
+    use std::io::Cursor;
+
+    #[test]
+    #[should_panic(expected = "The input ended before the prompt 'Age:' was answered.")]
+    fn looping_readers_stop_at_the_end_of_the_input() {
+        let mut reader = Reader::new(Cursor::new("old\n"), Vec::new());
+        reader.read_i32(Some("Age: "), None);
+    }
+
+    #[test]
+    fn string_readers_return_an_empty_string() {
+        let mut reader = Reader::new(Cursor::new("\n"), Vec::new());
+
+        assert_eq!(reader.run(|| read_string_untrimmed(None)), "\n");
+        assert_eq!(reader.run(|| read_string_untrimmed(None)), "");
+        assert_eq!(reader.run(|| read_string(None)), "");
+    }
+
+    #[test]
+    fn fallible_readers_return_eof() {
+        let mut reader = Reader::new(Cursor::new("x\n"), Vec::new());
+        let age = reader.run(|| {
+            Prompt::new("Age: ")
+                .value::<u8>()
+                .max_attempts(3)
+                .try_read()
+        });
+
+        assert!(matches!(age, Err(InputError::Eof)));
+    }
+}
@@ -0,0 +1,67 @@
+//! Errors returned by the fallible readers.
+
+use std::error::Error;
+use std::fmt;
+use std::io;
+
+/// Reason why a fallible reader (Ex: try_read or Prompt::try_read) could not return a value.
+///
+/// Every variant can be told apart, so a program can handle each case on its own
+/// (Ex: using a default value when stdin is closed, but exiting when the answer is invalid).
+/// The answers of sensitive prompts are reported as "***".
+#[derive(Debug)]
+pub enum InputError {
+    /// Reading from stdin failed.
+    Io(io::Error),
+    /// Stdin was closed before a line was typed (Ex: the end of a piped file was reached).
+    Eof,
+    /// The line typed could not be parsed as the expected type.
+    Parse { raw: String },
+    /// The value typed is outside of the range of the prompt, described by 'expected'
+    /// (Ex: "between 1 and 10").
+    OutOfRange { raw: String, expected: String },
+    /// The value typed was rejected by a validator, for the reason it returned.
+    Invalid { raw: String, reason: String },
+    /// The user gave up the prompt before answering it (Ex: by pressing Ctrl+C or typing the
+    /// cancel word of a cancellable Prompt).
+    Cancelled,
+    /// The user typed an invalid value as many times as allowed (Ex: by Prompt::max_attempts).
+    MaxAttemptsExceeded,
+    /// No answer was typed before the time limit (Ex: set with Prompt::timeout, or the time
+    /// budget of a Session) passed.
+    Timeout,
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::Io(err) => write!(f, "Unable to read from stdin: {err}"),
+            InputError::Eof => write!(f, "The input ended before a value was typed."),
+            InputError::Parse { raw } => write!(f, "'{raw}' is not a valid value."),
+            InputError::OutOfRange { raw, expected } => {
+                write!(f, "'{raw}' is out of range (expected a value {expected}).")
+            }
+            InputError::Invalid { raw, reason } => write!(f, "'{raw}' was rejected: {reason}"),
+            InputError::Cancelled => write!(f, "The prompt was cancelled before it was answered."),
+            InputError::MaxAttemptsExceeded => {
+                write!(f, "No valid value was typed in the attempts allowed.")
+            }
+            InputError::Timeout => write!(f, "No answer was typed before the time limit."),
+        }
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            InputError::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for InputError {
+    fn from(err: io::Error) -> Self {
+        InputError::Io(err)
+    }
+}
@@ -0,0 +1,75 @@
+//! Example answers added to the default error messages of validated readers.
+
+/// Answers tried, in order, until one is accepted by the reader.
+const CANDIDATES: &[&str] = &["42", "5", "1", "0", "10", "100", "-1", "0.5", "a", "abc"];
+
+/// # Arguments #
+/// 'hints' (&[String]) - Answers known to suit the reader (Ex: the bounds of a range),
+/// tried after the generic candidates.
+///
+/// 'accept' (impl Fn(&str) -> Result<T, E>) - Closure parsing and validating an answer,
+/// the same one the reader uses with the user's input.
+///
+/// # Description #
+/// Private function returning the first candidate accepted by the reader, if any.
+pub(crate) fn find_example<T, E>(
+    hints: &[String],
+    accept: impl Fn(&str) -> Result<T, E>,
+) -> Option<String> {
+    CANDIDATES
+        .iter()
+        .copied()
+        .chain(hints.iter().map(String::as_str))
+        .find(|candidate| accept(candidate).is_ok())
+        .map(String::from)
+}
+
+/// # Arguments #
+/// 'message' (&str) - Default error message.
+///
+/// 'example' (Option<&str>) - Valid answer found by find_example.
+///
+/// # Description #
+/// Private function adding the example to the message before its final '.', if there is one
+/// (Ex: "Please enter a value of at least 18 (e.g. 42).").
+pub(crate) fn with_example(message: &str, example: Option<&str>) -> String {
+    let Some(example) = example else {
+        return message.to_string();
+    };
+
+    match message.strip_suffix('.') {
+        Some(base) => format!("{base} (e.g. {example})."),
+        None => format!("{message} (e.g. {example})"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn examples_satisfy_the_reader() {
+        let even = |input: &str| match input.parse::<u8>() {
+            Ok(value) if value.is_multiple_of(2) && value < 20 => Ok(value),
+            _ => Err(String::new()),
+        };
+        assert_eq!(find_example(&[], even).as_deref(), Some("0"));
+
+        let hints = [String::from("1000")];
+        let large = |input: &str| match input.parse::<u32>() {
+            Ok(value) if value >= 1000 => Ok(value),
+            _ => Err(String::new()),
+        };
+        assert_eq!(find_example(&hints, large).as_deref(), Some("1000"));
+    }
+
+    #[test]
+    fn examples_are_added_before_the_final_period() {
+        assert_eq!(
+            with_example("Please enter a value between 1 and 10.", Some("1")),
+            "Please enter a value between 1 and 10 (e.g. 1)."
+        );
+        assert_eq!(with_example("Too short", Some("abc")), "Too short (e.g. abc)");
+        assert_eq!(with_example("Invalid.", None), "Invalid.");
+    }
+}
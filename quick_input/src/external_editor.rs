@@ -0,0 +1,211 @@
+//! Reader for long texts written in the user's text editor, like git commit does.
+
+use std::env;
+use std::fs::{self, OpenOptions};
+use std::io::{self, IsTerminal, Write};
+use std::path::PathBuf;
+use std::process::{self, Command};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::multiline::read_lines;
+use crate::reader::outln;
+use crate::{dry_run, input, protocol, reader, render};
+
+/// Editor opened when neither VISUAL nor EDITOR are set.
+const DEFAULT_EDITOR: &str = if cfg!(windows) { "notepad" } else { "vi" };
+
+/// Number of files created so far, which makes the name of every file unique.
+static FILES: AtomicUsize = AtomicUsize::new(0);
+
+/// Number of names tried before giving up on creating the temporary file.
+const MAX_FILE_ATTEMPTS: usize = 100;
+
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed on its own line
+/// before the editor is opened. Must be set to Some("...") or None.
+///
+/// 'initial_contents' (&str) - text the file starts with (Ex: a template or a previous
+/// answer). May be left empty.
+///
+/// # DESCRIPTION #
+/// Writes 'initial_contents' to a temporary file and opens it in the editor set in the
+/// VISUAL or EDITOR environment variables (vi by default, or notepad on Windows), waiting
+/// until it is closed. The editor may be set with arguments (Ex: "code --wait").
+/// The file is removed once it is read back. It is created only for the current user (on
+/// Unix) and never replaces an existing file, since the text may hold secrets.
+///
+/// If no terminal is available to open the editor on (Ex: piped input), or the temporary file
+/// can't be created, the text is read from the input instead, as with read_multiline, ending
+/// with an empty line.
+///
+/// # RETURNS #
+/// A String with the contents of the file once the editor was closed, without trailing
+/// whitespace.
+///
+/// # PANICS #
+/// If the editor can't be started, or it exits with an error (Ex: :cq in vi), since the
+/// text may be incomplete, or if the file can't be read back once the editor is closed.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::read_via_editor;
+/// let notes = read_via_editor(Some("Opening the editor to write the release notes..."), "");
+///
+/// let message = read_via_editor(None, "Summary\n\nDetails");
+/// ```
+pub fn read_via_editor(msg: Option<&str>, initial_contents: &str) -> String {
+    if let Some(msg) = msg {
+        outln!("{msg}");
+    }
+    if dry_run::is_dry_run() {
+        outln!();
+        return initial_contents.to_string();
+    }
+
+    if reader::is_active()
+        || input::is_background()
+        || render::is_custom()
+        || protocol::is_protocol_mode()
+        || !io::stdin().is_terminal()
+    {
+        return read_lines(str::is_empty);
+    }
+
+    let path = match create_temp_file(initial_contents) {
+        Ok(path) => path,
+        Err(err) => {
+            outln!("(unable to create a temporary file for the editor: {err})");
+            return read_lines(str::is_empty);
+        }
+    };
+
+    let editor = editor();
+    let status = editor_command(&editor).map(|mut command| command.arg(&path).status());
+
+    let text = fs::read_to_string(&path);
+    let _ = fs::remove_file(&path);
+
+    match status {
+        Some(Ok(status)) if status.success() => {}
+        Some(Ok(status)) => panic!("The editor '{editor}' exited with an error ({status})."),
+        Some(Err(err)) => panic!("Unable to start the editor '{editor}': {err}"),
+        None => panic!("The editor set in VISUAL / EDITOR is empty."),
+    }
+
+    let text = text.unwrap_or_else(|err| panic!("Unable to read the temporary file: {err}"));
+    text.trim_end().to_string()
+}
+
+/// Editor set by the user in VISUAL or EDITOR, or the default one.
+fn editor() -> String {
+    ["VISUAL", "EDITOR"]
+        .into_iter()
+        .filter_map(|name| env::var(name).ok())
+        .find(|value| !value.trim().is_empty())
+        .unwrap_or_else(|| String::from(DEFAULT_EDITOR))
+}
+
+/// # Arguments #
+/// 'editor' (&str) - Editor set by the user, which may be followed by arguments.
+///
+/// # Description #
+/// Private function building the command which opens the editor, splitting it on whitespace.
+///
+/// # Returns #
+/// None if 'editor' is blank.
+fn editor_command(editor: &str) -> Option<Command> {
+    let mut words = editor.split_whitespace();
+    let mut command = Command::new(words.next()?);
+    command.args(words);
+    Some(command)
+}
+
+/// # Arguments #
+/// 'contents' (&str) - Text the file starts with.
+///
+/// # Description #
+/// Private function creating a new temporary file, readable only by the current user on Unix.
+/// Existing files (Ex: a symbolic link planted by another user of a shared directory) are
+/// never opened: another name is tried instead.
+///
+/// # Returns #
+/// The path of the file, or the error which kept it from being created.
+fn create_temp_file(contents: &str) -> io::Result<PathBuf> {
+    let mut options = OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+
+    for _ in 0..MAX_FILE_ATTEMPTS {
+        let path = temp_file_path();
+        match options.open(&path) {
+            Ok(mut file) => {
+                if let Err(err) = file.write_all(contents.as_bytes()) {
+                    let _ = fs::remove_file(&path);
+                    return Err(err);
+                }
+                return Ok(path);
+            }
+            Err(err) if err.kind() == io::ErrorKind::AlreadyExists => continue,
+            Err(err) => return Err(err),
+        }
+    }
+
+    Err(io::Error::new(
+        io::ErrorKind::AlreadyExists,
+        "every temporary file name tried was taken",
+    ))
+}
+
+/// Path of a new temporary file, unique to this process and hard to guess by others.
+fn temp_file_path() -> PathBuf {
+    let count = FILES.fetch_add(1, Ordering::Relaxed);
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.subsec_nanos());
+    env::temp_dir().join(format!(
+        "quick_input-{}-{count}-{nanos:09}.txt",
+        process::id()
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::MockInput;
+
+    #[test]
+    fn editor_arguments_are_kept() {
+        let command = editor_command("code  --wait").unwrap();
+        let args: Vec<_> = command.get_args().collect();
+
+        assert_eq!(command.get_program(), "code");
+        assert_eq!(args, ["--wait"]);
+        assert!(editor_command(" ").is_none());
+    }
+
+    #[test]
+    fn text_is_read_from_the_input_without_a_terminal() {
+        let mut mock = MockInput::new(["Summary", "Details", ""]);
+        let text = mock.run(|| read_via_editor(None, "Template"));
+
+        assert_eq!(text, "Summary\nDetails");
+        mock.assert_all_answered();
+    }
+
+    #[test]
+    fn temp_files_are_private_and_unique() {
+        let path = create_temp_file("Draft").unwrap();
+        let contents = fs::read_to_string(&path);
+        #[cfg(unix)]
+        let mode =
+            std::os::unix::fs::PermissionsExt::mode(&fs::metadata(&path).unwrap().permissions());
+        let _ = fs::remove_file(&path);
+
+        assert_eq!(contents.unwrap(), "Draft");
+        #[cfg(unix)]
+        assert_eq!(mode & 0o777, 0o600);
+        assert_ne!(temp_file_path(), temp_file_path());
+    }
+}
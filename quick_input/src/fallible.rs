@@ -0,0 +1,273 @@
+//! Readers making a limited number of attempts (or waiting a limited time) and returning
+//! an error instead of prompting forever.
+
+use std::str::FromStr;
+use std::time::Duration;
+
+use crate::{InputError, Prompt, dry_run, input, postprocess, reader, render, show_error_message};
+
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as the input prompt. Must be set to Some("...") or None.
+///
+/// # DESCRIPTION #
+/// Prompts the user to type a value of type T once. Unlike the read_* functions, an invalid
+/// value is not asked again and the end of the input does not make the program loop or panic:
+/// both are returned as an InputError, which suits programs reading answers piped from a file
+/// or another program.
+///
+/// Provides an information message on the same line as the prompt if Some("...")
+/// is provided, and just the prompt if None is provided.
+///
+/// # RETURNS #
+/// The trimmed line typed by the user parsed as T, or the InputError explaining
+/// why it could not be read (InputError::Eof, InputError::Parse or InputError::Io).
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::{try_read, InputError};
+/// match try_read::<u32>(Some("Number of workers: ")) {
+///     Ok(workers) => println!("Starting {workers} workers."),
+///     Err(InputError::Eof) => println!("No answer, using 4 workers."),
+///     Err(err) => eprintln!("{err}"),
+/// }
+///
+/// let name: String = try_read(None).unwrap_or_default();
+/// ```
+pub fn try_read<T: FromStr>(msg: Option<&str>) -> Result<T, InputError> {
+    let msg = msg.unwrap_or_default();
+
+    if dry_run::is_dry_run() {
+        return Ok(dry_run::placeholder(Some(msg), &[], |input| {
+            input.parse().map_err(|_| String::new())
+        }));
+    }
+
+    render::show_prompt(msg, false, None);
+    reader::flush()?;
+
+    let mut line = String::new();
+    let read = input::read_line(&mut line)?;
+
+    let mut line = line.trim().to_string();
+    postprocess::accept(msg, &mut line, |line| parse_line(line, read))
+}
+
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as the input prompt. Must be set to Some("...") or None.
+///
+/// 'err_msg' (Option<&str>) - an optional error message which will be printed
+/// if the user inputs an invalid value. Must be set to Some("...") or None.
+///
+/// 'attempts' (usize) - number of invalid values allowed before giving up.
+///
+/// # DESCRIPTION #
+/// Prompts the user to type a value of type T, asking again after an invalid value like the
+/// read_* functions do, but only 'attempts' times. This suits programs which must not get
+/// stuck (Ex: a kiosk), and falling back to a value is done with unwrap_or.
+///
+/// Provides an information message on the same line as the prompt if Some("...")
+/// is provided, and just the prompt if None is provided.
+///
+/// If err_msg is set to None, a default message will be shown.
+///
+/// # RETURNS #
+/// The value typed by the user, InputError::MaxAttemptsExceeded if every attempt was used,
+/// or InputError::Eof / InputError::Io if the input could not be read.
+///
+/// # PANICS #
+/// If 'attempts' is 0.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::read_limited;
+/// let workers: u16 = read_limited(Some("Workers: "), None, 3).unwrap_or(4);
+///
+/// let ip: Result<std::net::IpAddr, _> = read_limited(Some("Server IP: "), None, 5);
+/// ```
+pub fn read_limited<T: FromStr>(
+    msg: Option<&str>,
+    err_msg: Option<&str>,
+    attempts: usize,
+) -> Result<T, InputError> {
+    read_attempts(msg, err_msg, attempts, "Please enter a valid value.")
+}
+
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as the input prompt. Must be set to Some("...") or None.
+///
+/// 'err_msg' (Option<&str>) - an optional error message which will be printed
+/// if the user inputs an invalid value. Must be set to Some("...") or None.
+///
+/// 'attempts' (usize) - number of invalid values allowed before giving up.
+///
+/// # DESCRIPTION #
+/// Prompts the user to type an integer value (i32), asking again after an invalid value,
+/// but only 'attempts' times (see read_limited).
+///
+/// If err_msg is set to None, a default message will be shown.
+///
+/// # RETURNS #
+/// An integer value of type i32 provided by the user, InputError::MaxAttemptsExceeded if
+/// every attempt was used, or InputError::Eof / InputError::Io if the input could not be read.
+///
+/// # PANICS #
+/// If 'attempts' is 0.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::read_i32_limited;
+/// let offset = read_i32_limited(Some("Offset: "), None, 3).unwrap_or(0);
+/// ```
+pub fn read_i32_limited(
+    msg: Option<&str>,
+    err_msg: Option<&str>,
+    attempts: usize,
+) -> Result<i32, InputError> {
+    read_attempts(
+        msg,
+        err_msg,
+        attempts,
+        "Please enter a valid number (32 bits).",
+    )
+}
+
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as the input prompt. Must be set to Some("...") or None.
+///
+/// 'err_msg' (Option<&str>) - an optional error message which will be printed
+/// if the user inputs an invalid value. Must be set to Some("...") or None.
+///
+/// 'attempts' (usize) - number of invalid values allowed before giving up.
+///
+/// # DESCRIPTION #
+/// Prompts the user to type an integer value (u32), asking again after an invalid value,
+/// but only 'attempts' times (see read_limited).
+///
+/// If err_msg is set to None, a default message will be shown.
+///
+/// # RETURNS #
+/// An integer value of type u32 provided by the user, InputError::MaxAttemptsExceeded if
+/// every attempt was used, or InputError::Eof / InputError::Io if the input could not be read.
+///
+/// # PANICS #
+/// If 'attempts' is 0.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::{read_u32_limited, InputError};
+/// match read_u32_limited(Some("Table number: "), None, 3) {
+///     Ok(table) => println!("Serving table {table}."),
+///     Err(InputError::MaxAttemptsExceeded) => println!("Please ask a waiter."),
+///     Err(err) => eprintln!("{err}"),
+/// }
+/// ```
+pub fn read_u32_limited(
+    msg: Option<&str>,
+    err_msg: Option<&str>,
+    attempts: usize,
+) -> Result<u32, InputError> {
+    read_attempts(
+        msg,
+        err_msg,
+        attempts,
+        "Please enter a valid positive number (32 bits).",
+    )
+}
+
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as the input prompt. Must be set to Some("...") or None.
+///
+/// 'timeout' (Duration) - time the user has to answer.
+///
+/// # DESCRIPTION #
+/// Prompts the user to type a string of text, giving up once 'timeout' passes without an
+/// answer, so unattended scripts never hang forever. A line typed after the time limit is
+/// kept for the next reader. For other types or a default value, see Prompt::timeout.
+///
+/// Provides an information message on the same line as the prompt if Some("...")
+/// is provided, and just the prompt if None is provided.
+///
+/// # RETURNS #
+/// The trimmed String typed by the user, InputError::Timeout if no answer was typed in time,
+/// or InputError::Eof / InputError::Io if the input could not be read.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::read_string_timeout;
+/// use std::time::Duration;
+/// let note = read_string_timeout(Some("Release note (30s): "), Duration::from_secs(30))
+///     .unwrap_or_default();
+/// ```
+pub fn read_string_timeout(msg: Option<&str>, timeout: Duration) -> Result<String, InputError> {
+    Prompt::new(msg.unwrap_or_default())
+        .text()
+        .timeout(timeout)
+        .try_read()
+}
+
+/// # Arguments #
+/// 'attempts' (usize) - Number of invalid values allowed.
+///
+/// 'def_err_msg' (&str) - Error message shown after an invalid value if err_msg is None.
+///
+/// # Description #
+/// Private function shared by the limited readers, calling try_read until it succeeds,
+/// the input ends or every attempt is used.
+fn read_attempts<T: FromStr>(
+    msg: Option<&str>,
+    err_msg: Option<&str>,
+    attempts: usize,
+    def_err_msg: &str,
+) -> Result<T, InputError> {
+    assert!(attempts > 0, "At least one attempt must be allowed.");
+
+    for _ in 0..attempts {
+        match try_read(msg) {
+            Err(InputError::Parse { .. }) => show_error_message(err_msg, def_err_msg),
+            result => return result,
+        }
+    }
+    Err(InputError::MaxAttemptsExceeded)
+}
+
+/// # Arguments #
+/// 'line' (&str) - Line read from stdin.
+///
+/// 'read' (usize) - Number of bytes read, which is 0 at the end of the input.
+///
+/// # Description #
+/// Private function turning the line read by try_read into its result.
+fn parse_line<T: FromStr>(line: &str, read: usize) -> Result<T, InputError> {
+    if read == 0 {
+        return Err(InputError::Eof);
+    }
+
+    let line = line.trim();
+    line.parse().map_err(|_| InputError::Parse {
+        raw: line.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lines_are_parsed_once() {
+        assert_eq!(parse_line::<u8>(" 42\n", 4).ok(), Some(42));
+        assert!(matches!(parse_line::<u8>("", 0), Err(InputError::Eof)));
+        assert!(matches!(
+            parse_line::<u8>("\n", 1),
+            Err(InputError::Parse { raw }) if raw.is_empty()
+        ));
+        assert!(matches!(
+            parse_line::<u8>("300\n", 4),
+            Err(InputError::Parse { raw }) if raw == "300"
+        ));
+    }
+}
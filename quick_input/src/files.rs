@@ -0,0 +1,300 @@
+//! Readers for files chosen by the user.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::{ask_yes_no, dry_run, read_choice_map_with_default, read_until_valid};
+use crate::reader::outln;
+
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as the input prompt. Must be set to Some("...") or None.
+///
+/// 'err_msg' (Option<&str>) - an optional error message which will be printed
+/// if the user inputs an invalid value. Must be set to Some("...") or None.
+///
+/// 'max_size' (u64) - maximum size of the file, in bytes.
+///
+/// # DESCRIPTION #
+/// Prompts the user to type the path of a text file (Ex: a key or a certificate request),
+/// whose contents will then be read and returned. The file must exist, be no bigger
+/// than 'max_size' and contain valid UTF-8 text. Quotes around the path (added by some
+/// terminals when a file is dragged onto them) are ignored.
+/// In case the path is not valid, the user will be prompted to try again.
+/// In dry-run mode, no file is read and an empty String is returned.
+///
+/// Provides an information message on the same line as the prompt if Some("...")
+/// is provided, and just the prompt if None is provided.
+///
+/// If err_msg is set to None, a default message explaining the problem will be shown.
+///
+/// # RETURNS #
+/// A String with the contents of the file.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::read_file_contents;
+/// let public_key = read_file_contents(Some("Path to your public key: "), None, 16 * 1024);
+/// ```
+pub fn read_file_contents(msg: Option<&str>, err_msg: Option<&str>, max_size: u64) -> String {
+    if dry_run::is_dry_run() {
+        return dry_run::placeholder(msg, &[], |_| Ok(String::new()));
+    }
+
+    read_until_valid(msg, err_msg, |input| {
+        let path = check_file(input, max_size)?;
+        fs::read_to_string(&path).map_err(|err| match err.kind() {
+            std::io::ErrorKind::InvalidData => {
+                format!("'{}' is not a text file.", path.display())
+            }
+            _ => format!("Unable to read '{}': {err}.", path.display()),
+        })
+    })
+}
+
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as the input prompt. Must be set to Some("...") or None.
+///
+/// 'err_msg' (Option<&str>) - an optional error message which will be printed
+/// if the user inputs an invalid value. Must be set to Some("...") or None.
+///
+/// 'max_size' (u64) - maximum size of the file, in bytes.
+///
+/// # DESCRIPTION #
+/// Prompts the user to type the path of a file, whose contents will then be read and returned
+/// as they are. The file must exist and be no bigger than 'max_size'. Quotes around the path
+/// are ignored. In case the path is not valid, the user will be prompted to try again.
+/// In dry-run mode, no file is read and an empty Vec is returned.
+///
+/// Provides an information message on the same line as the prompt if Some("...")
+/// is provided, and just the prompt if None is provided.
+///
+/// If err_msg is set to None, a default message explaining the problem will be shown.
+///
+/// # RETURNS #
+/// A Vec<u8> with the contents of the file.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::read_file_bytes;
+/// let icon = read_file_bytes(Some("Icon: "), None, 1024 * 1024);
+/// ```
+pub fn read_file_bytes(msg: Option<&str>, err_msg: Option<&str>, max_size: u64) -> Vec<u8> {
+    if dry_run::is_dry_run() {
+        return dry_run::placeholder(msg, &[], |_| Ok(Vec::new()));
+    }
+
+    read_until_valid(msg, err_msg, |input| {
+        let path = check_file(input, max_size)?;
+        fs::read(&path).map_err(|err| format!("Unable to read '{}': {err}.", path.display()))
+    })
+}
+
+/// # ARGUMENTS #
+/// 'path' (impl AsRef<Path>) - path of the file the program is about to write.
+///
+/// # DESCRIPTION #
+/// Checks whether the file already exists and, if it does, shows its size and when it was
+/// last modified and asks the user (y / n) whether it should be overwritten.
+/// Nothing is asked if the file does not exist.
+///
+/// # RETURNS #
+/// True if the file can be written, either because it does not exist or because
+/// the user confirmed it may be overwritten.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::confirm_overwrite;
+/// if confirm_overwrite("report.csv") {
+///     std::fs::write("report.csv", "id,total\n").unwrap();
+/// }
+/// ```
+pub fn confirm_overwrite(path: impl AsRef<Path>) -> bool {
+    let path = path.as_ref();
+    let Some(description) = describe_existing(path) else {
+        return true;
+    };
+
+    outln!("{description}");
+    ask_yes_no("Overwrite it? (y / n): ")
+}
+
+/// # ARGUMENTS #
+/// 'path' (impl AsRef<Path>) - path of the file the program is about to write.
+///
+/// # DESCRIPTION #
+/// Works like confirm_overwrite, but lets the user choose between [o]verwrite, [r]ename and
+/// [c]ancel (the default). Choosing rename asks for a new path on the spot, which is checked
+/// the same way in case it exists as well.
+///
+/// # RETURNS #
+/// The path the file should be written to ('path' itself or the new one typed by the user),
+/// or None if the user cancelled.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::confirm_overwrite_or_rename;
+/// if let Some(path) = confirm_overwrite_or_rename("report.csv") {
+///     std::fs::write(path, "id,total\n").unwrap();
+/// }
+/// ```
+pub fn confirm_overwrite_or_rename(path: impl AsRef<Path>) -> Option<PathBuf> {
+    let mut path = path.as_ref().to_path_buf();
+
+    while let Some(description) = describe_existing(&path) {
+        outln!("{description}");
+
+        let options = [('o', "overwrite"), ('r', "rename"), ('c', "cancel")];
+        match read_choice_map_with_default(Some("What do you want to do? "), None, &options, 'c') {
+            'o' => break,
+            'r' => {
+                path = read_until_valid(Some("New path: "), None, |input| match unquote(input) {
+                    "" => Err(String::from("Please enter the path of a file.")),
+                    path if Path::new(path).is_dir() => Err(format!("'{path}' is a directory.")),
+                    path => Ok(PathBuf::from(path)),
+                });
+            }
+            _ => return None,
+        }
+    }
+
+    Some(path)
+}
+
+/// # Arguments #
+/// 'path' (&Path) - Path of the file which is about to be written.
+///
+/// # Description #
+/// Private function describing the file at 'path' (Ex: 'a.txt' already exists (1.5 KiB,
+/// modified 3 hours ago).), or returning None if there is no such file.
+fn describe_existing(path: &Path) -> Option<String> {
+    let metadata = fs::metadata(path).ok()?;
+
+    let mut details = Vec::new();
+    if metadata.is_file() {
+        details.push(format_size(metadata.len()));
+    }
+    if let Some(age) = metadata
+        .modified()
+        .ok()
+        .and_then(|time| time.elapsed().ok())
+    {
+        details.push(format!("modified {}", format_age(age)));
+    }
+
+    let kind = if metadata.is_dir() {
+        "A directory"
+    } else {
+        "A file"
+    };
+    let mut description = format!("{kind} named '{}' already exists", path.display());
+    if !details.is_empty() {
+        description.push_str(&format!(" ({})", details.join(", ")));
+    }
+    description.push('.');
+
+    Some(description)
+}
+
+fn check_file(input: &str, max_size: u64) -> Result<PathBuf, String> {
+    let path = Path::new(unquote(input));
+    if input.is_empty() {
+        return Err(String::from("Please enter the path of a file."));
+    }
+
+    let metadata =
+        fs::metadata(path).map_err(|_| format!("The file '{}' does not exist.", path.display()))?;
+    if !metadata.is_file() {
+        return Err(format!("'{}' is not a file.", path.display()));
+    }
+    if metadata.len() > max_size {
+        return Err(format!(
+            "The file is {}, but at most {} are allowed.",
+            format_size(metadata.len()),
+            format_size(max_size)
+        ));
+    }
+
+    Ok(path.to_path_buf())
+}
+
+fn unquote(input: &str) -> &str {
+    ['"', '\'']
+        .iter()
+        .find_map(|&quote| input.strip_prefix(quote)?.strip_suffix(quote))
+        .unwrap_or(input)
+}
+
+/// Formats how long ago something happened, using the largest unit which keeps it above 1.
+fn format_age(age: Duration) -> String {
+    const UNITS: [(u64, &str); 4] = [
+        (86_400, "day"),
+        (3_600, "hour"),
+        (60, "minute"),
+        (1, "second"),
+    ];
+
+    let seconds = age.as_secs();
+    if seconds == 0 {
+        return String::from("just now");
+    }
+
+    let (size, unit) = UNITS
+        .iter()
+        .find(|(size, _)| seconds >= *size)
+        .copied()
+        .unwrap_or((1, "second"));
+    let count = seconds / size;
+    let plural = if count == 1 { "" } else { "s" };
+
+    format!("{count} {unit}{plural} ago")
+}
+
+/// Formats a number of bytes with the largest binary unit which keeps it above 1.
+pub(crate) fn format_size(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+
+    let mut size = bytes as f64;
+    let mut unit = "B";
+    for next in UNITS {
+        if size < 1024.0 {
+            break;
+        }
+        size /= 1024.0;
+        unit = next;
+    }
+
+    format!("{size:.1} {unit}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sizes_are_formatted() {
+        assert_eq!(format_size(512), "512 B");
+        assert_eq!(format_size(1536), "1.5 KiB");
+        assert_eq!(format_size(3 * 1024 * 1024), "3.0 MiB");
+    }
+
+    #[test]
+    fn ages_are_formatted() {
+        assert_eq!(format_age(Duration::ZERO), "just now");
+        assert_eq!(format_age(Duration::from_secs(1)), "1 second ago");
+        assert_eq!(format_age(Duration::from_secs(7_300)), "2 hours ago");
+    }
+
+    #[test]
+    fn quotes_are_removed() {
+        assert_eq!(unquote("'/tmp/my file'"), "/tmp/my file");
+        assert_eq!(unquote("\"key.pem\""), "key.pem");
+        assert_eq!(unquote("it's"), "it's");
+    }
+}
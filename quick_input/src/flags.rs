@@ -0,0 +1,248 @@
+//! Readers for a line of key=value flags checked against a typed schema.
+
+use crate::split::{Delimiters, split_nested};
+use crate::{Answer, Answers, FieldKind, dry_run, read_until_valid};
+
+/// Delimiters of a line of flags: flags are separated by spaces and values may be quoted.
+const FLAG_DELIMITERS: Delimiters = Delimiters {
+    outer: ' ',
+    inner: '=',
+    quote: Some('"'),
+};
+
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as the input prompt. Must be set to Some("...") or None.
+///
+/// 'err_msg' (Option<&str>) - an optional error message which will be printed
+/// if the user inputs an invalid value. Must be set to Some("...") or None.
+///
+/// 'schema' (&[(&str, FieldKind, Option<Answer>)]) - name, type and default value of every
+/// flag. Flags without a default value must be typed.
+///
+/// # DESCRIPTION #
+/// Prompts the user to type a line of flags separated by spaces, written as key=value
+/// (Ex: verbose=true level=3 name="my project"), which is lighter than running a whole Form.
+/// Every value is parsed according to the type of its flag, and boolean flags may be typed
+/// without a value to set them to true (Ex: verbose). Flags which are not typed take their
+/// default value. In case the user types an unknown flag, repeats a flag, writes an invalid
+/// value or leaves out a flag with no default, they will be prompted to try again.
+///
+/// Provides an information message on the same line as the prompt if Some("...")
+/// is provided, and just the prompt if None is provided.
+///
+/// If err_msg is set to None, a default message explaining the problem will be shown.
+///
+/// # RETURNS #
+/// The Answers for every flag of the schema, in the order of the schema.
+///
+/// # PANICS #
+/// If the schema has the same flag twice, or a default value which does not match
+/// the type of its flag.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::{read_keyvalue_flags, Answer, FieldKind};
+/// let flags = read_keyvalue_flags(
+///     Some("Options (verbose level=N): "),
+///     None,
+///     &[
+///         ("verbose", FieldKind::Bool, Some(Answer::Bool(false))),
+///         ("level", FieldKind::Unsigned, Some(Answer::Unsigned(1))),
+///     ],
+/// );
+///
+/// let verbose: bool = flags.get("verbose").unwrap();
+/// let level: u8 = flags.get("level").unwrap();
+/// ```
+pub fn read_keyvalue_flags(
+    msg: Option<&str>,
+    err_msg: Option<&str>,
+    schema: &[(&str, FieldKind, Option<Answer>)],
+) -> Answers {
+    check_schema(schema);
+    let parse = |input: &str| parse_flags(input, schema);
+
+    if dry_run::is_dry_run() {
+        let hint = schema
+            .iter()
+            .filter(|(_, _, default)| default.is_none())
+            .map(|(name, kind, _)| format!("{name}={}", placeholder_value(*kind)))
+            .collect::<Vec<_>>()
+            .join(" ");
+        return dry_run::placeholder(msg, &[hint], parse);
+    }
+
+    read_until_valid(msg, err_msg, parse)
+}
+
+/// # Arguments #
+/// 'schema' (&[(&str, FieldKind, Option<Answer>)]) - Flags the reader accepts.
+///
+/// # Description #
+/// Private function panicking if the schema has repeated flags or defaults of the wrong type,
+/// since those are mistakes of the program and not of the user.
+fn check_schema(schema: &[(&str, FieldKind, Option<Answer>)]) {
+    for (index, (name, kind, default)) in schema.iter().enumerate() {
+        if schema[..index].iter().any(|(other, _, _)| other == name) {
+            panic!("The flag '{name}' appears more than once in the schema.");
+        }
+
+        if let Some(default) = default
+            && !matches_kind(default, *kind)
+        {
+            panic!("The default value of the flag '{name}' ({default}) is not of type {kind:?}.");
+        }
+    }
+}
+
+/// # Arguments #
+/// 'input' (&str) - Trimmed input typed by the user.
+///
+/// 'schema' (&[(&str, FieldKind, Option<Answer>)]) - Flags the reader accepts.
+///
+/// # Description #
+/// Private function parsing a line of flags into Answers, filling in the default values.
+fn parse_flags(
+    input: &str,
+    schema: &[(&str, FieldKind, Option<Answer>)],
+) -> Result<Answers, String> {
+    let mut typed: Vec<(&str, Answer)> = Vec::new();
+
+    for parts in split_nested(input, &FLAG_DELIMITERS)? {
+        let (key, value) = match parts.as_slice() {
+            [key] => (key.as_str(), None),
+            [key, value] => (key.as_str(), Some(value.as_str())),
+            _ => {
+                return Err(format!(
+                    "'{}' must be written as key=value.",
+                    parts.join("=")
+                ));
+            }
+        };
+
+        let Some((name, kind, _)) = schema.iter().find(|(name, _, _)| *name == key) else {
+            let known: Vec<&str> = schema.iter().map(|(name, _, _)| *name).collect();
+            return Err(format!(
+                "Unknown flag '{key}'. The flags available are: {}.",
+                known.join(", ")
+            ));
+        };
+        if typed.iter().any(|(typed_name, _)| typed_name == name) {
+            return Err(format!("The flag '{name}' was typed more than once."));
+        }
+
+        typed.push((name, parse_value(name, *kind, value)?));
+    }
+
+    let mut answers = Answers::default();
+    for (name, _, default) in schema {
+        let answer = match typed.iter().find(|(typed_name, _)| typed_name == name) {
+            Some((_, answer)) => answer.clone(),
+            None => default
+                .clone()
+                .ok_or_else(|| format!("The flag '{name}' is required."))?,
+        };
+        answers.insert(name, answer);
+    }
+    Ok(answers)
+}
+
+/// Parses the value typed for a flag. Only boolean flags may be typed without a value.
+fn parse_value(name: &str, kind: FieldKind, value: Option<&str>) -> Result<Answer, String> {
+    let Some(value) = value else {
+        return match kind {
+            FieldKind::Bool => Ok(Answer::Bool(true)),
+            _ => Err(format!("The flag '{name}' needs a value ({name}=...).")),
+        };
+    };
+
+    let invalid = |expected: &str| format!("The flag '{name}' must be {expected}, not '{value}'.");
+    match kind {
+        FieldKind::Text => Ok(Answer::Text(value.to_string())),
+        FieldKind::Integer => value
+            .parse()
+            .map(Answer::Integer)
+            .map_err(|_| invalid("an integer")),
+        FieldKind::Unsigned => value
+            .parse()
+            .map(Answer::Unsigned)
+            .map_err(|_| invalid("a positive integer")),
+        FieldKind::Real => value
+            .parse()
+            .map(Answer::Real)
+            .map_err(|_| invalid("a number")),
+        FieldKind::Bool => value
+            .to_lowercase()
+            .parse()
+            .map(Answer::Bool)
+            .map_err(|_| invalid("true or false")),
+    }
+}
+
+fn matches_kind(answer: &Answer, kind: FieldKind) -> bool {
+    matches!(
+        (answer, kind),
+        (Answer::Text(_), FieldKind::Text)
+            | (Answer::Integer(_), FieldKind::Integer)
+            | (Answer::Unsigned(_), FieldKind::Unsigned)
+            | (Answer::Real(_), FieldKind::Real)
+            | (Answer::Bool(_), FieldKind::Bool)
+    )
+}
+
+fn placeholder_value(kind: FieldKind) -> &'static str {
+    match kind {
+        FieldKind::Text => "x",
+        FieldKind::Integer | FieldKind::Unsigned | FieldKind::Real => "0",
+        FieldKind::Bool => "false",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn schema() -> Vec<(&'static str, FieldKind, Option<Answer>)> {
+        vec![
+            ("verbose", FieldKind::Bool, Some(Answer::Bool(false))),
+            ("level", FieldKind::Unsigned, None),
+            (
+                "name",
+                FieldKind::Text,
+                Some(Answer::Text(String::from("demo"))),
+            ),
+        ]
+    }
+
+    #[test]
+    fn flags_are_parsed_with_defaults() {
+        let answers = parse_flags("level=3 verbose", &schema()).unwrap();
+        assert_eq!(answers.get::<bool>("verbose"), Ok(true));
+        assert_eq!(answers.get::<u8>("level"), Ok(3));
+        assert_eq!(answers.get::<String>("name"), Ok(String::from("demo")));
+
+        let answers = parse_flags("name=\"my project\"  level=1", &schema()).unwrap();
+        assert_eq!(
+            answers.get::<String>("name"),
+            Ok(String::from("my project"))
+        );
+    }
+
+    #[test]
+    fn invalid_flags_are_rejected() {
+        for input in [
+            "",
+            "level=x",
+            "level",
+            "level=1 level=2",
+            "level=1 debug",
+            "level=1 verbose=maybe",
+        ] {
+            assert!(
+                parse_flags(input, &schema()).is_err(),
+                "{input} was accepted"
+            );
+        }
+    }
+}
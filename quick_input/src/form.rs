@@ -0,0 +1,914 @@
+//! Forms, which ask a sequence of questions and collect the answers.
+
+use std::error::Error;
+use std::fmt;
+
+use crate::protocol::json_string;
+use crate::{read_bool, read_f64, read_i64, read_string, read_u64, read_until_valid};
+use crate::reader::outln;
+
+/// Type of value asked for by a Form field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldKind {
+    /// Trimmed text, read with read_string.
+    Text,
+    /// Integer value, read with read_i64.
+    Integer,
+    /// Positive integer value, read with read_u64.
+    Unsigned,
+    /// Real number, read with read_f64.
+    Real,
+    /// Boolean value, read with read_bool.
+    Bool,
+}
+
+impl FieldKind {
+    /// Name of the kind used in the catalog exported by Form::catalog_json.
+    fn name(self) -> &'static str {
+        match self {
+            FieldKind::Text => "text",
+            FieldKind::Integer => "integer",
+            FieldKind::Unsigned => "unsigned",
+            FieldKind::Real => "real",
+            FieldKind::Bool => "bool",
+        }
+    }
+}
+
+/// Description of a question a Form may ask, listed by Form::catalog.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct CatalogEntry {
+    /// Key the answer is stored under.
+    pub key: String,
+    /// Message of the prompt.
+    pub message: String,
+    /// Type of the answer, or None for a branch choice (whose answer is the branch name).
+    pub kind: Option<FieldKind>,
+    /// Names of the branches of a branch choice, empty for other questions.
+    pub options: Vec<String>,
+    /// Whether the question was added with field_if, so it may be skipped.
+    pub conditional: bool,
+    /// Branches which must be chosen for the question to be asked, as (key, branch name)
+    /// pairs from the outermost one.
+    pub requires: Vec<(String, String)>,
+    /// Title of the section the question belongs to, if any.
+    pub section: Option<String>,
+}
+
+impl CatalogEntry {
+    /// Writes the entry as a JSON object.
+    fn to_json(&self) -> String {
+        let kind = self.kind.map_or("choice", FieldKind::name);
+        let options: Vec<String> = self.options.iter().map(|name| json_string(name)).collect();
+        let requires: Vec<String> = self
+            .requires
+            .iter()
+            .map(|(key, name)| {
+                format!(
+                    "{{\"key\":{},\"branch\":{}}}",
+                    json_string(key),
+                    json_string(name)
+                )
+            })
+            .collect();
+        let section = self
+            .section
+            .as_deref()
+            .map_or(String::from("null"), json_string);
+
+        format!(
+            "{{\"key\":{},\"message\":{},\"type\":\"{kind}\",\"options\":[{}],\"conditional\":{},\"requires\":[{}],\"section\":{section}}}",
+            json_string(&self.key),
+            json_string(&self.message),
+            options.join(","),
+            self.conditional,
+            requires.join(","),
+        )
+    }
+}
+
+/// A value provided by the user while filling in a Form.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Answer {
+    Text(String),
+    Integer(i64),
+    Unsigned(u64),
+    Real(f64),
+    Bool(bool),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Text(value) => write!(f, "{value}"),
+            Answer::Integer(value) => write!(f, "{value}"),
+            Answer::Unsigned(value) => write!(f, "{value}"),
+            Answer::Real(value) => write!(f, "{value}"),
+            Answer::Bool(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+/// Answers collected by a Form, kept in the order they were asked.
+///
+/// Answers are read with typed getters (Ex: answers.get::<u16>("port")), which return a
+/// descriptive AnswerError if the field was not asked or its answer does not fit in the type.
+///
+/// With the "serde" feature enabled, Answers can be serialized as a map
+/// (Ex: straight into a JSON or TOML configuration file) and deserialized back.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Answers {
+    entries: Vec<(String, Answer)>,
+}
+
+impl Answers {
+    /// # ARGUMENTS #
+    /// 'key' (&str) - name of the field.
+    ///
+    /// # DESCRIPTION #
+    /// Returns the answer given to the field 'key' converted to T, which can be any integer
+    /// type (as long as the answer fits in it), f32, f64, bool, String or Answer itself.
+    ///
+    /// # RETURNS #
+    /// The converted answer, or an AnswerError explaining why it is not available
+    /// (the field was not asked, or its answer does not fit in T).
+    ///
+    /// # EXAMPLES #
+    /// ```no_run
+    /// use quick_input::{FieldKind, Form};
+    /// fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let answers = Form::new()
+    ///         .field("host", "Host: ", FieldKind::Text)
+    ///         .field("port", "Port: ", FieldKind::Unsigned)
+    ///         .run();
+    ///
+    ///     let host: String = answers.get("host")?;
+    ///     let port = answers.get::<u16>("port")?;
+    ///     println!("Connecting to {host}:{port}");
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn get<T: FromAnswer>(&self, key: &str) -> Result<T, AnswerError> {
+        let answer = self
+            .answer(key)
+            .ok_or_else(|| AnswerError::Missing(key.to_string()))?;
+
+        T::from_answer(answer).ok_or_else(|| AnswerError::WrongType {
+            key: key.to_string(),
+            expected: std::any::type_name::<T>(),
+            found: answer.clone(),
+        })
+    }
+
+    /// Returns the answer given to the field 'key', if it was asked.
+    pub fn answer(&self, key: &str) -> Option<&Answer> {
+        self.entries
+            .iter()
+            .find(|(entry_key, _)| entry_key == key)
+            .map(|(_, answer)| answer)
+    }
+
+    /// Returns the answer given to the field 'key', if it was asked and is text.
+    pub fn get_text(&self, key: &str) -> Option<&str> {
+        match self.answer(key) {
+            Some(Answer::Text(value)) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the answer given to the field 'key', if it was asked and is an integer.
+    pub fn get_integer(&self, key: &str) -> Option<i64> {
+        match self.answer(key) {
+            Some(Answer::Integer(value)) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Returns the answer given to the field 'key', if it was asked and is a positive integer.
+    pub fn get_unsigned(&self, key: &str) -> Option<u64> {
+        match self.answer(key) {
+            Some(Answer::Unsigned(value)) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Returns the answer given to the field 'key', if it was asked and is a real number.
+    pub fn get_real(&self, key: &str) -> Option<f64> {
+        match self.answer(key) {
+            Some(Answer::Real(value)) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Returns the answer given to the field 'key', if it was asked and is a boolean.
+    pub fn get_bool(&self, key: &str) -> Option<bool> {
+        match self.answer(key) {
+            Some(Answer::Bool(value)) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Iterates over every (key, answer) pair, in the order they were asked.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &Answer)> {
+        self.entries
+            .iter()
+            .map(|(key, answer)| (key.as_str(), answer))
+    }
+
+    /// Number of answers collected.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether no answers were collected.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Stores an answer, replacing any previous answer given to the same field.
+    pub(crate) fn insert(&mut self, key: &str, answer: Answer) {
+        match self
+            .entries
+            .iter_mut()
+            .find(|(entry_key, _)| entry_key == key)
+        {
+            Some(entry) => entry.1 = answer,
+            None => self.entries.push((key.to_string(), answer)),
+        }
+    }
+}
+
+/// Types an Answer can be converted to, used by Answers::get.
+pub trait FromAnswer: Sized {
+    /// Returns the answer converted to this type, or None if it does not fit in it.
+    fn from_answer(answer: &Answer) -> Option<Self>;
+}
+
+impl FromAnswer for Answer {
+    fn from_answer(answer: &Answer) -> Option<Self> {
+        Some(answer.clone())
+    }
+}
+
+impl FromAnswer for String {
+    fn from_answer(answer: &Answer) -> Option<Self> {
+        match answer {
+            Answer::Text(value) => Some(value.clone()),
+            _ => None,
+        }
+    }
+}
+
+impl FromAnswer for bool {
+    fn from_answer(answer: &Answer) -> Option<Self> {
+        match answer {
+            Answer::Bool(value) => Some(*value),
+            _ => None,
+        }
+    }
+}
+
+impl FromAnswer for f64 {
+    fn from_answer(answer: &Answer) -> Option<Self> {
+        match answer {
+            Answer::Real(value) => Some(*value),
+            Answer::Integer(value) => Some(*value as f64),
+            Answer::Unsigned(value) => Some(*value as f64),
+            _ => None,
+        }
+    }
+}
+
+impl FromAnswer for f32 {
+    fn from_answer(answer: &Answer) -> Option<Self> {
+        f64::from_answer(answer).map(|value| value as f32)
+    }
+}
+
+macro_rules! impl_from_answer_for_integers {
+    ($($integer:ty),*) => {
+        $(
+            impl FromAnswer for $integer {
+                fn from_answer(answer: &Answer) -> Option<Self> {
+                    match answer {
+                        Answer::Integer(value) => <$integer>::try_from(*value).ok(),
+                        Answer::Unsigned(value) => <$integer>::try_from(*value).ok(),
+                        _ => None,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_from_answer_for_integers!(
+    i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+);
+
+/// Error returned by Answers::get when an answer is not available as the requested type.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AnswerError {
+    /// The field with this key was not asked (Ex: it was skipped by a condition).
+    Missing(String),
+    /// The answer does not fit in the requested type (Ex: text read as a number,
+    /// or 70000 read as an u16).
+    WrongType {
+        key: String,
+        expected: &'static str,
+        found: Answer,
+    },
+}
+
+impl fmt::Display for AnswerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AnswerError::Missing(key) => write!(f, "There is no answer for '{key}'."),
+            AnswerError::WrongType {
+                key,
+                expected,
+                found,
+            } => write!(
+                f,
+                "The answer for '{key}' ({found}) cannot be read as {expected}."
+            ),
+        }
+    }
+}
+
+impl Error for AnswerError {}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Answer {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Answer::Text(value) => serializer.serialize_str(value),
+            Answer::Integer(value) => serializer.serialize_i64(*value),
+            Answer::Unsigned(value) => serializer.serialize_u64(*value),
+            Answer::Real(value) => serializer.serialize_f64(*value),
+            Answer::Bool(value) => serializer.serialize_bool(*value),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Answers {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeMap;
+
+        let mut map = serializer.serialize_map(Some(self.entries.len()))?;
+        for (key, answer) in &self.entries {
+            map.serialize_entry(key, answer)?;
+        }
+        map.end()
+    }
+}
+
+/// Answers are deserialized from a map of strings, numbers and booleans. Positive integers
+/// become Answer::Unsigned, which Answers::get converts to any suitable integer type.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Answer {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct AnswerVisitor;
+
+        impl serde::de::Visitor<'_> for AnswerVisitor {
+            type Value = Answer;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a string, a number or a boolean")
+            }
+
+            fn visit_bool<E>(self, value: bool) -> Result<Answer, E> {
+                Ok(Answer::Bool(value))
+            }
+
+            fn visit_i64<E>(self, value: i64) -> Result<Answer, E> {
+                Ok(Answer::Integer(value))
+            }
+
+            fn visit_u64<E>(self, value: u64) -> Result<Answer, E> {
+                Ok(Answer::Unsigned(value))
+            }
+
+            fn visit_f64<E>(self, value: f64) -> Result<Answer, E> {
+                Ok(Answer::Real(value))
+            }
+
+            fn visit_str<E>(self, value: &str) -> Result<Answer, E> {
+                Ok(Answer::Text(value.to_string()))
+            }
+        }
+
+        deserializer.deserialize_any(AnswerVisitor)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Answers {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct AnswersVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for AnswersVisitor {
+            type Value = Answers;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a map of answers")
+            }
+
+            fn visit_map<A: serde::de::MapAccess<'de>>(
+                self,
+                mut map: A,
+            ) -> Result<Answers, A::Error> {
+                let mut answers = Answers::default();
+                while let Some((key, answer)) = map.next_entry::<String, Answer>()? {
+                    answers.insert(&key, answer);
+                }
+                Ok(answers)
+            }
+        }
+
+        deserializer.deserialize_map(AnswersVisitor)
+    }
+}
+
+type Condition = Box<dyn Fn(&Answers) -> bool>;
+
+struct Field {
+    key: String,
+    msg: String,
+    kind: FieldKind,
+    condition: Option<Condition>,
+}
+
+struct Branch {
+    key: String,
+    msg: String,
+    options: Vec<(String, Form)>,
+}
+
+struct Section {
+    title: String,
+    description: Option<String>,
+}
+
+enum Step {
+    Field(Field),
+    Branch(Branch),
+    Section(Section),
+}
+
+/// A struct which can be filled in by asking for each of its fields, in the order they are
+/// declared, unlike a Form, whose answers are looked up by key.
+///
+/// With the `derive` feature, it can be derived for structs with named fields. Every field is
+/// asked for with a Prompt, configured with `#[prompt(...)]` (msg, help, err_msg, default,
+/// range, validate and hidden):
+///
+/// ```
+/// # #[cfg(feature = "derive")]
+/// # {
+/// use quick_input::PromptForm;
+/// use quick_input::testing::MockInput;
+///
+/// fn not_root(user: &String) -> Result<(), String> {
+///     match user.as_str() {
+///         "root" => Err(String::from("Please use an unprivileged user.")),
+///         _ => Ok(()),
+///     }
+/// }
+///
+/// #[derive(Debug, PartialEq, PromptForm)]
+/// struct Config {
+///     #[prompt(msg = "Host: ", default = "localhost")]
+///     host: String,
+///     #[prompt(range = 1..=65535, default = 8080)]
+///     port: u32,
+///     #[prompt(validate = not_root)]
+///     user: String,
+/// }
+///
+/// let mut mock = MockInput::new(["", "70000", "443", "root", "ada"]);
+/// let config = mock.run(Config::prompt);
+///
+/// mock.assert_prompted("Port: ");
+/// mock.assert_rejected("Please enter a value between 1 and 65535 (e.g. 42).");
+/// mock.assert_prompted_times("User: ", 2);
+/// assert_eq!(
+///     config,
+///     Config { host: String::from("localhost"), port: 443, user: String::from("ada") }
+/// );
+/// # }
+/// ```
+pub trait PromptForm: Sized {
+    /// Asks for every field and returns the struct built from the answers.
+    fn prompt() -> Self;
+}
+
+/// A sequence of questions asked one after another, such as a setup wizard.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::{FieldKind, Form};
+/// let answers = Form::new()
+///     .field("name", "Project name: ", FieldKind::Text)
+///     .field("port", "Port: ", FieldKind::Unsigned)
+///     .run();
+///
+/// let name: String = answers.get("name").unwrap();
+/// let port: u16 = answers.get("port").unwrap();
+/// println!("Serving {name} on port {port}");
+/// ```
+#[derive(Default)]
+pub struct Form {
+    steps: Vec<Step>,
+}
+
+impl Form {
+    /// Creates an empty Form.
+    pub fn new() -> Self {
+        Form::default()
+    }
+
+    /// # ARGUMENTS #
+    /// 'key' (&str) - name used to retrieve the answer from the collected Answers.
+    ///
+    /// 'msg' (&str) - message printed at the same line as the input prompt.
+    ///
+    /// 'kind' (FieldKind) - type of value the user must provide.
+    ///
+    /// # DESCRIPTION #
+    /// Adds a question at the end of the Form.
+    pub fn field(mut self, key: &str, msg: &str, kind: FieldKind) -> Self {
+        self.steps.push(Step::Field(Field {
+            key: key.to_string(),
+            msg: msg.to_string(),
+            kind,
+            condition: None,
+        }));
+        self
+    }
+
+    /// # ARGUMENTS #
+    /// 'condition' (impl Fn(&Answers) -> bool) - closure receiving the answers collected so far,
+    /// which decides whether the question is asked.
+    ///
+    /// 'key' (&str) - name used to retrieve the answer from the collected Answers.
+    ///
+    /// 'msg' (&str) - message printed at the same line as the input prompt.
+    ///
+    /// 'kind' (FieldKind) - type of value the user must provide.
+    ///
+    /// # DESCRIPTION #
+    /// Adds a question at the end of the Form which is only asked if 'condition' returns true
+    /// when the Form reaches it. Skipped questions are not included in the collected Answers.
+    ///
+    /// # EXAMPLES #
+    /// ```no_run
+    /// use quick_input::{FieldKind, Form};
+    /// let answers = Form::new()
+    ///     .field("use_tls", "Enable TLS? ", FieldKind::Bool)
+    ///     .field_if(|answers| answers.get_bool("use_tls") == Some(true), "cert", "Certificate path: ", FieldKind::Text)
+    ///     .run();
+    /// ```
+    pub fn field_if(
+        mut self,
+        condition: impl Fn(&Answers) -> bool + 'static,
+        key: &str,
+        msg: &str,
+        kind: FieldKind,
+    ) -> Self {
+        self.steps.push(Step::Field(Field {
+            key: key.to_string(),
+            msg: msg.to_string(),
+            kind,
+            condition: Some(Box::new(condition)),
+        }));
+        self
+    }
+
+    /// # ARGUMENTS #
+    /// 'key' (&str) - name used to retrieve the chosen branch from the collected Answers.
+    ///
+    /// 'msg' (&str) - message printed at the same line as the input prompt.
+    ///
+    /// 'branches' (Vec<(&str, Form)>) - name of every branch and the questions it asks.
+    ///
+    /// # DESCRIPTION #
+    /// Adds a choice at the end of the Form: the branch names are listed, and the user picks
+    /// one by typing its number or its name (not case-sensitive). The questions of the chosen
+    /// branch are then asked as part of this Form, and the branch name is stored under 'key'
+    /// as an Answer::Text, so the Answers record which branch was taken.
+    ///
+    /// # PANICS #
+    /// If 'branches' is empty.
+    ///
+    /// # EXAMPLES #
+    /// ```no_run
+    /// use quick_input::{FieldKind, Form};
+    /// let answers = Form::new()
+    ///     .branch("install_type", "Install type: ", vec![
+    ///         ("local", Form::new().field("path", "Install path: ", FieldKind::Text)),
+    ///         ("remote", Form::new()
+    ///             .field("host", "Host: ", FieldKind::Text)
+    ///             .field("port", "Port: ", FieldKind::Unsigned)),
+    ///     ])
+    ///     .run();
+    ///
+    /// if answers.get_text("install_type") == Some("remote") {
+    ///     println!("Connecting to {}", answers.get_text("host").unwrap());
+    /// }
+    /// ```
+    pub fn branch(mut self, key: &str, msg: &str, branches: Vec<(&str, Form)>) -> Self {
+        assert!(
+            !branches.is_empty(),
+            "The branch choice '{key}' needs at least one branch."
+        );
+
+        self.steps.push(Step::Branch(Branch {
+            key: key.to_string(),
+            msg: msg.to_string(),
+            options: branches
+                .into_iter()
+                .map(|(name, form)| (name.to_string(), form))
+                .collect(),
+        }));
+        self
+    }
+
+    /// # ARGUMENTS #
+    /// 'title' (&str) - header printed before the following questions.
+    ///
+    /// 'description' (Option<&str>) - an optional text printed under the header.
+    /// Must be set to Some("...") or None.
+    ///
+    /// # DESCRIPTION #
+    /// Starts a group of related questions: when the Form reaches it, the title is printed
+    /// underlined and followed by the description, which helps structuring long Forms.
+    ///
+    /// # EXAMPLES #
+    /// ```no_run
+    /// use quick_input::{FieldKind, Form};
+    /// let answers = Form::new()
+    ///     .section("Project", None)
+    ///     .field("name", "Name: ", FieldKind::Text)
+    ///     .section("Network settings", Some("Where the server will listen for requests."))
+    ///     .field("host", "Host: ", FieldKind::Text)
+    ///     .field("port", "Port: ", FieldKind::Unsigned)
+    ///     .run();
+    /// ```
+    pub fn section(mut self, title: &str, description: Option<&str>) -> Self {
+        self.steps.push(Step::Section(Section {
+            title: title.to_string(),
+            description: description.map(str::to_string),
+        }));
+        self
+    }
+
+    /// # DESCRIPTION #
+    /// Lists every question the Form may ask without asking any, including conditional
+    /// questions and the questions of every branch, which allows documenting a wizard or
+    /// preparing the answers of an unattended run (see catalog_json).
+    ///
+    /// # RETURNS #
+    /// A Vec<CatalogEntry> with the questions in the order they would be asked.
+    ///
+    /// # EXAMPLES #
+    /// ```
+    /// use quick_input::{FieldKind, Form};
+    /// let form = Form::new()
+    ///     .field("name", "Project name: ", FieldKind::Text)
+    ///     .branch("mode", "Mode: ", vec![
+    ///         ("remote", Form::new().field("port", "Port: ", FieldKind::Unsigned)),
+    ///     ]);
+    ///
+    /// let keys: Vec<String> = form.catalog().into_iter().map(|entry| entry.key).collect();
+    /// assert_eq!(keys, ["name", "mode", "port"]);
+    /// ```
+    pub fn catalog(&self) -> Vec<CatalogEntry> {
+        let mut entries = Vec::new();
+        self.catalog_into(&mut entries, &[], None);
+        entries
+    }
+
+    /// # DESCRIPTION #
+    /// Exports the catalog of the Form (see catalog) as a JSON array with one object per
+    /// question, holding its "key", "message", "type" (text, integer, unsigned, real, bool
+    /// or choice), the "options" of a choice, whether it is "conditional", the branches it
+    /// "requires" and its "section".
+    ///
+    /// # RETURNS #
+    /// The JSON array, with one question per line.
+    ///
+    /// # EXAMPLES #
+    /// ```no_run
+    /// use quick_input::{FieldKind, Form};
+    /// let form = Form::new().field("port", "Port: ", FieldKind::Unsigned);
+    ///
+    /// std::fs::write("prompts.json", form.catalog_json()).unwrap();
+    /// ```
+    pub fn catalog_json(&self) -> String {
+        let entries: Vec<String> = self
+            .catalog()
+            .iter()
+            .map(|entry| format!("  {}", entry.to_json()))
+            .collect();
+
+        match entries.is_empty() {
+            true => String::from("[]"),
+            false => format!("[\n{}\n]", entries.join(",\n")),
+        }
+    }
+
+    fn catalog_into<'a>(
+        &'a self,
+        entries: &mut Vec<CatalogEntry>,
+        requires: &[(String, String)],
+        mut section: Option<&'a str>,
+    ) {
+        for step in &self.steps {
+            match step {
+                Step::Field(field) => entries.push(CatalogEntry {
+                    key: field.key.clone(),
+                    message: field.msg.clone(),
+                    kind: Some(field.kind),
+                    options: Vec::new(),
+                    conditional: field.condition.is_some(),
+                    requires: requires.to_vec(),
+                    section: section.map(str::to_string),
+                }),
+                Step::Branch(branch) => {
+                    entries.push(CatalogEntry {
+                        key: branch.key.clone(),
+                        message: branch.msg.clone(),
+                        kind: None,
+                        options: branch
+                            .options
+                            .iter()
+                            .map(|(name, _)| name.clone())
+                            .collect(),
+                        conditional: false,
+                        requires: requires.to_vec(),
+                        section: section.map(str::to_string),
+                    });
+
+                    for (name, form) in &branch.options {
+                        let mut inner = requires.to_vec();
+                        inner.push((branch.key.clone(), name.clone()));
+                        form.catalog_into(entries, &inner, section);
+                    }
+                }
+                Step::Section(new_section) => section = Some(&new_section.title),
+            }
+        }
+    }
+
+    /// Asks every question in order, looping on each one until a valid value is provided.
+    ///
+    /// # RETURNS #
+    /// The Answers provided by the user.
+    pub fn run(&self) -> Answers {
+        let mut answers = Answers::default();
+        self.run_into(&mut answers);
+        answers
+    }
+
+    fn run_into(&self, answers: &mut Answers) {
+        for step in &self.steps {
+            match step {
+                Step::Field(field) => {
+                    if field
+                        .condition
+                        .as_ref()
+                        .is_some_and(|condition| !condition(answers))
+                    {
+                        continue;
+                    }
+
+                    answers.insert(&field.key, ask_field(&field.msg, field.kind));
+                }
+                Step::Branch(branch) => {
+                    let (name, form) = ask_branch(branch);
+                    answers.insert(&branch.key, Answer::Text(name.clone()));
+                    form.run_into(answers);
+                }
+                Step::Section(section) => show_section(section),
+            }
+        }
+    }
+}
+
+fn ask_branch(branch: &Branch) -> &(String, Form) {
+    for (number, (name, _)) in branch.options.iter().enumerate() {
+        outln!("  {}. {name}", number + 1);
+    }
+
+    read_until_valid(Some(&branch.msg), None, |input| {
+        let by_number = input
+            .parse::<usize>()
+            .ok()
+            .and_then(|number| number.checked_sub(1))
+            .and_then(|index| branch.options.get(index));
+        let by_name = || {
+            branch
+                .options
+                .iter()
+                .find(|(name, _)| name.eq_ignore_ascii_case(input))
+        };
+
+        by_number.or_else(by_name).ok_or_else(|| {
+            format!(
+                "Please enter a number between 1 and {} or one of the listed names.",
+                branch.options.len()
+            )
+        })
+    })
+}
+
+fn show_section(section: &Section) {
+    outln!();
+    outln!("{}", section.title);
+    outln!("{}", "=".repeat(section.title.chars().count()));
+
+    if let Some(description) = &section.description {
+        outln!("{description}");
+    }
+}
+
+fn ask_field(msg: &str, kind: FieldKind) -> Answer {
+    match kind {
+        FieldKind::Text => Answer::Text(read_string(Some(msg))),
+        FieldKind::Integer => Answer::Integer(read_i64(Some(msg), None)),
+        FieldKind::Unsigned => Answer::Unsigned(read_u64(Some(msg), None)),
+        FieldKind::Real => Answer::Real(read_f64(Some(msg), None)),
+        FieldKind::Bool => Answer::Bool(read_bool(Some(msg), None)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Answers {
+        let mut answers = Answers::default();
+        answers.insert("name", Answer::Text(String::from("demo")));
+        answers.insert("port", Answer::Unsigned(8080));
+        answers.insert("tls", Answer::Bool(true));
+        answers
+    }
+
+    #[test]
+    fn typed_getters_convert_or_explain() {
+        let answers = sample();
+        assert_eq!(answers.get::<u16>("port"), Ok(8080));
+        assert_eq!(answers.get::<f64>("port"), Ok(8080.0));
+        assert_eq!(answers.get::<String>("name"), Ok(String::from("demo")));
+
+        assert_eq!(
+            answers.get::<u8>("port").unwrap_err().to_string(),
+            "The answer for 'port' (8080) cannot be read as u8."
+        );
+        assert_eq!(
+            answers.get::<bool>("cert"),
+            Err(AnswerError::Missing(String::from("cert")))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "The branch choice 'mode' needs at least one branch.")]
+    fn branch_choices_need_a_branch() {
+        let _ = Form::new().branch("mode", "Mode: ", Vec::new());
+    }
+
+    #[test]
+    fn catalog_lists_every_branch() {
+        let form = Form::new()
+            .section("Network", None)
+            .field_if(|_| true, "host", "Host: ", FieldKind::Text)
+            .branch(
+                "mode",
+                "Mode: ",
+                vec![("tls", Form::new().field("cert", "Cert: ", FieldKind::Text))],
+            );
+
+        assert_eq!(
+            form.catalog_json(),
+            concat!(
+                "[\n",
+                r#"  {"key":"host","message":"Host: ","type":"text","options":[],"conditional":true,"requires":[],"section":"Network"},"#,
+                "\n",
+                r#"  {"key":"mode","message":"Mode: ","type":"choice","options":["tls"],"conditional":false,"requires":[],"section":"Network"},"#,
+                "\n",
+                r#"  {"key":"cert","message":"Cert: ","type":"text","options":[],"conditional":false,"requires":[{"key":"mode","branch":"tls"}],"section":"Network"}"#,
+                "\n]"
+            )
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn answers_serialize_as_ordered_map() {
+        let json = serde_json::to_string(&sample()).unwrap();
+        assert_eq!(json, r#"{"name":"demo","port":8080,"tls":true}"#);
+        assert_eq!(serde_json::from_str::<Answers>(&json).unwrap(), sample());
+    }
+}
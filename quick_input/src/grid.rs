@@ -0,0 +1,137 @@
+//! Readers for matrices of booleans (Ex: the permissions of several users).
+
+use crate::{dry_run, read_until_valid};
+use crate::reader::outln;
+
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as the input prompt. Must be set to Some("...") or None.
+///
+/// 'err_msg' (Option<&str>) - an optional error message which will be printed
+/// if the user inputs an invalid value. Must be set to Some("...") or None.
+///
+/// 'items' (&[&str]) - rows of the grid (Ex: users), in the order they must be typed.
+///
+/// 'options' (&[&str]) - columns of the grid (Ex: permissions), numbered from 1.
+///
+/// # DESCRIPTION #
+/// Lists the numbered options and the items, and then prompts the user to check the options
+/// of every item in a single line: one group per item separated by ';', each group being the
+/// numbers of its checked options separated by ',', or '-' if none is checked.
+/// Ex: "1,3;2;-" checks options 1 and 3 of the first item, option 2 of the second one and
+/// nothing for the third one.
+/// In case the user writes an invalid value, they will be prompted to try again.
+///
+/// Provides an information message on the same line as the prompt if Some("...")
+/// is provided, and just the prompt if None is provided.
+///
+/// If err_msg is set to None, a default message explaining the problem will be shown.
+///
+/// # RETURNS #
+/// A Vec with one row per item, each one with a bool per option telling whether it was checked.
+///
+/// # PANICS #
+/// If 'items' or 'options' is empty.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::read_checkbox_grid;
+/// let users = ["alice", "bob", "carol"];
+/// let grid = read_checkbox_grid(Some("Permissions: "), None, &users, &["read", "write", "admin"]);
+///
+/// for (user, row) in users.iter().zip(&grid) {
+///     println!("{user} can write: {}", row[1]);
+/// }
+/// ```
+pub fn read_checkbox_grid(
+    msg: Option<&str>,
+    err_msg: Option<&str>,
+    items: &[&str],
+    options: &[&str],
+) -> Vec<Vec<bool>> {
+    assert!(!items.is_empty(), "The grid needs at least one item.");
+    assert!(!options.is_empty(), "The grid needs at least one option.");
+
+    let numbered: Vec<String> = options
+        .iter()
+        .enumerate()
+        .map(|(index, option)| format!("{}) {option}", index + 1))
+        .collect();
+    outln!("Options: {}", numbered.join("  "));
+    outln!(
+        "Items (one group each, separated by ';'): {}",
+        items.join("; ")
+    );
+
+    let parse = |input: &str| parse_grid(input, items.len(), options.len());
+
+    if dry_run::is_dry_run() {
+        let unchecked = vec!["-"; items.len()].join(";");
+        return dry_run::placeholder(msg, &[unchecked], parse);
+    }
+
+    read_until_valid(msg, err_msg, parse)
+}
+
+/// # Arguments #
+/// 'input' (&str) - Trimmed input typed by the user.
+///
+/// 'rows' (usize) - Number of items.
+///
+/// 'columns' (usize) - Number of options.
+///
+/// # Description #
+/// Private function parsing groups like "1,3;2;-" into a matrix of booleans.
+fn parse_grid(input: &str, rows: usize, columns: usize) -> Result<Vec<Vec<bool>>, String> {
+    let groups: Vec<&str> = input.split(';').map(str::trim).collect();
+    if groups.len() != rows {
+        return Err(format!(
+            "Please enter {rows} groups separated by ';' (one per item) instead of {}.",
+            groups.len()
+        ));
+    }
+
+    groups
+        .into_iter()
+        .map(|group| parse_row(group, columns))
+        .collect()
+}
+
+fn parse_row(group: &str, columns: usize) -> Result<Vec<bool>, String> {
+    let mut row = vec![false; columns];
+    if group.is_empty() || group == "-" {
+        return Ok(row);
+    }
+
+    for number in group.split(',').map(str::trim) {
+        match number.parse::<usize>() {
+            Ok(column @ 1..) if column <= columns => row[column - 1] = true,
+            _ => {
+                return Err(format!(
+                    "'{number}' is not an option. Please use 1 to {columns}, or '-' for none."
+                ));
+            }
+        }
+    }
+    Ok(row)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn groups_become_rows() {
+        assert_eq!(
+            parse_grid("1,3; 2 ;-", 3, 3),
+            Ok(vec![
+                vec![true, false, true],
+                vec![false, true, false],
+                vec![false, false, false],
+            ])
+        );
+        assert!(parse_grid("1;2", 3, 3).is_err());
+        assert!(parse_grid("1;4;-", 3, 3).is_err());
+        assert!(parse_grid("0;x;-", 3, 3).is_err());
+    }
+}
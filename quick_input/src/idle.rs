@@ -0,0 +1,95 @@
+//! Reminders shown while a prompt waits for a user who stopped typing.
+
+use std::sync::RwLock;
+use std::time::Duration;
+
+use crate::reader::{self, out, outln};
+
+/// Time without input after which the reminder is shown, and the reminder itself
+/// (None to show the prompt again).
+static IDLE_REMINDER: RwLock<Option<(Duration, Option<String>)>> = RwLock::new(None);
+
+/// Message of the last prompt shown on the terminal, printed again by the reminder.
+static LAST_PROMPT: RwLock<String> = RwLock::new(String::new());
+
+/// # ARGUMENTS #
+/// 'after' (Duration) - time without an answer after which the reminder is shown.
+/// Duration::ZERO (the default) disables the reminders.
+///
+/// 'reminder' (Option<&str>) - line printed as the reminder, before the prompt is shown
+/// again. If it is set to None, just the prompt is shown again.
+///
+/// # DESCRIPTION #
+/// Makes every reader remind the user that an answer is awaited once 'after' passes without
+/// one, and again every time the same amount of time passes. This suits kiosks and lab
+/// sessions, where users tend to wander off and miss a question at the bottom of a long output.
+/// Anything typed before the reminder is kept as part of the answer.
+///
+/// Reminders are only shown on the terminal, not in protocol mode or through a custom renderer.
+///
+/// Waiting with a timeout requires a background thread reading stdin, which is started by the
+/// first reader waiting for a line while reminders are enabled and then reads stdin for the
+/// rest of the process, even if the reminders are disabled later. From then on, the features
+/// reading keys one by one are no longer available: masked passwords (Ex:
+/// read_password_masked) are hidden entirely instead, Prompt::edit_on_retry and the Ctrl+C
+/// cancellation of cancellable prompts are ignored (the cancel word still works), and
+/// read_via_editor reads the text from the input instead of opening the editor.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::{read_string, set_idle_reminder};
+/// use std::time::Duration;
+/// set_idle_reminder(Duration::from_secs(60), Some("Still there? Please answer to continue."));
+///
+/// let name = read_string(Some("Student name: "));
+/// ```
+pub fn set_idle_reminder(after: Duration, reminder: Option<&str>) {
+    let setting = (!after.is_zero()).then(|| (after, reminder.map(String::from)));
+    *IDLE_REMINDER
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner()) = setting;
+}
+
+/// Returns the time after which a reminder is due, or None if reminders are disabled.
+pub(crate) fn reminder_delay() -> Option<Duration> {
+    IDLE_REMINDER
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .as_ref()
+        .map(|(after, _)| *after)
+}
+
+/// Remembers the message of the prompt being shown, for the reminders.
+pub(crate) fn remember_prompt(msg: &str) {
+    if reminder_delay().is_none() {
+        return;
+    }
+
+    let mut last = LAST_PROMPT
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    last.clear();
+    last.push_str(msg);
+}
+
+/// # Description #
+/// Private function printing the reminder (if any) on its own line and then the last
+/// prompt again, so the user sees what is being asked.
+pub(crate) fn remind() {
+    let reminder = IDLE_REMINDER
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .as_ref()
+        .and_then(|(_, reminder)| reminder.clone());
+    let prompt = LAST_PROMPT
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .clone();
+
+    outln!();
+    if let Some(reminder) = reminder {
+        outln!("{reminder}");
+    }
+    out!("{prompt}");
+    let _ = reader::flush();
+}
@@ -0,0 +1,154 @@
+//! Shared access to the lines typed on stdin.
+//!
+//! Lines are normally read straight from stdin. The first time a line is read with a deadline,
+//! a background thread takes over reading stdin and forwards every line through a channel,
+//! which is then used by every later read, so no line is lost between both kinds of reads.
+
+use std::io;
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::sync::{Mutex, OnceLock};
+use std::thread;
+use std::time::Instant;
+
+use crate::{idle, protocol, reader, render};
+
+static BACKGROUND_LINES: OnceLock<Mutex<Receiver<io::Result<String>>>> = OnceLock::new();
+
+/// # Arguments #
+/// 'input' (&mut String) - String the line typed by the user is appended to.
+///
+/// # Description #
+/// Reads a line from the running Reader (if any) or from stdin, blocking until it is
+/// available. If an idle reminder is set, it is shown every time its delay passes without a
+/// line, which requires the background thread (see set_idle_reminder).
+///
+/// # Returns #
+/// The number of bytes read, which is 0 once stdin is closed.
+pub(crate) fn read_line(input: &mut String) -> io::Result<usize> {
+    if let Some(read) = reader::read_line(input) {
+        return read;
+    }
+    if let Some(answer) = render::with_renderer(|renderer| renderer.collect_answer()) {
+        return Ok(push_answer(input, answer));
+    }
+
+    if let Some(after) = idle::reminder_delay().filter(|_| !protocol::is_protocol_mode()) {
+        loop {
+            if let Some(read) = read_line_before(input, Instant::now() + after) {
+                return read;
+            }
+            idle::remind();
+        }
+    }
+
+    match BACKGROUND_LINES.get() {
+        Some(lines) => {
+            let lines = lines
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner());
+            receive(
+                lines.recv().map_err(|_| RecvTimeoutError::Disconnected),
+                input,
+            )
+            .unwrap_or(Ok(0))
+        }
+        None => {
+            let mut line = String::new();
+            let read = io::stdin().read_line(&mut line)?;
+            push_line(input, &line);
+            Ok(read)
+        }
+    }
+}
+
+/// # Returns #
+/// True once stdin is being read by the background thread, in which case nothing else
+/// may read from stdin directly.
+pub(crate) fn is_background() -> bool {
+    BACKGROUND_LINES.get().is_some()
+}
+
+/// # Arguments #
+/// 'input' (&mut String) - String the line typed by the user is appended to.
+///
+/// 'deadline' (Instant) - Moment after which the read is abandoned.
+///
+/// # Description #
+/// Reads a line from stdin, waiting at most until 'deadline'.
+/// A line typed after the deadline is kept for the next read.
+///
+/// # Returns #
+/// None if the deadline passed, or the number of bytes read (0 once stdin is closed).
+pub(crate) fn read_line_before(input: &mut String, deadline: Instant) -> Option<io::Result<usize>> {
+    if let Some(read) = reader::read_line(input) {
+        return Some(read);
+    }
+    if let Some(answer) = render::with_renderer(|renderer| renderer.collect_answer()) {
+        return Some(Ok(push_answer(input, answer)));
+    }
+
+    let lines = BACKGROUND_LINES.get_or_init(|| {
+        let (sender, receiver) = mpsc::channel();
+
+        thread::spawn(move || {
+            loop {
+                let mut line = String::new();
+                let result = io::stdin().read_line(&mut line).map(|_| line);
+                let finished = !matches!(&result, Ok(line) if !line.is_empty());
+
+                if sender.send(result).is_err() || finished {
+                    break;
+                }
+            }
+        });
+
+        Mutex::new(receiver)
+    });
+
+    let lines = lines
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    receive(
+        lines.recv_timeout(deadline.saturating_duration_since(Instant::now())),
+        input,
+    )
+}
+
+fn receive(
+    received: Result<io::Result<String>, RecvTimeoutError>,
+    input: &mut String,
+) -> Option<io::Result<usize>> {
+    match received {
+        Ok(Ok(line)) => {
+            push_line(input, &line);
+            Some(Ok(line.len()))
+        }
+        Ok(Err(err)) => Some(Err(err)),
+        Err(RecvTimeoutError::Timeout) => None,
+        Err(RecvTimeoutError::Disconnected) => Some(Ok(0)),
+    }
+}
+
+/// Appends a line read from stdin to 'input'. In protocol mode, the line holds a JSON answer
+/// which is decoded first.
+fn push_line(input: &mut String, line: &str) {
+    if line.is_empty() || !protocol::is_protocol_mode() {
+        input.push_str(line);
+        return;
+    }
+
+    input.push_str(&protocol::decode_answer(line));
+    input.push('\n');
+}
+
+/// Appends the answer collected by a renderer to 'input' as if it was a line typed on stdin,
+/// returning its length (0 if there is no answer, like a closed stdin).
+fn push_answer(input: &mut String, answer: Option<String>) -> usize {
+    let Some(answer) = answer else {
+        return 0;
+    };
+
+    input.push_str(&answer);
+    input.push('\n');
+    answer.len() + 1
+}
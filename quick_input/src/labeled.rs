@@ -0,0 +1,160 @@
+//! Readers asking for several labeled values on a single line.
+
+use crate::{Answer, Answers, FieldKind};
+use crate::{dry_run, read_until_valid};
+
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as the input prompt. Must be set to Some("...") or None.
+///
+/// 'components' (&[(&str, FieldKind)]) - label and type of every value, in order.
+///
+/// # DESCRIPTION #
+/// Prompts the user to type several values on one line, separated by spaces, either labeled
+/// (Ex: width=800 height=600, in any order) or positionally (Ex: 800 600). Both forms can be
+/// mixed, in which case the unlabeled values fill in the missing labels in order.
+/// Every value is validated, and the error message names the label which failed.
+/// In case the user writes an invalid value, they will be prompted to try again.
+///
+/// Provides an information message on the same line as the prompt if Some("...")
+/// is provided, and just the prompt if None is provided.
+///
+/// # RETURNS #
+/// The Answers provided by the user, stored under their labels in the order of 'components'.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::{read_labeled, FieldKind};
+/// let size = read_labeled(
+///     Some("Window size (width height): "),
+///     &[("width", FieldKind::Unsigned), ("height", FieldKind::Unsigned)],
+/// );
+///
+/// println!("{}x{}", size.get_unsigned("width").unwrap(), size.get_unsigned("height").unwrap());
+/// ```
+pub fn read_labeled(msg: Option<&str>, components: &[(&str, FieldKind)]) -> Answers {
+    if dry_run::is_dry_run() {
+        let sample: Vec<&str> = components
+            .iter()
+            .map(|(_, kind)| match kind {
+                FieldKind::Text => "text",
+                FieldKind::Bool => "false",
+                _ => "0",
+            })
+            .collect();
+
+        return dry_run::placeholder(msg, &[sample.join(" ")], |input| {
+            parse_labeled(input, components)
+        });
+    }
+
+    read_until_valid(msg, None, |input| parse_labeled(input, components))
+}
+
+fn parse_labeled(input: &str, components: &[(&str, FieldKind)]) -> Result<Answers, String> {
+    let mut values: Vec<Option<&str>> = vec![None; components.len()];
+    let mut positional = Vec::new();
+
+    for token in input.split_whitespace() {
+        let Some((label, value)) = token.split_once('=') else {
+            positional.push(token);
+            continue;
+        };
+
+        let index = components
+            .iter()
+            .position(|(name, _)| name.eq_ignore_ascii_case(label))
+            .ok_or_else(|| format!("Unknown label '{label}'. {}", expected(components)))?;
+        if values[index].replace(value).is_some() {
+            return Err(format!(
+                "'{}' was given more than once.",
+                components[index].0
+            ));
+        }
+    }
+
+    let mut positional = positional.into_iter();
+    for value in values.iter_mut().filter(|value| value.is_none()) {
+        *value = positional.next();
+    }
+    if positional.next().is_some() {
+        return Err(format!("Too many values. {}", expected(components)));
+    }
+
+    let mut answers = Answers::default();
+    for ((label, kind), value) in components.iter().zip(values) {
+        let value = value.ok_or_else(|| format!("Missing value for '{label}'."))?;
+        answers.insert(label, parse_component(label, *kind, value)?);
+    }
+
+    Ok(answers)
+}
+
+fn parse_component(label: &str, kind: FieldKind, value: &str) -> Result<Answer, String> {
+    let (answer, expected) = match kind {
+        FieldKind::Text => (Some(Answer::Text(value.to_string())), "text"),
+        FieldKind::Integer => (value.parse().ok().map(Answer::Integer), "an integer"),
+        FieldKind::Unsigned => (
+            value.parse().ok().map(Answer::Unsigned),
+            "a positive integer",
+        ),
+        FieldKind::Real => (value.parse().ok().map(Answer::Real), "a real number"),
+        FieldKind::Bool => (
+            value.to_lowercase().parse().ok().map(Answer::Bool),
+            "true or false",
+        ),
+    };
+
+    answer.ok_or_else(|| format!("'{label}' must be {expected} (got '{value}')."))
+}
+
+fn expected(components: &[(&str, FieldKind)]) -> String {
+    let labels: Vec<&str> = components.iter().map(|(label, _)| *label).collect();
+    format!("Expected: {}.", labels.join(" "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SIZE: &[(&str, FieldKind)] = &[
+        ("width", FieldKind::Unsigned),
+        ("height", FieldKind::Unsigned),
+    ];
+
+    #[test]
+    fn labeled_and_positional_values_are_accepted() {
+        for input in [
+            "width=800 height=600",
+            "height=600 width=800",
+            "800 600",
+            "height=600 800",
+        ] {
+            let answers = parse_labeled(input, SIZE).unwrap();
+            assert_eq!(answers.get_unsigned("width"), Some(800));
+            assert_eq!(answers.get_unsigned("height"), Some(600));
+        }
+    }
+
+    #[test]
+    fn failing_label_is_reported() {
+        assert_eq!(
+            parse_labeled("width=800 height=-1", SIZE).unwrap_err(),
+            "'height' must be a positive integer (got '-1')."
+        );
+        assert_eq!(
+            parse_labeled("800", SIZE).unwrap_err(),
+            "Missing value for 'height'."
+        );
+        assert!(
+            parse_labeled("depth=3", SIZE)
+                .unwrap_err()
+                .starts_with("Unknown label 'depth'.")
+        );
+        assert!(
+            parse_labeled("1 2 3", SIZE)
+                .unwrap_err()
+                .starts_with("Too many values.")
+        );
+    }
+}
@@ -14,8 +14,156 @@
 
 // ----- BASIC ----- //
 
-use std::io;
-use std::io::Write;
+use std::str::FromStr;
+
+use crate::reader::outln;
+
+mod bounded;
+mod cache;
+mod calendar;
+mod chars;
+mod choice;
+mod clock;
+mod collect;
+mod confirm;
+mod date;
+mod defaults;
+mod dimensions;
+mod dry_run;
+mod editor;
+mod eof;
+mod error;
+mod examples;
+mod fallible;
+mod files;
+mod flags;
+mod form;
+mod grid;
+mod idle;
+mod input;
+mod labeled;
+mod lines;
+mod menu;
+mod messages;
+mod multiline;
+mod net;
+mod opt_in;
+mod optional;
+mod password;
+mod postprocess;
+pub mod prompt;
+mod protocol;
+mod reader;
+mod record;
+mod render;
+mod retry;
+mod review;
+mod row;
+mod schedule;
+mod session;
+mod sources;
+mod split;
+mod suggest;
+pub mod term;
+pub mod testing;
+mod text;
+mod translit;
+mod tty;
+mod twice;
+mod version;
+
+pub use bounded::{
+    read_f32_in_range, read_f64_in_range, read_i32_in_range, read_i64_in_range, read_in_range,
+    read_u32_in_range, read_u64_in_range, read_usize_in_range,
+};
+pub use cache::AnswerCache;
+pub use calendar::{CalendarNames, Month, Weekday, read_month, read_weekday};
+pub use chars::{
+    read_char_in, read_char_in_ignore_case, read_digit_char, read_letter, read_uppercase_letter,
+};
+pub use choice::{Choice, read_choice};
+pub use clock::read_duration_hms;
+pub use collect::{read_until, read_vec_until};
+pub use confirm::confirm_each;
+pub use date::{Date, DateRange, read_date_range, read_date_range_split};
+pub use defaults::{read_f64_or, read_i32_or, read_or, read_string_or, read_u32_or};
+pub use dimensions::read_size2d;
+pub use dry_run::{is_dry_run, set_dry_run};
+pub use eof::{EofPolicy, set_eof_policy};
+pub use error::InputError;
+pub use fallible::{
+    read_i32_limited, read_limited, read_string_timeout, read_u32_limited, try_read,
+};
+pub use files::{
+    confirm_overwrite, confirm_overwrite_or_rename, read_file_bytes, read_file_contents,
+};
+pub use flags::read_keyvalue_flags;
+pub use form::{
+    Answer, AnswerError, Answers, CatalogEntry, FieldKind, Form, FromAnswer, PromptForm,
+};
+pub use grid::read_checkbox_grid;
+pub use idle::set_idle_reminder;
+pub use menu::{
+    ChoiceOrNew, multi_select, read_choice_map, read_choice_map_with_default,
+    read_choice_multi_column_with_search, read_existing_choice_or_new, select, select_value,
+};
+pub use messages::{Messages, set_messages};
+pub use multiline::{read_multiline, read_multiline_until};
+pub use opt_in::read_opt_in_list;
+pub use optional::{read_opt, read_opt_f64, read_opt_i32, read_opt_string, read_opt_u32};
+pub use password::{
+    Secret, read_password, read_password_confirmed, read_password_masked, read_secret,
+};
+pub use postprocess::{AnsweredPrompt, add_post_processor, clear_post_processors};
+pub use prompt::Prompt;
+pub use protocol::{is_protocol_mode, set_protocol_mode};
+pub use reader::Reader;
+pub use render::{PromptRenderer, PromptView, reset_renderer, set_renderer};
+pub use retry::set_retry_delay;
+pub use review::{print_review, render_review};
+pub use row::{read_array, read_n, read_pair, read_triple, read_vec};
+pub use schedule::{Time, read_paired_ranges};
+pub use session::{Session, SessionStats};
+pub use sources::{AnswerOrigin, AnswerSource};
+pub use split::{Delimiters, read_nested_list, read_semicolon_list_of_pairs};
+pub use labeled::read_labeled;
+pub use lines::{lines, values};
+pub use net::{read_endpoint, read_port, read_port_with_warning, read_socket_addr};
+pub use text::{
+    UsernameRules, read_key_path, read_nonempty_string, read_string_bounded,
+    read_string_with_suggestions, read_username,
+};
+pub use translit::{read_slug, slugify, to_ascii};
+pub use tty::TerminalGuard;
+pub use twice::{read_hidden_twice, read_twice};
+pub use version::{Version, read_version_bump};
+
+#[cfg(any(feature = "glob", feature = "regex"))]
+mod patterns;
+
+#[cfg(feature = "glob")]
+pub use patterns::{read_glob, read_glob_with_preview};
+#[cfg(feature = "regex")]
+pub use patterns::{read_matching, read_matching_captures, read_matching_regex, read_regex};
+
+#[cfg(feature = "tokio")]
+pub mod asynchronous;
+#[cfg(feature = "external-editor")]
+mod external_editor;
+#[cfg(feature = "iso-codes")]
+mod codes;
+#[cfg(feature = "chrono-tz")]
+mod time;
+
+#[cfg(feature = "chrono-tz")]
+pub use time::read_timezone;
+#[cfg(feature = "external-editor")]
+pub use external_editor::read_via_editor;
+#[cfg(feature = "derive")]
+pub use quick_input_derive::{Choice, PromptForm};
+
+#[cfg(feature = "iso-codes")]
+pub use codes::{Currency, read_country_code, read_currency_code, read_language_code, read_locale};
 
 /// # ARGUMENTS #
 /// 'msg' (Option<&str>) - an optional message which will be printed at
@@ -28,10 +176,10 @@ use std::io::Write;
 /// is provided, and just the prompt if None is provided.
 ///
 /// # RETURNS #
-/// A trimmed String value provided by the user.
+/// A trimmed String value provided by the user, or an empty String if the input ended.
 ///
 /// # EXAMPLES #
-/// ```
+/// ```no_run
 /// use quick_input::read_string;
 /// let user_str_with_msg = read_string(Some("Please input some text: "));
 ///
@@ -40,14 +188,13 @@ use std::io::Write;
 pub fn read_string(msg: Option<&str>) -> String {
     let mut input = String::new();
 
-    if msg.is_some() {
-        print!("{}", msg.unwrap());
-        flush_and_read(&mut input);
-    } else {
-        flush_and_read(&mut input);
-    }
+    render::show_prompt(msg.unwrap_or_default(), false, None);
+    // Only looping readers apply the EOF policy, since this one can't loop forever.
+    flush_and_try_read(&mut input);
 
-    input.trim().to_string()
+    let mut input = input.trim().to_string();
+    postprocess::run(msg.unwrap_or_default(), &mut input);
+    input
 }
 
 /// # ARGUMENTS #
@@ -70,37 +217,17 @@ pub fn read_string(msg: Option<&str>) -> String {
 /// An integer value of type i32 provided by the user.
 ///
 /// # EXAMPLES #
-/// ```
+/// ```no_run
 /// use quick_input::read_i32;
 /// let user_i32_with_msg = read_i32(Some("Please input a number: "), Some("Please input a valid number."));
 ///
 /// let user_i32: i32 = read_i32(None, None);
 /// ```
 pub fn read_i32(msg: Option<&str>, err_msg: Option<&str>) -> i32 {
-    let mut input = String::new();
-
-    if msg.is_some() {
-        while input.trim().parse::<i32>().is_err() {
-            input.clear();
-            print!("{}", msg.unwrap());
-            flush_and_read(&mut input);
-
-            if input.trim().parse::<i32>().is_err() {
-                show_error_message(err_msg, "Please enter a valid number (32 bits).");
-            }
-        }
-    } else {
-        while input.trim().parse::<i32>().is_err() {
-            input.clear();
-            flush_and_read(&mut input);
-
-            if input.trim().parse::<i32>().is_err() {
-                show_error_message(err_msg, "Please enter a valid number (32 bits).");
-            }
-        }
-    }
-
-    input.trim().parse().unwrap()
+    read_until_valid(msg, err_msg, |input| {
+        input.parse()
+            .map_err(|_| String::from("Please enter a valid number (32 bits)."))
+    })
 }
 
 /// # ARGUMENTS #
@@ -123,37 +250,17 @@ pub fn read_i32(msg: Option<&str>, err_msg: Option<&str>) -> i32 {
 /// An integer value of type u32 provided by the user.
 ///
 /// # EXAMPLES #
-/// ```
+/// ```no_run
 /// use quick_input::read_u32;
 /// let user_u32_with_msg = read_u32(Some("Please input a number: "), Some("Please input a valid number."));
 ///
 /// let user_u32: u32 = read_u32(None, None);
 /// ```
-pub fn read_u32(msg: Option<&str>, err_msg :Option<&str>) -> u32 {
-    let mut input = String::new();
-
-    if msg.is_some() {
-        while input.trim().parse::<u32>().is_err() {
-            input.clear();
-            print!("{}", msg.unwrap());
-            flush_and_read(&mut input);
-
-            if input.trim().parse::<u32>().is_err() {
-                show_error_message(err_msg, "Please enter a valid positive number (32 bits).");
-            }
-        }
-    } else {
-        while input.trim().parse::<i32>().is_err() {
-            input.clear();
-            flush_and_read(&mut input);
-
-            if input.trim().parse::<i32>().is_err() {
-                show_error_message(err_msg, "Please enter a valid positive number (32 bits).");
-            }
-        }
-    }
-
-    input.trim().parse().unwrap()
+pub fn read_u32(msg: Option<&str>, err_msg: Option<&str>) -> u32 {
+    read_until_valid(msg, err_msg, |input| {
+        input.parse()
+            .map_err(|_| String::from("Please enter a valid positive number (32 bits)."))
+    })
 }
 
 /// # ARGUMENTS #
@@ -166,6 +273,8 @@ pub fn read_u32(msg: Option<&str>, err_msg :Option<&str>) -> u32 {
 /// # DESCRIPTION #
 /// Prompts the user to type a real number with double precision (f64) which will then be returned.
 /// Both '.' and ',' are accepted as separators for the decimal part (Ex: 12.3 and 45,67).
+/// Scientific notation (Ex: 1e-3, 6.02E23) is accepted as well; use read_f64_with_notation
+/// to reject it.
 /// If the user writes an invalid value, they will be prompted to try again.
 ///
 /// Provides an information message on the same line as the prompt if Some("...")
@@ -177,37 +286,16 @@ pub fn read_u32(msg: Option<&str>, err_msg :Option<&str>) -> u32 {
 /// A floating point value of type f64 provided by the user.
 ///
 /// # EXAMPLES #
-/// ```
+/// ```no_run
 /// use quick_input::read_f64;
 /// let user_f64_with_msg = read_f64(Some("Please input a number with decimals: "), Some("Please input a valid number."));
 ///
 /// let user_f64: f64 = read_f64(None, None);
 /// ```
 pub fn read_f64(msg: Option<&str>, err_msg: Option<&str>) -> f64 {
-    let mut input = String::new();
-
-    if msg.is_some() {
-        while input.replace(',', ".").trim().parse::<f64>().is_err() {
-            input.clear();
-            print!("{}", msg.unwrap());
-            flush_and_read(&mut input);
-
-            if input.replace(',', ".").trim().parse::<f64>().is_err() {
-                show_error_message(err_msg, "Please enter a valid real number (64 bits).");
-            }
-        }
-    } else {
-        while input.trim().parse::<f64>().is_err() {
-            input.clear();
-            flush_and_read(&mut input);
-
-            if input.replace(',', ".").trim().parse::<f64>().is_err() {
-                show_error_message(err_msg, "Please enter a valid real number (64 bits).");
-            }
-        }
-    }
-
-    input.replace(',', ".").trim().parse().unwrap()
+    read_until_valid(msg, err_msg, |input| {
+        parse_real(input, true, "Please enter a valid real number (64 bits).")
+    })
 }
 
 /// # ARGUMENTS #
@@ -225,25 +313,19 @@ pub fn read_f64(msg: Option<&str>, err_msg: Option<&str>) -> f64 {
 /// A single character (char) provided by the user.
 ///
 /// # EXAMPLES #
-/// ```
+/// ```no_run
 /// use quick_input::read_char;
 /// let user_char_with_msg = read_char(Some("Please input a character: "));
 ///
 /// let user_char: char = read_char(None);
 /// ```
 pub fn read_char(msg: Option<&str>) -> char {
-    let mut input = String::from(".");
-
-    if msg.is_some() {
-        input.clear();
-        print!("{}", msg.unwrap());
-        flush_and_read(&mut input);
-    } else {
-        input.clear();
-        flush_and_read(&mut input);
-    }
-
-    input.trim().chars().next().unwrap()
+    read_until_valid(msg, None, |input| {
+        input
+            .chars()
+            .next()
+            .ok_or_else(|| String::from("Please enter a character."))
+    })
 }
 
 /// # ARGUMENTS #
@@ -256,6 +338,7 @@ pub fn read_char(msg: Option<&str>) -> char {
 /// # DESCRIPTION #
 /// Prompts the user to type a boolean value (bool) manually, which will then be returned.
 /// This function is not case-sensitive, so values like True or fAlSe will still work.
+/// For questions answered with y / n, see read_yes_no.
 /// In case the user writes an invalid value, they will be prompted to try again.
 ///
 /// Provides an information message on the same line as the prompt if Some("...")
@@ -267,43 +350,17 @@ pub fn read_char(msg: Option<&str>) -> char {
 /// A boolean value (bool) provided by the user.
 ///
 /// # EXAMPLES #
-/// ```
+/// ```no_run
 /// use quick_input::read_bool;
 /// let user_bool_with_msg = read_bool(Some("Please input a boolean value: "), Some("Please input true or false."));
 ///
 /// let user_bool: bool = read_bool(None, None);
 /// ```
 pub fn read_bool(msg: Option<&str>, err_msg: Option<&str>) -> bool {
-    let mut input = String::new();
-
-    if msg.is_some() {
-        while input.trim().parse::<bool>().is_err() {
-            input.clear();
-            print!("{}", msg.unwrap());
-            flush_and_read(&mut input);
-
-            input = input.trim().to_lowercase();
-
-            if input.parse::<bool>().is_err() {
-                show_error_message(err_msg, "Please enter a valid boolean value (true / false).");
-            }
-        }
-    } else {
-        while input.trim().parse::<bool>().is_err() {
-            input.clear();
-            flush_and_read(&mut input);
-
-            input = input.trim().to_lowercase();
-
-            println!("{input}");
-
-            if input.parse::<bool>().is_err() {
-                show_error_message(err_msg, "Please enter a valid boolean value (true / false).");
-            }
-        }
-    }
-
-    input.trim().parse::<bool>().unwrap()
+    read_until_valid(msg, err_msg, |input| {
+        input.to_lowercase().parse()
+            .map_err(|_| String::from("Please enter a valid boolean value (true / false)."))
+    })
 }
 
 // ----- EXTRA ----- //
@@ -319,10 +376,11 @@ pub fn read_bool(msg: Option<&str>, err_msg: Option<&str>) -> bool {
 /// is provided, and just the prompt if None is provided.
 ///
 /// # RETURNS #
-/// A non-trimmed String value provided by the user.
+/// A non-trimmed String value provided by the user, ending with the line break typed by the
+/// user, or an empty String if the input ended (which tells both cases apart).
 ///
 /// # EXAMPLES #
-/// ```
+/// ```no_run
 /// use quick_input::read_string_untrimmed;
 /// let user_str_with_msg = read_string_untrimmed(Some("Please input some text: "));
 ///
@@ -331,15 +389,285 @@ pub fn read_bool(msg: Option<&str>, err_msg: Option<&str>) -> bool {
 pub fn read_string_untrimmed(msg: Option<&str>) -> String {
     let mut input = String::new();
 
-    if msg.is_some() {
-        print!("{}", msg.unwrap());
-        flush_and_read(&mut input);
-    } else {
-        flush_and_read(&mut input);
-    }
+    render::show_prompt(msg.unwrap_or_default(), false, None);
+    flush_and_try_read(&mut input);
     input
 }
 
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as the input prompt. Must be set to Some("...") or None.
+///
+/// 'err_msg' (Option<&str>) - an optional error message which will be printed
+/// if the user inputs an invalid value. Must be set to Some("...") or None.
+///
+/// 'any_nonzero' (bool) - whether any integer other than 0 is accepted as true (C-style),
+/// instead of just 1.
+///
+/// # DESCRIPTION #
+/// Prompts the user to type a boolean value (bool) which will then be returned, accepting
+/// 1 and 0 besides true and false (not case-sensitive). Useful for exercises ported from C,
+/// where flags are usually typed as numbers.
+/// In case the user writes an invalid value, they will be prompted to try again.
+///
+/// Provides an information message on the same line as the prompt if Some("...")
+/// is provided, and just the prompt if None is provided.
+///
+/// If err_msg is set to None, a default message will be shown.
+///
+/// # RETURNS #
+/// A boolean value (bool) provided by the user.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::read_bool_int;
+/// let user_flag_with_msg = read_bool_int(Some("Verbose (1 / 0): "), None, false);
+///
+/// let user_flag: bool = read_bool_int(None, None, true);
+/// ```
+pub fn read_bool_int(msg: Option<&str>, err_msg: Option<&str>, any_nonzero: bool) -> bool {
+    read_until_valid(msg, err_msg, |input| parse_bool_int(input, any_nonzero))
+}
+
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as the input prompt. Must be set to Some("...") or None.
+///
+/// 'err_msg' (Option<&str>) - an optional error message which will be printed
+/// if the user inputs an invalid value. Must be set to Some("...") or None.
+///
+/// # DESCRIPTION #
+/// Prompts the user to answer yes, no or skip, which suits configuration prompts where
+/// leaving a setting as it is must be told apart from an explicit "no".
+/// Accepted answers (not case-sensitive) are y / yes / true, n / no / false, and
+/// s / skip or an empty line to skip.
+/// In case the user writes an invalid value, they will be prompted to try again.
+///
+/// Provides an information message on the same line as the prompt if Some("...")
+/// is provided, and just the prompt if None is provided.
+///
+/// If err_msg is set to None, a default message will be shown.
+///
+/// # RETURNS #
+/// Some(true) or Some(false) if the user answered yes or no, and None if they skipped.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::read_tristate;
+/// let telemetry = read_tristate(Some("Enable telemetry? (y / n / skip): "), None);
+///
+/// if let Some(enabled) = telemetry {
+///     println!("Telemetry set to {enabled}.");
+/// }
+/// ```
+pub fn read_tristate(msg: Option<&str>, err_msg: Option<&str>) -> Option<bool> {
+    read_until_valid(msg, err_msg, parse_tristate)
+}
+
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as the input prompt. Must be set to Some("...") or None.
+///
+/// 'err_msg' (Option<&str>) - an optional error message which will be printed
+/// if the user inputs an invalid value. Must be set to Some("...") or None.
+///
+/// # DESCRIPTION #
+/// Prompts the user to answer a yes / no question, accepting y / yes and n / no
+/// (not case-sensitive), which is friendlier than typing true or false (see read_bool).
+/// To let the user press Enter for a default answer, see read_yes_no_or.
+/// In case the user writes an invalid value, they will be prompted to try again.
+///
+/// Provides an information message on the same line as the prompt if Some("...")
+/// is provided, and just the prompt if None is provided.
+///
+/// If err_msg is set to None, a default message will be shown (see set_messages).
+///
+/// # RETURNS #
+/// True if the user answered yes, false if they answered no.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::read_yes_no;
+/// let save = read_yes_no(Some("Save the changes? (y / n): "), None);
+///
+/// let again: bool = read_yes_no(None, Some("Please type y or n."));
+/// ```
+pub fn read_yes_no(msg: Option<&str>, err_msg: Option<&str>) -> bool {
+    read_until_valid(msg, err_msg, parse_yes_no)
+}
+
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as the input prompt. Must be set to Some("...") or None.
+///
+/// 'err_msg' (Option<&str>) - an optional error message which will be printed
+/// if the user inputs an invalid value. Must be set to Some("...") or None.
+///
+/// 'default' (bool) - answer used if the user presses Enter without typing anything.
+///
+/// # DESCRIPTION #
+/// Works like read_yes_no, but an empty answer is accepted as 'default', which is shown
+/// in the message as [Y/n] (default yes) or [y/N] (default no), following the usual
+/// convention of command line tools (Ex: "Continue? " becomes "Continue? [Y/n] ").
+///
+/// # RETURNS #
+/// True if the user answered yes, false if they answered no, or 'default' if the
+/// input was empty.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::read_yes_no_or;
+/// let install = read_yes_no_or(Some("Install the dependencies? "), None, true);
+/// ```
+pub fn read_yes_no_or(msg: Option<&str>, err_msg: Option<&str>, default: bool) -> bool {
+    let hint = if default { "Y/n" } else { "y/N" };
+    let msg = defaults::show_default(msg.unwrap_or_default(), hint);
+
+    read_until_valid(Some(&msg), err_msg, |input| match input {
+        "" => Ok(default),
+        _ => parse_yes_no(input),
+    })
+}
+
+/// # ARGUMENTS #
+/// 'msg' (&str) - question printed at the same line as the input prompt.
+///
+/// # DESCRIPTION #
+/// Asks the user to confirm something (Ex: deleting a file), defaulting to no so that
+/// pressing Enter by mistake is harmless. Shorthand for read_yes_no_or(Some(msg), None, false).
+///
+/// # RETURNS #
+/// True only if the user answered yes.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::confirm;
+/// if confirm("Delete every log file? ") {
+///     println!("Deleting...");
+/// }
+/// ```
+pub fn confirm(msg: &str) -> bool {
+    read_yes_no_or(Some(msg), None, false)
+}
+
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as the input prompt. Must be set to Some("...") or None.
+///
+/// 'err_msg' (Option<&str>) - an optional error message which will be printed
+/// if the user inputs an invalid value. Must be set to Some("...") or None.
+///
+/// 'none_keywords' (&[&str]) - words meaning "no value" (Ex: &["none", "-", "null"]),
+/// compared without case-sensitivity.
+///
+/// # DESCRIPTION #
+/// Prompts the user to type a value of any type implementing FromStr, or one of the
+/// keywords meaning there is no value. Unlike an empty line, a keyword can't be typed
+/// by accident, so it is a better choice for fields where leaving them blank is ambiguous.
+/// To accept an empty line as no value instead, see read_opt.
+/// In case the user writes an invalid value, they will be prompted to try again.
+///
+/// Provides an information message on the same line as the prompt if Some("...")
+/// is provided, and just the prompt if None is provided.
+///
+/// If err_msg is set to None, a default message listing the keywords will be shown.
+///
+/// # RETURNS #
+/// Some(T) with the value provided by the user, or None if a keyword was typed.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::read_option;
+/// let user_limit_with_msg: Option<u32> = read_option(Some("Limit (or none): "), None, &["none", "-"]);
+///
+/// let user_limit: Option<f64> = read_option(None, None, &["null"]);
+/// ```
+pub fn read_option<T: FromStr>(
+    msg: Option<&str>,
+    err_msg: Option<&str>,
+    none_keywords: &[&str],
+) -> Option<T> {
+    read_until_valid(msg, err_msg, |input| {
+        if none_keywords
+            .iter()
+            .any(|keyword| keyword.eq_ignore_ascii_case(input))
+        {
+            return Ok(None);
+        }
+
+        input.parse().map(Some).map_err(|_| match none_keywords {
+            [] => String::from("Please enter a valid value."),
+            keywords => format!(
+                "Please enter a valid value (or {} if there is none).",
+                keywords.join(" / ")
+            ),
+        })
+    })
+}
+
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as the input prompt. Must be set to Some("...") or None.
+///
+/// 'err_msg' (Option<&str>) - an optional error message which will be printed
+/// if the input cannot be parsed as T. Must be set to Some("...") or None.
+///
+/// 'validator' (impl Fn(&T) -> Result<(), String>) - closure receiving the parsed value,
+/// which returns the error message to show if the value is not acceptable.
+///
+/// # DESCRIPTION #
+/// Prompts the user to type a value of any type implementing FromStr, which must also be
+/// accepted by the validator (Ex: an even number, or a name with no spaces).
+/// If the input cannot be parsed, err_msg (or a default message with an example of a valid
+/// value, if it is set to None) is shown, and if the validator rejects the value, the message it returned is shown instead.
+/// In both cases the user will be prompted to try again.
+///
+/// Provides an information message on the same line as the prompt if Some("...")
+/// is provided, and just the prompt if None is provided.
+///
+/// For more than one rule, or to combine it with a range or a default value, see Prompt::validate.
+///
+/// # RETURNS #
+/// A value of type T provided by the user and accepted by the validator.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::read_with_validator;
+/// let players: u32 = read_with_validator(Some("Players (even): "), None, |players: &u32| {
+///     match players % 2 {
+///         0 => Ok(()),
+///         _ => Err(String::from("The number of players must be even.")),
+///     }
+/// });
+///
+/// let tag: String = read_with_validator(Some("Tag: "), None, |tag: &String| match tag.is_empty() {
+///     true => Err(String::from("The tag cannot be empty.")),
+///     false => Ok(()),
+/// });
+/// ```
+pub fn read_with_validator<T: FromStr>(
+    msg: Option<&str>,
+    err_msg: Option<&str>,
+    validator: impl Fn(&T) -> Result<(), String>,
+) -> T {
+    read_until_valid(msg, None, |input| {
+        let value = input.parse().map_err(|_| {
+            err_msg.map_or_else(
+                || {
+                    let example = examples::find_example(&[], |input| {
+                        let value = input.parse::<T>().map_err(|_| ())?;
+                        validator(&value).map_err(|_| ())
+                    });
+                    examples::with_example("Please enter a valid value.", example.as_deref())
+                },
+                String::from,
+            )
+        })?;
+
+        validator(&value).map(|_| value)
+    })
+}
+
 /// # ARGUMENTS #
 /// 'msg' (Option<&str>) - an optional message which will be printed at
 /// the same line as the input prompt. Must be set to Some("...") or None.
@@ -350,6 +678,8 @@ pub fn read_string_untrimmed(msg: Option<&str>) -> String {
 /// # DESCRIPTION #
 /// Prompts the user to type a real number with single precision (f32) which will then be returned.
 /// Both '.' and ',' are accepted as separators for the decimal part (Ex: 12.3 and 45,67).
+/// Scientific notation (Ex: 1e-3, 6.02E23) is accepted as well; use read_f32_with_notation
+/// to reject it.
 /// If the user writes an invalid value, they will be prompted to try again.
 ///
 /// Provides an information message on the same line as the prompt if Some("...")
@@ -361,37 +691,88 @@ pub fn read_string_untrimmed(msg: Option<&str>) -> String {
 /// A floating point value of type f32 provided by the user.
 ///
 /// # EXAMPLES #
-/// ```
+/// ```no_run
 /// use quick_input::read_f32;
 /// let user_f32_with_msg = read_f32(Some("Please input a number with decimals: "), Some("Please input a valid number."));
 ///
 /// let user_f32: f32 = read_f32(None, None);
 /// ```
 pub fn read_f32(msg: Option<&str>, err_msg: Option<&str>) -> f32 {
-    let mut input = String::new();
-
-    if msg.is_some() {
-        while input.replace(',', ".").trim().parse::<f32>().is_err() {
-            input.clear();
-            print!("{}", msg.unwrap());
-            flush_and_read(&mut input);
+    read_until_valid(msg, err_msg, |input| {
+        parse_real(input, true, "Please enter a valid real number (32 bits).")
+    })
+}
 
-            if input.replace(',', ".").trim().parse::<f32>().is_err() {
-                show_error_message(err_msg, "Please enter a valid real number (32 bits).");
-            }
-        }
-    } else {
-        while input.trim().parse::<f32>().is_err() {
-            input.clear();
-            flush_and_read(&mut input);
-
-            if input.replace(',', ".").trim().parse::<f32>().is_err() {
-                show_error_message(err_msg, "Please enter a valid real number (32 bits).");
-            }
-        }
-    }
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as the input prompt. Must be set to Some("...") or None.
+///
+/// 'err_msg' (Option<&str>) - an optional error message which will be printed
+/// if the user inputs an invalid value. Must be set to Some("...") or None.
+///
+/// 'scientific' (bool) - whether scientific notation (Ex: 1e-3, 6.02E23) is accepted.
+///
+/// # DESCRIPTION #
+/// Prompts the user to type a real number with single precision (f32) which will then be returned,
+/// like read_f32, but choosing whether scientific notation is accepted. If it is not,
+/// a message asking for the plain decimal form is shown instead.
+/// If the user writes an invalid value, they will be prompted to try again.
+///
+/// Provides an information message on the same line as the prompt if Some("...")
+/// is provided, and just the prompt if None is provided.
+///
+/// If err_msg is set to None, a default message will be shown.
+///
+/// # RETURNS #
+/// A floating point value of type f32 provided by the user.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::read_f32_with_notation;
+/// let user_f32_with_msg = read_f32_with_notation(Some("Price: "), None, false);
+///
+/// let user_f32: f32 = read_f32_with_notation(None, None, true);
+/// ```
+pub fn read_f32_with_notation(msg: Option<&str>, err_msg: Option<&str>, scientific: bool) -> f32 {
+    read_until_valid(msg, err_msg, |input| {
+        parse_real(input, scientific, "Please enter a valid real number (32 bits).")
+    })
+}
 
-    input.replace(',', ".").trim().parse().unwrap()
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as the input prompt. Must be set to Some("...") or None.
+///
+/// 'err_msg' (Option<&str>) - an optional error message which will be printed
+/// if the user inputs an invalid value. Must be set to Some("...") or None.
+///
+/// 'scientific' (bool) - whether scientific notation (Ex: 1e-3, 6.02E23) is accepted.
+///
+/// # DESCRIPTION #
+/// Prompts the user to type a real number with double precision (f64) which will then be returned,
+/// like read_f64, but choosing whether scientific notation is accepted. If it is not,
+/// a message asking for the plain decimal form is shown instead.
+/// If the user writes an invalid value, they will be prompted to try again.
+///
+/// Provides an information message on the same line as the prompt if Some("...")
+/// is provided, and just the prompt if None is provided.
+///
+/// If err_msg is set to None, a default message will be shown.
+///
+/// # RETURNS #
+/// A floating point value of type f64 provided by the user.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::read_f64_with_notation;
+/// let user_f64_with_msg = read_f64_with_notation(Some("Price: "), None, false);
+///
+/// let user_f64: f64 = read_f64_with_notation(None, None, true);
+/// ```
+pub fn read_f64_with_notation(msg: Option<&str>, err_msg: Option<&str>, scientific: bool) -> f64 {
+    read_until_valid(msg, err_msg, |input| {
+        parse_real(input, scientific, "Please enter a valid real number (64 bits).")
+    })
 }
 
 /// # ARGUMENTS #
@@ -414,37 +795,17 @@ pub fn read_f32(msg: Option<&str>, err_msg: Option<&str>) -> f32 {
 /// An integer value of type i8 provided by the user.
 ///
 /// # EXAMPLES #
-/// ```
+/// ```no_run
 /// use quick_input::read_i8;
 /// let user_i8_with_msg = read_i8(Some("Please input a number: "),Some("Please input a valid number."));
 ///
 /// let user_i8: i8 = read_i8(None, None);
 /// ```
 pub fn read_i8(msg: Option<&str>, err_msg: Option<&str>) -> i8 {
-    let mut input = String::new();
-
-    if msg.is_some() {
-        while input.trim().parse::<i8>().is_err() {
-            input.clear();
-            print!("{}", msg.unwrap());
-            flush_and_read(&mut input);
-
-            if input.trim().parse::<i8>().is_err() {
-                show_error_message(err_msg, "Please enter a valid number (8 bits).");
-            }
-        }
-    } else {
-        while input.trim().parse::<i8>().is_err() {
-            input.clear();
-            flush_and_read(&mut input);
-
-            if input.trim().parse::<i8>().is_err() {
-                show_error_message(err_msg, "Please enter a valid number (8 bits).");
-            }
-        }
-    }
-
-    input.trim().parse().unwrap()
+    read_until_valid(msg, err_msg, |input| {
+        input.parse()
+            .map_err(|_| String::from("Please enter a valid number (8 bits)."))
+    })
 }
 
 /// # ARGUMENTS #
@@ -467,37 +828,17 @@ pub fn read_i8(msg: Option<&str>, err_msg: Option<&str>) -> i8 {
 /// An integer value of type u8 provided by the user.
 ///
 /// # EXAMPLES #
-/// ```
+/// ```no_run
 /// use quick_input::read_u8;
 /// let user_u8_with_msg = read_u8(Some("Please input a number: "), Some("Please input a valid number."));
 ///
 /// let user_u8: u8 = read_u8(None, None);
 /// ```
 pub fn read_u8(msg: Option<&str>, err_msg: Option<&str>) -> u8 {
-    let mut input = String::new();
-
-    if msg.is_some() {
-        while input.trim().parse::<u8>().is_err() {
-            input.clear();
-            print!("{}", msg.unwrap());
-            flush_and_read(&mut input);
-
-            if input.trim().parse::<u8>().is_err() {
-                show_error_message(err_msg, "Please enter a valid positive number (8 bits).");
-            }
-        }
-    } else {
-        while input.trim().parse::<u8>().is_err() {
-            input.clear();
-            flush_and_read(&mut input);
-
-            if input.trim().parse::<u8>().is_err() {
-                show_error_message(err_msg, "Please enter a valid positive number (8 bits).");
-            }
-        }
-    }
-
-    input.trim().parse().unwrap()
+    read_until_valid(msg, err_msg, |input| {
+        input.parse()
+            .map_err(|_| String::from("Please enter a valid positive number (8 bits)."))
+    })
 }
 
 /// # ARGUMENTS #
@@ -520,37 +861,17 @@ pub fn read_u8(msg: Option<&str>, err_msg: Option<&str>) -> u8 {
 /// An integer value of type i16 provided by the user.
 ///
 /// # EXAMPLES #
-/// ```
+/// ```no_run
 /// use quick_input::read_i16;
 /// let user_i16_with_msg = read_i16(Some("Please input a number: "), Some("Please input a valid number."));
 ///
 /// let user_i16: i16 = read_i16(None, None);
 /// ```
 pub fn read_i16(msg: Option<&str>, err_msg: Option<&str>) -> i16 {
-    let mut input = String::new();
-
-    if msg.is_some() {
-        while input.trim().parse::<i16>().is_err() {
-            input.clear();
-            print!("{}", msg.unwrap());
-            flush_and_read(&mut input);
-
-            if input.trim().parse::<i16>().is_err() {
-                show_error_message(err_msg, "Please enter a valid number (16 bits).");
-            }
-        }
-    } else {
-        while input.trim().parse::<i16>().is_err() {
-            input.clear();
-            flush_and_read(&mut input);
-
-            if input.trim().parse::<i16>().is_err() {
-                show_error_message(err_msg, "Please enter a valid number (16 bits).");
-            }
-        }
-    }
-
-    input.trim().parse().unwrap()
+    read_until_valid(msg, err_msg, |input| {
+        input.parse()
+            .map_err(|_| String::from("Please enter a valid number (16 bits)."))
+    })
 }
 
 /// # ARGUMENTS #
@@ -573,37 +894,17 @@ pub fn read_i16(msg: Option<&str>, err_msg: Option<&str>) -> i16 {
 /// An integer value of type u16 provided by the user.
 ///
 /// # EXAMPLES #
-/// ```
+/// ```no_run
 /// use quick_input::read_u16;
 /// let user_u16_with_msg = read_u16(Some("Please input a number: "), Some("Please input a valid number."));
 ///
 /// let user_u16: u16 = read_u16(None, None);
 /// ```
 pub fn read_u16(msg: Option<&str>, err_msg: Option<&str>) -> u16 {
-    let mut input = String::new();
-
-    if msg.is_some() {
-        while input.trim().parse::<u16>().is_err() {
-            input.clear();
-            print!("{}", msg.unwrap());
-            flush_and_read(&mut input);
-
-            if input.trim().parse::<u16>().is_err() {
-                show_error_message(err_msg, "Please enter a valid positive number (16 bits).");
-            }
-        }
-    } else {
-        while input.trim().parse::<u16>().is_err() {
-            input.clear();
-            flush_and_read(&mut input);
-
-            if input.trim().parse::<u16>().is_err() {
-                show_error_message(err_msg, "Please enter a valid positive number (16 bits).");
-            }
-        }
-    }
-
-    input.trim().parse().unwrap()
+    read_until_valid(msg, err_msg, |input| {
+        input.parse()
+            .map_err(|_| String::from("Please enter a valid positive number (16 bits)."))
+    })
 }
 
 /// # ARGUMENTS #
@@ -626,37 +927,17 @@ pub fn read_u16(msg: Option<&str>, err_msg: Option<&str>) -> u16 {
 /// An integer value of type i64 provided by the user.
 ///
 /// # EXAMPLES #
-/// ```
+/// ```no_run
 /// use quick_input::read_i64;
 /// let user_i64_with_msg = read_i64(Some("Please input a number: "), Some("Please input a valid number"));
 ///
 /// let user_i64: i64 = read_i64(None, None);
 /// ```
 pub fn read_i64(msg: Option<&str>, err_msg: Option<&str>) -> i64 {
-    let mut input = String::new();
-
-    if msg.is_some() {
-        while input.trim().parse::<i64>().is_err() {
-            input.clear();
-            print!("{}", msg.unwrap());
-            flush_and_read(&mut input);
-
-            if input.trim().parse::<i64>().is_err() {
-                show_error_message(err_msg, "Please enter a valid number (64 bits).");
-            }
-        }
-    } else {
-        while input.trim().parse::<i64>().is_err() {
-            input.clear();
-            flush_and_read(&mut input);
-
-            if input.trim().parse::<i64>().is_err() {
-                show_error_message(err_msg, "Please enter a valid number (64 bits).");
-            }
-        }
-    }
-
-    input.trim().parse().unwrap()
+    read_until_valid(msg, err_msg, |input| {
+        input.parse()
+            .map_err(|_| String::from("Please enter a valid number (64 bits)."))
+    })
 }
 
 /// # ARGUMENTS #
@@ -679,37 +960,17 @@ pub fn read_i64(msg: Option<&str>, err_msg: Option<&str>) -> i64 {
 /// An integer value of type u64 provided by the user.
 ///
 /// # EXAMPLES #
-/// ```
+/// ```no_run
 /// use quick_input::read_u64;
 /// let user_u64_with_msg = read_u64(Some("Please input a number: "), Some("Please input a valid number."));
 ///
 /// let user_u64: u64 = read_u64(None, None);
 /// ```
 pub fn read_u64(msg: Option<&str>, err_msg: Option<&str>) -> u64 {
-    let mut input = String::new();
-
-    if msg.is_some() {
-        while input.trim().parse::<u64>().is_err() {
-            input.clear();
-            print!("{}", msg.unwrap());
-            flush_and_read(&mut input);
-
-            if input.trim().parse::<u64>().is_err() {
-                show_error_message(err_msg, "Please enter a valid positive number (64 bits).");
-            }
-        }
-    } else {
-        while input.trim().parse::<u64>().is_err() {
-            input.clear();
-            flush_and_read(&mut input);
-
-            if input.trim().parse::<u64>().is_err() {
-                show_error_message(err_msg, "Please enter a valid positive number (64 bits).");
-            }
-        }
-    }
-
-    input.trim().parse().unwrap()
+    read_until_valid(msg, err_msg, |input| {
+        input.parse()
+            .map_err(|_| String::from("Please enter a valid positive number (64 bits)."))
+    })
 }
 
 /// # ARGUMENTS #
@@ -732,37 +993,17 @@ pub fn read_u64(msg: Option<&str>, err_msg: Option<&str>) -> u64 {
 /// An integer value of type i128 provided by the user.
 ///
 /// # EXAMPLES #
-/// ```
+/// ```no_run
 /// use quick_input::read_i128;
 /// let user_i128_with_msg = read_i128(Some("Please input a number: "), Some("Please input a valid number."));
 ///
 /// let user_i128: i128 = read_i128(None, None);
 /// ```
 pub fn read_i128(msg: Option<&str>, err_msg: Option<&str>) -> i128 {
-    let mut input = String::new();
-
-    if msg.is_some() {
-        while input.trim().parse::<i128>().is_err() {
-            input.clear();
-            print!("{}", msg.unwrap());
-            flush_and_read(&mut input);
-
-            if input.trim().parse::<i128>().is_err() {
-                show_error_message(err_msg, "Please enter a valid number (128 bits).");
-            }
-        }
-    } else {
-        while input.trim().parse::<i128>().is_err() {
-            input.clear();
-            flush_and_read(&mut input);
-
-            if input.trim().parse::<i128>().is_err() {
-                show_error_message(err_msg, "Please enter a valid number (128 bits).");
-            }
-        }
-    }
-
-    input.trim().parse().unwrap()
+    read_until_valid(msg, err_msg, |input| {
+        input.parse()
+            .map_err(|_| String::from("Please enter a valid number (128 bits)."))
+    })
 }
 
 /// # ARGUMENTS #
@@ -785,37 +1026,17 @@ pub fn read_i128(msg: Option<&str>, err_msg: Option<&str>) -> i128 {
 /// An integer value of type u128 provided by the user.
 ///
 /// # EXAMPLES #
-/// ```
+/// ```no_run
 /// use quick_input::read_u128;
 /// let user_u128_with_msg = read_u128(Some("Please input a number: "), Some("Please input a valid number."));
 ///
 /// let user_u128: u128 = read_u128(None, None);
 /// ```
 pub fn read_u128(msg: Option<&str>, err_msg: Option<&str>) -> u128 {
-    let mut input = String::new();
-
-    if msg.is_some() {
-        while input.trim().parse::<u128>().is_err() {
-            input.clear();
-            print!("{}", msg.unwrap());
-            flush_and_read(&mut input);
-
-            if input.trim().parse::<u128>().is_err() {
-                show_error_message(err_msg, "Please enter a valid positive number (128 bits).");
-            }
-        }
-    } else {
-        while input.trim().parse::<u128>().is_err() {
-            input.clear();
-            flush_and_read(&mut input);
-
-            if input.trim().parse::<u128>().is_err() {
-                show_error_message(err_msg, "Please enter a valid positive number (128 bits).");
-            }
-        }
-    }
-
-    input.trim().parse().unwrap()
+    read_until_valid(msg, err_msg, |input| {
+        input.parse()
+            .map_err(|_| String::from("Please enter a valid positive number (128 bits)."))
+    })
 }
 
 /// # ARGUMENTS #
@@ -838,37 +1059,17 @@ pub fn read_u128(msg: Option<&str>, err_msg: Option<&str>) -> u128 {
 /// An integer value of type isize provided by the user.
 ///
 /// # EXAMPLES #
-/// ```
+/// ```no_run
 /// use quick_input::read_isize;
 /// let user_isize_with_msg = read_isize(Some("Please input a number: "), Some("Please input a valid number"));
 ///
 /// let user_isize: isize = read_isize(None, None);
 /// ```
 pub fn read_isize(msg: Option<&str>, err_msg: Option<&str>) -> isize {
-    let mut input = String::new();
-
-    if msg.is_some() {
-        while input.trim().parse::<isize>().is_err() {
-            input.clear();
-            print!("{}", msg.unwrap());
-            flush_and_read(&mut input);
-
-            if input.trim().parse::<isize>().is_err() {
-                show_error_message(err_msg, "Please enter a valid number (32/64 bits).");
-            }
-        }
-    } else {
-        while input.trim().parse::<isize>().is_err() {
-            input.clear();
-            flush_and_read(&mut input);
-
-            if input.trim().parse::<isize>().is_err() {
-                show_error_message(err_msg, "Please enter a valid number (32/64 bits).");
-            }
-        }
-    }
-
-    input.trim().parse().unwrap()
+    read_until_valid(msg, err_msg, |input| {
+        input.parse()
+            .map_err(|_| String::from("Please enter a valid number (32/64 bits)."))
+    })
 }
 
 /// # ARGUMENTS #
@@ -891,43 +1092,26 @@ pub fn read_isize(msg: Option<&str>, err_msg: Option<&str>) -> isize {
 /// An integer value of type usize provided by the user.
 ///
 /// # EXAMPLES #
-/// ```
+/// ```no_run
 /// use quick_input::read_usize;
 /// let user_usize_with_msg = read_usize(Some("Please input a number: "), Some("Please input a valid number."));
 ///
 /// let user_usize: usize = read_usize(None, None);
 /// ```
 pub fn read_usize(msg: Option<&str>, err_msg: Option<&str>) -> usize {
-    let mut input = String::new();
-
-    if msg.is_some() {
-        while input.trim().parse::<usize>().is_err() {
-            input.clear();
-            print!("{}", msg.unwrap());
-            flush_and_read(&mut input);
-
-            if input.trim().parse::<usize>().is_err() {
-                show_error_message(err_msg, "Please enter a valid positive number (32/64 bits).");
-            }
-        }
-    } else {
-        while input.trim().parse::<usize>().is_err() {
-            input.clear();
-            flush_and_read(&mut input);
-
-            if input.trim().parse::<usize>().is_err() {
-                show_error_message(err_msg, "Please enter a valid positive number (32/64 bits).");
-            }
-        }
-    }
-
-    input.trim().parse().unwrap()
+    read_until_valid(msg, err_msg, |input| {
+        input.parse()
+            .map_err(|_| String::from("Please enter a valid positive number (32/64 bits)."))
+    })
 }
 
 
 // ----- PRIVATE METHODS ----- //
 
 /// # Arguments #
+/// 'msg' (&str) - Message of the prompt, used to explain which prompt was not answered
+/// if the input ends.
+///
 /// 'input' (&mut String) - Mutable reference to the variable containing
 /// an empty String, which is returned at the end of all read_* methods.
 ///
@@ -937,11 +1121,28 @@ pub fn read_usize(msg: Option<&str>, err_msg: Option<&str>) -> usize {
 ///
 /// This function also obtains the value typed by the user and assings it
 /// to the "input" variable through the mutable reference provided.
-fn flush_and_read(input: &mut String) {
-    io::stdout().flush().unwrap();
-    io::stdin()
-        .read_line(input)
-        .expect("Unable to read from stdin.");
+/// In dry-run mode, nothing is read, as if the user had pressed Enter.
+/// If the input ended, the policy set with set_eof_policy is applied.
+pub(crate) fn flush_and_read(msg: &str, input: &mut String) {
+    if !flush_and_try_read(input) {
+        eof::reached(msg);
+    }
+}
+
+/// # Arguments #
+/// 'input' (&mut String) - Mutable reference to the variable the line is appended to.
+///
+/// # Description #
+/// Private method working like flush_and_read, but returning false once the input ended
+/// instead of applying the EOF policy.
+pub(crate) fn flush_and_try_read(input: &mut String) -> bool {
+    if dry_run::is_dry_run() {
+        outln!();
+        return true;
+    }
+
+    reader::flush().unwrap();
+    input::read_line(input).expect("Unable to read from stdin.") > 0
 }
 
 /// # Arguments #
@@ -954,18 +1155,233 @@ fn flush_and_read(input: &mut String) {
 /// # Description #
 /// Private function used to display a custom error message if the users provides an invalid value.
 /// This function will display a default error message if the provided custom error message is set to None.
-fn show_error_message(err_msg: Option<&str>, def_err_msg: &str) {
-    if err_msg.is_some() {
-        println!("{}", err_msg.unwrap());
-        println!("---");
-    } else {
-        println!("{def_err_msg}");
-        println!("---");
+/// Afterwards, it waits for the delay set with set_retry_delay, if any.
+pub(crate) fn show_error_message(err_msg: Option<&str>, def_err_msg: &str) {
+    render::show_error(err_msg.unwrap_or(def_err_msg));
+
+    retry::wait_before_retry();
+}
+
+/// # Arguments #
+/// 'msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as the input prompt. Must be set to Some("...") or None.
+///
+/// 'err_msg' (Option<&str>) - Custom error message which will be displayed in case
+/// the user provides an invalid value. Must be set to Some("...") or None.
+///
+/// 'parse' (impl Fn(&str) -> Result<T, String>) - Closure receiving the trimmed input which
+/// returns either the accepted value or the default error message for that input.
+///
+/// # Description #
+/// Private function shared by the readers which need more validation than a plain parse().
+/// Prompts the user until 'parse' accepts the input, showing 'err_msg' (or the default error
+/// message returned by 'parse' if it is set to None) after every invalid attempt.
+/// Accepted answers go through the post-processors (see add_post_processor).
+/// In dry-run mode, the first placeholder accepted by 'parse' is returned instead.
+pub(crate) fn read_until_valid<T>(
+    msg: Option<&str>,
+    err_msg: Option<&str>,
+    parse: impl Fn(&str) -> Result<T, String>,
+) -> T {
+    read_until_valid_as(msg, err_msg, false, parse)
+}
+
+/// # Description #
+/// Private function working like read_until_valid for answers hidden while typed (the caller
+/// disables the echo), which are sensitive: they never go through the post-processors, and
+/// 'parse' receives them untrimmed.
+pub(crate) fn read_hidden_until_valid<T>(
+    msg: Option<&str>,
+    err_msg: Option<&str>,
+    parse: impl Fn(&str) -> Result<T, String>,
+) -> T {
+    read_until_valid_as(msg, err_msg, true, parse)
+}
+
+fn read_until_valid_as<T>(
+    msg: Option<&str>,
+    err_msg: Option<&str>,
+    hidden: bool,
+    parse: impl Fn(&str) -> Result<T, String>,
+) -> T {
+    if dry_run::is_dry_run() {
+        return dry_run::placeholder(msg, &[], parse);
+    }
+
+    loop {
+        let mut input = String::new();
+
+        render::show_prompt(msg.unwrap_or_default(), hidden, None);
+        flush_and_read(msg.unwrap_or_default(), &mut input);
+
+        let mut input = match hidden {
+            true => strip_line_end(&input).to_string(),
+            false => input.trim().to_string(),
+        };
+        let accepted = match hidden {
+            true => parse(&input),
+            false => postprocess::accept(msg.unwrap_or_default(), &mut input, &parse),
+        };
+        match accepted {
+            Ok(value) => return value,
+            Err(def_err_msg) => show_error_message(err_msg, &def_err_msg),
+        }
+    }
+}
+
+/// # Arguments #
+/// 'line' (&str) - Line read from the input.
+///
+/// # Description #
+/// Private function removing the line break ending 'line' (\n or \r\n), if any, but not
+/// the other whitespace, which is kept in hidden answers such as passwords.
+pub(crate) fn strip_line_end(line: &str) -> &str {
+    let line = line.strip_suffix('\n').unwrap_or(line);
+    line.strip_suffix('\r').unwrap_or(line)
+}
+
+/// # Arguments #
+/// 'input' (&str) - Trimmed input typed by the user.
+///
+/// 'scientific' (bool) - Whether scientific notation (Ex: 1e-3) is accepted.
+///
+/// 'def_err_msg' (&str) - Error message returned if the input is not a valid number.
+///
+/// # Description #
+/// Private function used by the float readers, which accept both '.' and ',' as decimal separators.
+pub(crate) fn parse_real<T: FromStr>(input: &str, scientific: bool, def_err_msg: &str) -> Result<T, String> {
+    if !scientific && input.contains(['e', 'E']) {
+        return Err(String::from(
+            "Please enter the number without scientific notation (Ex: 0.001 instead of 1e-3).",
+        ));
+    }
+
+    input
+        .replace(',', ".")
+        .parse()
+        .map_err(|_| def_err_msg.to_string())
+}
+
+/// # Arguments #
+/// 'input' (&str) - Trimmed input typed by the user.
+///
+/// 'any_nonzero' (bool) - Whether integers other than 0 and 1 are accepted as true.
+///
+/// # Description #
+/// Private function used by read_bool_int to parse true / false or their numeric form.
+fn parse_bool_int(input: &str, any_nonzero: bool) -> Result<bool, String> {
+    if let Ok(value) = input.to_lowercase().parse::<bool>() {
+        return Ok(value);
+    }
+
+    match input.parse::<i128>() {
+        Ok(0) => Ok(false),
+        Ok(1) => Ok(true),
+        Ok(_) if any_nonzero => Ok(true),
+        _ if any_nonzero => Err(String::from(
+            "Please enter true, false or an integer (0 is false, anything else is true).",
+        )),
+        _ => Err(String::from("Please enter true, false, 1 or 0.")),
+    }
+}
+
+/// # Arguments #
+/// 'input' (&str) - Trimmed input typed by the user.
+///
+/// # Description #
+/// Private function used by read_tristate to parse yes, no or skip.
+fn parse_tristate(input: &str) -> Result<Option<bool>, String> {
+    match input.to_lowercase().as_str() {
+        "y" | "yes" | "true" => Ok(Some(true)),
+        "n" | "no" | "false" => Ok(Some(false)),
+        "" | "s" | "skip" => Ok(None),
+        _ => Err(String::from("Please answer yes, no or skip (y / n / s).")),
+    }
+}
+
+/// # Arguments #
+/// 'msg' (&str) - Question printed at the same line as the input prompt.
+///
+/// # Description #
+/// Private function used to ask a yes / no question, looping until the user types
+/// 'y', 'yes', 'n' or 'no' (not case-sensitive).
+///
+/// These questions confirm a value the user just typed, so in dry-run mode they are
+/// answered with yes, letting the reader return it.
+pub(crate) fn ask_yes_no(msg: &str) -> bool {
+    if dry_run::is_dry_run() {
+        outln!("{msg}y");
+        return true;
+    }
+
+    read_until_valid(Some(msg), None, parse_yes_no)
+}
+
+/// # Arguments #
+/// 'input' (&str) - Trimmed input typed by the user.
+///
+/// # Description #
+/// Private function parsing 'y', 'yes', 'n' or 'no' (not case-sensitive).
+fn parse_yes_no(input: &str) -> Result<bool, String> {
+    match input.to_lowercase().as_str() {
+        "y" | "yes" => Ok(true),
+        "n" | "no" => Ok(false),
+        _ => Err(messages::lookup(
+            None,
+            |m| &m.yes_or_no,
+            "Please answer yes or no (y / n).",
+        )),
     }
 }
 
 
 #[cfg(test)]
 mod tests {
+    use super::*;
+    use crate::testing::MockInput;
+
+    #[test]
+    fn scientific_notation_can_be_rejected() {
+        let err = "Please enter a valid real number (64 bits).";
+        assert_eq!(parse_real::<f64>("1e-3", true, err), Ok(0.001));
+        assert_eq!(parse_real::<f64>("45,67", false, err), Ok(45.67));
+        assert!(parse_real::<f64>("1e-3", false, err).is_err());
+        assert!(parse_real::<f64>("-Infinity", false, err).is_ok());
+    }
+
+    #[test]
+    fn float_readers_accept_a_comma_as_decimal_separator() {
+        let mut mock = MockInput::new(["45,67", "1,5"]);
+
+        assert_eq!(mock.run(|| read_f64(Some("Price: "), None)), 45.67);
+        assert_eq!(mock.run(|| read_f32(None, None)), 1.5);
+        mock.assert_all_answered();
+    }
+
+    #[test]
+    fn bool_int_accepts_numeric_flags() {
+        assert_eq!(parse_bool_int("1", false), Ok(true));
+        assert_eq!(parse_bool_int("0", false), Ok(false));
+        assert_eq!(parse_bool_int("TRUE", false), Ok(true));
+        assert!(parse_bool_int("-3", false).is_err());
+        assert_eq!(parse_bool_int("-3", true), Ok(true));
+        assert!(parse_bool_int("yes", true).is_err());
+    }
 
+    #[test]
+    fn tristate_tells_skip_apart_from_no() {
+        assert_eq!(parse_tristate("YES"), Ok(Some(true)));
+        assert_eq!(parse_tristate("false"), Ok(Some(false)));
+        assert_eq!(parse_tristate(""), Ok(None));
+        assert_eq!(parse_tristate("skip"), Ok(None));
+        assert!(parse_tristate("maybe").is_err());
+    }
+
+    #[test]
+    fn yes_no_accepts_short_and_long_answers() {
+        assert_eq!(parse_yes_no("Y"), Ok(true));
+        assert_eq!(parse_yes_no("no"), Ok(false));
+        assert!(parse_yes_no("true").is_err());
+        assert!(parse_yes_no("").is_err());
+    }
 }
@@ -0,0 +1,129 @@
+//! Iterators reading the input lazily, one answer per line, until the input ends.
+
+use std::str::FromStr;
+
+use crate::reader::outln;
+use crate::{dry_run, flush_and_try_read, postprocess, render, show_error_message};
+
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as every input prompt. Must be set to Some("...") or None.
+///
+/// # DESCRIPTION #
+/// Returns an iterator which prompts the user for a line every time the next item is needed,
+/// so bulk input (Ex: a piped file) can be consumed with iterator adapters. Unlike the other
+/// readers, reaching the end of the input is not an error: it just ends the iterator.
+///
+/// Provides an information message on the same line as every prompt if Some("...")
+/// is provided, and just the prompt if None is provided.
+///
+/// In a dry run, the prompt is shown once and the iterator ends.
+///
+/// # RETURNS #
+/// An iterator over the lines typed by the user, without surrounding whitespace.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::lines;
+/// let words: usize = lines(None).map(|line| line.split_whitespace().count()).sum();
+///
+/// let names: Vec<String> = lines(Some("Name (empty line to finish): "))
+///     .take_while(|name| !name.is_empty())
+///     .collect();
+/// ```
+pub fn lines(msg: Option<&str>) -> impl Iterator<Item = String> {
+    std::iter::from_fn(move || next_answer(msg, None, |input| Ok(input.to_string())))
+}
+
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as every input prompt. Must be set to Some("...") or None.
+///
+/// 'err_msg' (Option<&str>) - an optional error message which will be printed
+/// if the user inputs an invalid value. Must be set to Some("...") or None.
+///
+/// # DESCRIPTION #
+/// Works like lines, but every line is parsed as T. In case the user writes an invalid
+/// value, they will be prompted to try again, so the iterator only yields valid values.
+///
+/// If err_msg is set to None, a default message will be shown.
+///
+/// # RETURNS #
+/// An iterator over the values typed by the user, one per line.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::values;
+/// let total: f64 = values::<f64>(Some("Amount: "), None).sum();
+///
+/// let first_even = values::<i64>(None, None).find(|n| n % 2 == 0);
+/// ```
+pub fn values<T: FromStr>(msg: Option<&str>, err_msg: Option<&str>) -> impl Iterator<Item = T> {
+    std::iter::from_fn(move || {
+        next_answer(msg, err_msg, |input| {
+            input
+                .parse()
+                .map_err(|_| String::from("Please enter a valid value."))
+        })
+    })
+}
+
+/// # Arguments #
+/// 'msg' (Option<&str>) - Optional message of the prompt.
+///
+/// 'err_msg' (Option<&str>) - Custom error message shown after an invalid answer.
+///
+/// 'parse' (impl Fn(&str) -> Result<T, String>) - Closure receiving the trimmed input which
+/// returns either the accepted value or the default error message for that input.
+///
+/// # Description #
+/// Private function working like read_until_valid, but returning None once the input ended
+/// (or in a dry run) instead of applying the EOF policy.
+fn next_answer<T>(
+    msg: Option<&str>,
+    err_msg: Option<&str>,
+    parse: impl Fn(&str) -> Result<T, String>,
+) -> Option<T> {
+    let msg = msg.unwrap_or_default();
+    if dry_run::is_dry_run() {
+        outln!("{msg}");
+        return None;
+    }
+
+    loop {
+        let mut input = String::new();
+
+        render::show_prompt(msg, false, None);
+        if !flush_and_try_read(&mut input) {
+            return None;
+        }
+
+        let mut input = input.trim().to_string();
+        match postprocess::accept(msg, &mut input, &parse) {
+            Ok(value) => return Some(value),
+            Err(def_err_msg) => show_error_message(err_msg, &def_err_msg),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Reader;
+    use std::io::Cursor;
+
+    #[test]
+    fn iterators_end_with_the_input() {
+        let mut reader = Reader::new(Cursor::new(" a \n\nb\n"), Vec::new());
+        let answers: Vec<String> = reader.run(|| lines(Some("> ")).collect());
+
+        assert_eq!(answers, ["a", "", "b"]);
+        assert_eq!(*reader.output(), b"> > > > ");
+
+        let mut reader = Reader::new(Cursor::new("1\nx\n2"), Vec::new());
+        let total: i32 = reader.run(|| values::<i32>(None, None).sum());
+
+        assert_eq!(total, 3);
+        assert!(String::from_utf8_lossy(&reader.output()).contains("Please enter a valid value."));
+    }
+}
@@ -0,0 +1,680 @@
+//! Menus whose options are picked by a short key, a number or a part of their name.
+
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::{dry_run, read_until_valid, tty};
+use crate::reader::{out, outln};
+
+/// Width used when the terminal width is unknown (Ex: output redirected to a file).
+const DEFAULT_WIDTH: usize = 80;
+
+/// Spaces between the columns of a multi-column menu.
+const COLUMN_GAP: usize = 3;
+
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as the input prompt. Must be set to Some("...") or None.
+///
+/// 'err_msg' (Option<&str>) - an optional error message which will be printed
+/// if the user inputs an invalid value. Must be set to Some("...") or None.
+///
+/// 'options' (&[(char, &str)]) - key and name of every option, in the order they are listed.
+///
+/// # DESCRIPTION #
+/// Lists the options on a single line with their key between brackets, inside the name when
+/// it contains it (Ex: [c]reate, e[x]it) and before it otherwise (Ex: [?] help), and then
+/// prompts the user to pick one by typing either its key or its full name (not case-sensitive).
+/// In case the user writes an invalid value, they will be prompted to try again.
+///
+/// Provides an information message on the same line as the prompt if Some("...")
+/// is provided, and just the prompt if None is provided.
+///
+/// If err_msg is set to None, a default message listing the valid keys will be shown.
+///
+/// # RETURNS #
+/// The key of the chosen option.
+///
+/// # PANICS #
+/// If 'options' is empty or two options share the same key.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::read_choice_map;
+/// match read_choice_map(Some("Action: "), None, &[('c', "create"), ('d', "delete"), ('q', "quit")]) {
+///     'c' => println!("Creating..."),
+///     'd' => println!("Deleting..."),
+///     _ => return,
+/// }
+/// ```
+pub fn read_choice_map(msg: Option<&str>, err_msg: Option<&str>, options: &[(char, &str)]) -> char {
+    read_menu(msg, err_msg, options, None)
+}
+
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as the input prompt. Must be set to Some("...") or None.
+///
+/// 'err_msg' (Option<&str>) - an optional error message which will be printed
+/// if the user inputs an invalid value. Must be set to Some("...") or None.
+///
+/// 'options' (&[(char, &str)]) - key and name of every option, in the order they are listed.
+///
+/// 'default' (char) - key of the option chosen when the user just presses Enter.
+///
+/// # DESCRIPTION #
+/// Works like read_choice_map, but the default option is marked with "(default)" in the list
+/// (Ex: [c]reate (default), [d]elete, [q]uit) and is chosen straight away if the user
+/// presses Enter without typing anything.
+///
+/// # RETURNS #
+/// The key of the chosen option, or 'default' if the input was empty.
+///
+/// # PANICS #
+/// If 'options' is empty, two options share the same key or 'default' is not one of the keys.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::read_choice_map_with_default;
+/// let action = read_choice_map_with_default(
+///     Some("File exists: "),
+///     None,
+///     &[('k', "keep"), ('o', "overwrite"), ('a', "abort")],
+///     'k',
+/// );
+/// ```
+pub fn read_choice_map_with_default(
+    msg: Option<&str>,
+    err_msg: Option<&str>,
+    options: &[(char, &str)],
+    default: char,
+) -> char {
+    assert!(
+        options.iter().any(|&(key, _)| key == default),
+        "The default key '{default}' is not one of the options."
+    );
+
+    read_menu(msg, err_msg, options, Some(default))
+}
+
+fn read_menu(
+    msg: Option<&str>,
+    err_msg: Option<&str>,
+    options: &[(char, &str)],
+    default: Option<char>,
+) -> char {
+    check_options(options);
+    outln!("{}", render_options(options, default));
+
+    let accept = |input: &str| match default {
+        Some(default) if input.is_empty() => Ok(default),
+        _ => parse_choice(input, options),
+    };
+
+    if dry_run::is_dry_run() {
+        let first = options[0].0.to_string();
+        return dry_run::placeholder(msg, &[first], accept);
+    }
+
+    read_until_valid(msg, err_msg, accept)
+}
+
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as the input prompt. Must be set to Some("...") or None.
+///
+/// 'err_msg' (Option<&str>) - an optional error message which will be printed
+/// if the user inputs an invalid value. Must be set to Some("...") or None.
+///
+/// 'items' (&[&str]) - items to choose from, in the order they are numbered.
+///
+/// # DESCRIPTION #
+/// Lists the numbered items in as many columns as fit the terminal, filling them top to bottom,
+/// and then prompts the user to pick one by typing either its number or a part of its name
+/// (not case-sensitive). A part matching several items is not accepted, and the matching
+/// items are listed so the user can refine it, unless it matches one of them exactly.
+/// In case the user writes an invalid value, they will be prompted to try again.
+///
+/// Provides an information message on the same line as the prompt if Some("...")
+/// is provided, and just the prompt if None is provided.
+///
+/// If err_msg is set to None, a default message explaining the problem will be shown.
+///
+/// # RETURNS #
+/// The index of the chosen item in 'items' (starting on 0).
+///
+/// # PANICS #
+/// If 'items' is empty.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::read_choice_multi_column_with_search;
+/// let fruits = ["Apple", "Apricot", "Banana", "Cherry", "Grape", "Mango", "Orange", "Pear"];
+/// let index = read_choice_multi_column_with_search(Some("Fruit (number or name): "), None, &fruits);
+///
+/// println!("You picked {}.", fruits[index]);
+/// ```
+pub fn read_choice_multi_column_with_search(
+    msg: Option<&str>,
+    err_msg: Option<&str>,
+    items: &[&str],
+) -> usize {
+    assert!(!items.is_empty(), "A menu needs at least one option.");
+
+    let width = tty::terminal_width().unwrap_or(DEFAULT_WIDTH);
+    out!("{}", render_columns(items, width));
+
+    read_until_valid(msg, err_msg, |input| search_item(input, items))
+}
+
+/// # ARGUMENTS #
+/// 'msg' (&str) - message which will be printed at the same line as the input prompt,
+/// after the menu. May be left empty to show just the prompt.
+///
+/// 'options' (&[&str]) - options to choose from, in the order they are numbered.
+///
+/// # DESCRIPTION #
+/// Prints a numbered menu with one option per line and prompts the user to pick one
+/// by typing its number. In case the user writes anything else or a number out of
+/// range, they will be prompted to try again.
+///
+/// # RETURNS #
+/// The index of the chosen option in 'options' (starting on 0).
+///
+/// # PANICS #
+/// If 'options' is empty.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::select;
+/// let actions = ["Add a task", "List the tasks", "Quit"];
+///
+/// match select("Choose an action: ", &actions) {
+///     0 => println!("Adding..."),
+///     1 => println!("Listing..."),
+///     _ => println!("Bye!"),
+/// }
+/// ```
+pub fn select(msg: &str, options: &[&str]) -> usize {
+    assert!(!options.is_empty(), "A menu needs at least one option.");
+
+    out!("{}", render_columns(options, 0));
+    read_until_valid(Some(msg), None, |input| parse_number(input, options.len()))
+}
+
+/// # ARGUMENTS #
+/// 'msg' (&str) - message which will be printed at the same line as the input prompt,
+/// after the menu. May be left empty to show just the prompt.
+///
+/// 'items' (&[T]) - items to choose from, which must implement Display.
+///
+/// # DESCRIPTION #
+/// Works like select, listing the items as they are displayed.
+///
+/// # RETURNS #
+/// A reference to the chosen item.
+///
+/// # PANICS #
+/// If 'items' is empty.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::select_value;
+/// let sizes = [32, 64, 128];
+/// let size = select_value("Disk size (GB): ", &sizes);
+/// ```
+pub fn select_value<'a, T: Display>(msg: &str, items: &'a [T]) -> &'a T {
+    let names: Vec<String> = items.iter().map(ToString::to_string).collect();
+    let names: Vec<&str> = names.iter().map(String::as_str).collect();
+
+    &items[select(msg, &names)]
+}
+
+/// # ARGUMENTS #
+/// 'msg' (&str) - message which will be printed at the same line as the input prompt,
+/// after the menu. May be left empty to show just the prompt.
+///
+/// 'options' (&[&str]) - options to choose from, in the order they are numbered.
+///
+/// # DESCRIPTION #
+/// Prints a numbered menu like select does, and prompts the user to pick any number of
+/// options by typing their numbers separated by commas (Ex: 1,3,5). Numbers typed more than
+/// once count once, and an empty line picks none. In case any of the numbers is invalid,
+/// the user will be prompted to try again.
+///
+/// # RETURNS #
+/// A Vec<usize> with the indexes of the chosen options (starting on 0), in ascending order.
+///
+/// # PANICS #
+/// If 'options' is empty.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::multi_select;
+/// let features = ["Desktop shortcut", "Start on login", "Automatic updates"];
+///
+/// for index in multi_select("Features to enable (Ex: 1,3): ", &features) {
+///     println!("Enabling {}...", features[index]);
+/// }
+/// ```
+pub fn multi_select(msg: &str, options: &[&str]) -> Vec<usize> {
+    assert!(!options.is_empty(), "A menu needs at least one option.");
+
+    out!("{}", render_columns(options, 0));
+    read_until_valid(Some(msg), None, |input| parse_numbers(input, options.len()))
+}
+
+/// Answer given to read_existing_choice_or_new.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChoiceOrNew<T> {
+    /// Index of the existing option chosen (starting on 0).
+    Chosen(usize),
+    /// New value typed by the user.
+    New(T),
+}
+
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as the input prompt. Must be set to Some("...") or None.
+///
+/// 'err_msg' (Option<&str>) - an optional error message which will be printed
+/// if the user inputs an invalid value. Must be set to Some("...") or None.
+///
+/// 'options' (&[&str]) - existing options, in the order they are numbered. May be empty.
+///
+/// 'validator' (impl Fn(&T) -> Result<(), String>) - closure checking a new value, which
+/// returns the error message to show if it is not acceptable.
+///
+/// # DESCRIPTION #
+/// Lists the numbered options and then prompts the user to either pick one, by typing its
+/// number or its full name (not case-sensitive), or type a new value, which must be parsed
+/// as T and accepted by the validator. This suits "pick a profile or create one" flows.
+/// Numbers always refer to options, so new values should not be plain numbers.
+/// In case the user writes an invalid value, they will be prompted to try again.
+///
+/// Provides an information message on the same line as the prompt if Some("...")
+/// is provided, and just the prompt if None is provided.
+///
+/// If err_msg is set to None, the message returned by the validator (or a default message
+/// explaining the problem) will be shown.
+///
+/// # RETURNS #
+/// ChoiceOrNew::Chosen with the index of the chosen option, or ChoiceOrNew::New with the
+/// new value.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::{ChoiceOrNew, read_existing_choice_or_new};
+/// let profiles = ["work", "personal"];
+/// let answer = read_existing_choice_or_new(
+///     Some("Profile (number, name or a new name): "),
+///     None,
+///     &profiles,
+///     |name: &String| match name.contains(' ') {
+///         true => Err(String::from("Profile names cannot contain spaces.")),
+///         false => Ok(()),
+///     },
+/// );
+///
+/// match answer {
+///     ChoiceOrNew::Chosen(index) => println!("Using {}.", profiles[index]),
+///     ChoiceOrNew::New(name) => println!("Creating {name}."),
+/// }
+/// ```
+pub fn read_existing_choice_or_new<T: FromStr>(
+    msg: Option<&str>,
+    err_msg: Option<&str>,
+    options: &[&str],
+    validator: impl Fn(&T) -> Result<(), String>,
+) -> ChoiceOrNew<T> {
+    if !options.is_empty() {
+        let width = tty::terminal_width().unwrap_or(DEFAULT_WIDTH);
+        out!("{}", render_columns(options, width));
+    }
+
+    read_until_valid(msg, err_msg, |input| {
+        parse_choice_or_new(input, options, &validator)
+    })
+}
+
+/// # Arguments #
+/// 'input' (&str) - Trimmed input typed by the user.
+///
+/// 'options' (&[&str]) - Existing options.
+///
+/// 'validator' (&impl Fn(&T) -> Result<(), String>) - Closure checking new values.
+///
+/// # Description #
+/// Private function returning the option chosen by its number or name, or else the input
+/// parsed as a new value.
+fn parse_choice_or_new<T: FromStr>(
+    input: &str,
+    options: &[&str],
+    validator: &impl Fn(&T) -> Result<(), String>,
+) -> Result<ChoiceOrNew<T>, String> {
+    if input.is_empty() {
+        return Err(String::from(
+            "Please enter the number or name of an option, or a new value.",
+        ));
+    }
+
+    if let Ok(number) = input.parse::<usize>() {
+        return match number.checked_sub(1) {
+            Some(index) if index < options.len() => Ok(ChoiceOrNew::Chosen(index)),
+            _ if options.is_empty() => Err(String::from("There are no options to choose from.")),
+            _ => Err(format!(
+                "Please enter a number between 1 and {}.",
+                options.len()
+            )),
+        };
+    }
+
+    if let Some(index) = options
+        .iter()
+        .position(|option| option.eq_ignore_ascii_case(input))
+    {
+        return Ok(ChoiceOrNew::Chosen(index));
+    }
+
+    let value = input
+        .parse()
+        .map_err(|_| format!("'{input}' is neither an option nor a valid new value."))?;
+    validator(&value).map(|_| ChoiceOrNew::New(value))
+}
+
+/// # Arguments #
+/// 'options' (&[(char, &str)]) - Key and name of every option.
+///
+/// # Description #
+/// Private function making sure the options can be told apart, since an ambiguous
+/// menu is a programming error rather than something the user can fix.
+fn check_options(options: &[(char, &str)]) {
+    assert!(!options.is_empty(), "A menu needs at least one option.");
+
+    for (index, (key, _)) in options.iter().enumerate() {
+        let repeated = options[..index]
+            .iter()
+            .any(|(other, _)| other.to_lowercase().eq(key.to_lowercase()));
+        assert!(
+            !repeated,
+            "The key '{key}' is used by more than one option."
+        );
+    }
+}
+
+/// # Arguments #
+/// 'options' (&[(char, &str)]) - Key and name of every option.
+///
+/// 'default' (Option<char>) - Key of the default option, if there is one.
+///
+/// # Description #
+/// Private function building the line listing the options (Ex: [c]reate, [d]elete, [q]uit).
+fn render_options(options: &[(char, &str)], default: Option<char>) -> String {
+    options
+        .iter()
+        .map(|&(key, name)| match default {
+            Some(default) if default == key => format!("{} (default)", label(key, name)),
+            _ => label(key, name),
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn label(key: char, name: &str) -> String {
+    let position = name
+        .char_indices()
+        .find(|(_, c)| c.to_lowercase().eq(key.to_lowercase()));
+
+    match position {
+        Some((index, c)) => {
+            let after = index + c.len_utf8();
+            format!("{}[{c}]{}", &name[..index], &name[after..])
+        }
+        None => format!("[{key}] {name}"),
+    }
+}
+
+/// # Arguments #
+/// 'input' (&str) - Trimmed input typed by the user.
+///
+/// 'options' (&[(char, &str)]) - Key and name of every option.
+///
+/// # Description #
+/// Private function returning the key of the option matching the input, which
+/// can be either the key itself or the full name of the option.
+fn parse_choice(input: &str, options: &[(char, &str)]) -> Result<char, String> {
+    let mut chars = input.chars();
+    let key = match (chars.next(), chars.next()) {
+        (Some(key), None) => Some(key),
+        _ => None,
+    };
+
+    options
+        .iter()
+        .find(|(option, name)| {
+            key.is_some_and(|key| key.to_lowercase().eq(option.to_lowercase()))
+                || name.eq_ignore_ascii_case(input)
+        })
+        .map(|&(key, _)| key)
+        .ok_or_else(|| {
+            let keys: Vec<String> = options.iter().map(|(key, _)| key.to_string()).collect();
+            format!(
+                "Please enter one of the keys ({}) or an option name.",
+                keys.join(", ")
+            )
+        })
+}
+
+/// # Arguments #
+/// 'items' (&[&str]) - Items of the menu.
+///
+/// 'width' (usize) - Maximum number of characters of every line.
+///
+/// # Description #
+/// Private function laying out the numbered items in columns, filled top to bottom.
+fn render_columns(items: &[&str], width: usize) -> String {
+    let number_width = items.len().to_string().len();
+    let entries: Vec<String> = items
+        .iter()
+        .enumerate()
+        .map(|(index, item)| format!("{:>number_width$}. {item}", index + 1))
+        .collect();
+
+    let entry_width = entries
+        .iter()
+        .map(|entry| entry.chars().count())
+        .max()
+        .unwrap_or(0);
+    let columns = ((width + COLUMN_GAP) / (entry_width + COLUMN_GAP)).clamp(1, entries.len());
+    let rows = entries.len().div_ceil(columns);
+
+    let mut rendered = String::new();
+    for row in 0..rows {
+        let line: Vec<String> = entries
+            .iter()
+            .skip(row)
+            .step_by(rows)
+            .map(|entry| format!("{entry:<entry_width$}"))
+            .collect();
+        rendered.push_str(line.join(&" ".repeat(COLUMN_GAP)).trim_end());
+        rendered.push('\n');
+    }
+
+    rendered
+}
+
+/// # Arguments #
+/// 'input' (&str) - Trimmed input typed by the user.
+///
+/// 'count' (usize) - Number of options of the menu.
+///
+/// # Description #
+/// Private function returning the index of the option chosen by its number (starting on 1).
+fn parse_number(input: &str, count: usize) -> Result<usize, String> {
+    match input.parse::<usize>().ok().and_then(|number| number.checked_sub(1)) {
+        Some(index) if index < count => Ok(index),
+        _ => Err(format!("Please enter a number between 1 and {count}.")),
+    }
+}
+
+/// # Arguments #
+/// 'input' (&str) - Trimmed input typed by the user.
+///
+/// 'count' (usize) - Number of options of the menu.
+///
+/// # Description #
+/// Private function returning the sorted indexes of the options chosen by their
+/// comma-separated numbers, without repetitions.
+fn parse_numbers(input: &str, count: usize) -> Result<Vec<usize>, String> {
+    let mut indexes = input
+        .split(',')
+        .map(str::trim)
+        .filter(|number| !number.is_empty())
+        .map(|number| {
+            parse_number(number, count).map_err(|err| format!("'{number}' is not valid. {err}"))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    indexes.sort_unstable();
+    indexes.dedup();
+    Ok(indexes)
+}
+
+/// # Arguments #
+/// 'input' (&str) - Trimmed input typed by the user.
+///
+/// 'items' (&[&str]) - Items of the menu.
+///
+/// # Description #
+/// Private function returning the index of the item chosen by its number, by its full name
+/// or by a part of its name which no other item contains.
+fn search_item(input: &str, items: &[&str]) -> Result<usize, String> {
+    if input.is_empty() {
+        return Err(String::from(
+            "Please enter a number or a part of an item name.",
+        ));
+    }
+
+    if let Ok(number) = input.parse::<usize>() {
+        return match number.checked_sub(1) {
+            Some(index) if index < items.len() => Ok(index),
+            _ => Err(format!(
+                "Please enter a number between 1 and {}.",
+                items.len()
+            )),
+        };
+    }
+
+    if let Some(index) = items
+        .iter()
+        .position(|item| item.eq_ignore_ascii_case(input))
+    {
+        return Ok(index);
+    }
+
+    let needle = input.to_lowercase();
+    let matches: Vec<usize> = (0..items.len())
+        .filter(|&index| items[index].to_lowercase().contains(&needle))
+        .collect();
+
+    match matches[..] {
+        [index] => Ok(index),
+        [] => Err(format!("No item contains '{input}'.")),
+        _ => {
+            let candidates: Vec<String> = matches
+                .iter()
+                .map(|&index| format!("{}. {}", index + 1, items[index]))
+                .collect();
+            Err(format!(
+                "'{input}' matches several items: {}.",
+                candidates.join(", ")
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ACTIONS: &[(char, &str)] = &[('c', "create"), ('x', "exit"), ('?', "help")];
+
+    #[test]
+    fn keys_are_shown_inside_names() {
+        assert_eq!(render_options(ACTIONS, None), "[c]reate, e[x]it, [?] help");
+        assert_eq!(
+            render_options(ACTIONS, Some('x')),
+            "[c]reate, e[x]it (default), [?] help"
+        );
+    }
+
+    #[test]
+    fn items_are_laid_out_in_columns() {
+        let items = ["Apple", "Banana", "Cherry", "Grape", "Mango"];
+        assert_eq!(
+            render_columns(&items, 30),
+            "1. Apple    4. Grape\n2. Banana   5. Mango\n3. Cherry\n"
+        );
+    }
+
+    #[test]
+    fn items_are_found_by_number_or_name() {
+        let items = ["Apple", "Apricot", "Pineapple", "Banana"];
+        assert_eq!(search_item("4", &items), Ok(3));
+        assert_eq!(search_item("cot", &items), Ok(1));
+        assert_eq!(search_item("apple", &items), Ok(0));
+        assert_eq!(
+            search_item("ap", &items).unwrap_err(),
+            "'ap' matches several items: 1. Apple, 2. Apricot, 3. Pineapple."
+        );
+        assert!(search_item("5", &items).is_err());
+    }
+
+    #[test]
+    fn menu_numbers_start_on_one() {
+        assert_eq!(parse_number("1", 3), Ok(0));
+        assert_eq!(parse_number("3", 3), Ok(2));
+        assert!(parse_number("0", 3).is_err());
+        assert!(parse_number("4", 3).is_err());
+        assert!(parse_number("two", 3).is_err());
+    }
+
+    #[test]
+    fn multi_select_dedups_and_sorts() {
+        assert_eq!(parse_numbers("3, 1,3", 3), Ok(vec![0, 2]));
+        assert_eq!(parse_numbers("", 3), Ok(vec![]));
+        assert_eq!(
+            parse_numbers("1,5", 3),
+            Err(String::from("'5' is not valid. Please enter a number between 1 and 3."))
+        );
+    }
+
+    #[test]
+    fn options_are_chosen_or_created() {
+        let profiles = ["work", "personal"];
+        let no_spaces = |name: &String| match name.contains(' ') {
+            true => Err(String::from("No spaces.")),
+            false => Ok(()),
+        };
+
+        assert_eq!(parse_choice_or_new("2", &profiles, &no_spaces), Ok(ChoiceOrNew::Chosen(1)));
+        assert_eq!(parse_choice_or_new("WORK", &profiles, &no_spaces), Ok(ChoiceOrNew::Chosen(0)));
+        assert_eq!(
+            parse_choice_or_new("gaming", &profiles, &no_spaces),
+            Ok(ChoiceOrNew::New(String::from("gaming")))
+        );
+        assert_eq!(
+            parse_choice_or_new("my games", &profiles, &no_spaces),
+            Err(String::from("No spaces."))
+        );
+        assert!(parse_choice_or_new("3", &profiles, &no_spaces).is_err());
+    }
+
+    #[test]
+    fn keys_and_names_are_accepted() {
+        assert_eq!(parse_choice("C", ACTIONS), Ok('c'));
+        assert_eq!(parse_choice("Exit", ACTIONS), Ok('x'));
+        assert_eq!(parse_choice("?", ACTIONS), Ok('?'));
+        assert!(parse_choice("cr", ACTIONS).is_err());
+    }
+}
@@ -0,0 +1,104 @@
+//! Catalog of the built-in messages, which can be translated globally or for a single Prompt.
+
+use std::sync::RwLock;
+
+/// Messages set with set_messages, used by every prompt which does not override them.
+static GLOBAL_MESSAGES: RwLock<Messages> = RwLock::new(Messages::EMPTY);
+
+/// Translations of the messages printed by the library itself. Every message left as None
+/// falls back to the global catalog (see set_messages), and then to the English default.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::Messages;
+/// let spanish = Messages {
+///     invalid_value: Some(String::from("Por favor, introduzca un valor válido.")),
+///     did_you_mean: Some(String::from("¿Quería decir '{value}'? [Y/n]: ")),
+///     ..Messages::default()
+/// };
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Messages {
+    /// Shown when the answer cannot be parsed ("Please enter a valid value.").
+    pub invalid_value: Option<String>,
+    /// Question asked before applying a suggested correction, where "{value}" is replaced by
+    /// the correction ("Did you mean '{value}'? [Y/n]: "). It is still answered with y or n.
+    pub did_you_mean: Option<String>,
+    /// Shown when a yes / no question gets another answer ("Please answer yes or no (y / n).").
+    pub yes_or_no: Option<String>,
+}
+
+impl Messages {
+    const EMPTY: Messages = Messages {
+        invalid_value: None,
+        did_you_mean: None,
+        yes_or_no: None,
+    };
+}
+
+/// # ARGUMENTS #
+/// 'messages' (Messages) - translations used by every prompt, unless a Prompt overrides them
+/// with Prompt::messages. Messages left as None are shown in English.
+///
+/// # DESCRIPTION #
+/// Replaces the global catalog of built-in messages.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::{set_messages, Messages};
+/// set_messages(Messages {
+///     yes_or_no: Some(String::from("Bitte mit ja oder nein antworten (y / n).")),
+///     ..Messages::default()
+/// });
+/// ```
+pub fn set_messages(messages: Messages) {
+    *GLOBAL_MESSAGES
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner()) = messages;
+}
+
+/// # Arguments #
+/// 'local' (Option<&Messages>) - Catalog of the Prompt asking, if it overrides the global one.
+///
+/// 'field' (fn(&Messages) -> &Option<String>) - Message to look up.
+///
+/// 'default' (&str) - English message used when no catalog has a translation.
+///
+/// # Description #
+/// Function shared by the readers to find the translation of a built-in message.
+pub(crate) fn lookup(
+    local: Option<&Messages>,
+    field: fn(&Messages) -> &Option<String>,
+    default: &str,
+) -> String {
+    if let Some(message) = local.and_then(|local| field(local).clone()) {
+        return message;
+    }
+
+    let global = GLOBAL_MESSAGES
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    field(&global).clone().unwrap_or_else(|| default.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn local_messages_fall_back_to_the_default() {
+        let local = Messages {
+            invalid_value: Some(String::from("Valor no válido.")),
+            ..Messages::default()
+        };
+
+        assert_eq!(
+            lookup(Some(&local), |m| &m.invalid_value, "Invalid."),
+            "Valor no válido."
+        );
+        assert_eq!(
+            lookup(Some(&local), |m| &m.did_you_mean, "Did you mean?"),
+            "Did you mean?"
+        );
+    }
+}
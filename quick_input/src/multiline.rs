@@ -0,0 +1,181 @@
+//! Readers for text spanning several lines, such as descriptions or pasted keys.
+
+use crate::{dry_run, flush_and_try_read, show_error_message};
+use crate::reader::outln;
+
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed on its own line
+/// before the text is typed. Must be set to Some("...") or None.
+///
+/// 'err_msg' (Option<&str>) - an optional error message which will be printed
+/// if the text is too long. Must be set to Some("...") or None.
+///
+/// 'max_words' (Option<usize>) - maximum number of words of the text. Must be set to
+/// Some(...) or None.
+///
+/// 'max_lines' (Option<usize>) - maximum number of lines of the text. Must be set to
+/// Some(...) or None.
+///
+/// # DESCRIPTION #
+/// Prompts the user to type a text of one or more lines, which ends with an empty line.
+/// Trailing spaces are removed from every line. If the text has more words or lines than
+/// allowed, the error message reports how many were typed and how many are allowed, and the
+/// user is prompted to type the whole text again. This suits texts which end up somewhere
+/// with a limited size (Ex: a commit message or a form field).
+///
+/// If err_msg is set to None, a default message with the counts will be shown.
+///
+/// # RETURNS #
+/// A String with the lines typed by the user, separated by '\n'.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::read_multiline;
+/// let description = read_multiline(Some("Description (end with an empty line):"), None, Some(200), None);
+///
+/// let address = read_multiline(Some("Postal address:"), None, None, Some(4));
+/// ```
+pub fn read_multiline(
+    msg: Option<&str>,
+    err_msg: Option<&str>,
+    max_words: Option<usize>,
+    max_lines: Option<usize>,
+) -> String {
+    loop {
+        if let Some(msg) = msg {
+            outln!("{msg}");
+        }
+        if dry_run::is_dry_run() {
+            outln!();
+            return String::new();
+        }
+
+        let text = read_lines(str::is_empty);
+        let checked = max_words
+            .map_or(Ok(()), |max| check_words(&text, max))
+            .and_then(|_| max_lines.map_or(Ok(()), |max| check_lines(&text, max)));
+
+        match checked {
+            Ok(()) => return text,
+            Err(def_err_msg) => show_error_message(err_msg, &def_err_msg),
+        }
+    }
+}
+
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed on its own line
+/// before the text is typed. Must be set to Some("...") or None.
+///
+/// 'terminator' (Option<&str>) - line which ends the text (Ex: "."), compared without the
+/// surrounding spaces. Must be set to Some("...") or None to read until the input ends.
+///
+/// # DESCRIPTION #
+/// Prompts the user to type a block of text, which ends with the terminator line or once the
+/// input ends (Ex: Ctrl+D, or the end of a piped file). Unlike read_multiline, empty lines are
+/// kept, so pasted texts such as keys, certificates or commit messages are read whole.
+/// Trailing spaces are removed from every line.
+///
+/// # RETURNS #
+/// A String with the lines typed by the user before the terminator, separated by '\n'.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::read_multiline_until;
+/// let key = read_multiline_until(Some("Paste the public key, then Ctrl+D:"), None);
+///
+/// let message = read_multiline_until(Some("Message (end with a lone '.'):"), Some("."));
+/// ```
+pub fn read_multiline_until(msg: Option<&str>, terminator: Option<&str>) -> String {
+    if let Some(msg) = msg {
+        outln!("{msg}");
+    }
+    if dry_run::is_dry_run() {
+        outln!("{}", terminator.unwrap_or_default());
+        return String::new();
+    }
+
+    read_lines(|line| terminator.is_some_and(|terminator| line.trim() == terminator.trim()))
+}
+
+/// # Arguments #
+/// 'text' (&str) - Text typed by the user.
+///
+/// 'max' (usize) - Maximum number of words.
+///
+/// # Description #
+/// Function shared with Prompt::max_words, rejecting texts with more than 'max' words.
+/// Words are separated by whitespace.
+pub(crate) fn check_words(text: &str, max: usize) -> Result<(), String> {
+    let words = text.split_whitespace().count();
+    match words > max {
+        true => Err(format!(
+            "The text has {}, but the limit is {max}.",
+            describe_count(words, "word")
+        )),
+        false => Ok(()),
+    }
+}
+
+fn check_lines(text: &str, max: usize) -> Result<(), String> {
+    let lines = text.lines().count();
+    match lines > max {
+        true => Err(format!(
+            "The text has {}, but the limit is {max}.",
+            describe_count(lines, "line")
+        )),
+        false => Ok(()),
+    }
+}
+
+/// Describes a count with its unit (Ex: "1 word" or "3 words").
+fn describe_count(count: usize, unit: &str) -> String {
+    match count {
+        1 => format!("1 {unit}"),
+        n => format!("{n} {unit}s"),
+    }
+}
+
+/// Reads lines until the input ends or 'is_last' returns true for a line, which is left out.
+pub(crate) fn read_lines(is_last: impl Fn(&str) -> bool) -> String {
+    let mut lines = Vec::new();
+    loop {
+        let mut line = String::new();
+        let ended = !flush_and_try_read(&mut line);
+
+        let line = line.trim_end();
+        if ended || is_last(line) {
+            return lines.join("\n");
+        }
+        lines.push(line.to_string());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::read_string;
+    use crate::testing::MockInput;
+
+    #[test]
+    fn counts_are_reported() {
+        assert_eq!(check_words("Fix  the\nbuild", 3), Ok(()));
+        assert_eq!(
+            check_words("Fix the build", 2),
+            Err(String::from("The text has 3 words, but the limit is 2."))
+        );
+        assert_eq!(check_lines("one\ntwo", 2), Ok(()));
+        assert_eq!(
+            check_lines("one\ntwo", 1),
+            Err(String::from("The text has 2 lines, but the limit is 1."))
+        );
+    }
+
+    #[test]
+    fn blocks_end_with_the_terminator() {
+        let mut mock = MockInput::new(["line 1", "", "  line 3  ", " . ", "next"]);
+        let text = mock.run(|| read_multiline_until(Some("Text:"), Some(".")));
+
+        assert_eq!(text, "line 1\n\n  line 3");
+        assert_eq!(mock.run(|| read_string(None)), "next");
+    }
+}
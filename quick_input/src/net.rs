@@ -0,0 +1,262 @@
+//! Readers for network related values.
+
+use std::net::{Ipv4Addr, Ipv6Addr, SocketAddr, ToSocketAddrs};
+
+use crate::{ask_yes_no, read_until_valid};
+use crate::reader::outln;
+
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as the input prompt. Must be set to Some("...") or None.
+///
+/// 'err_msg' (Option<&str>) - an optional error message which will be printed
+/// if the user inputs an invalid value. Must be set to Some("...") or None.
+///
+/// # DESCRIPTION #
+/// Prompts the user to type a network port (1 - 65535) which will then be returned.
+/// Port 0 is rejected, since it cannot be used to reach a service.
+/// In case the user writes an invalid value, they will be prompted to try again.
+///
+/// Provides an information message on the same line as the prompt if Some("...")
+/// is provided, and just the prompt if None is provided.
+///
+/// If err_msg is set to None, a default message will be shown.
+///
+/// # RETURNS #
+/// A port number (u16) provided by the user.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::read_port;
+/// let user_port_with_msg = read_port(Some("Port: "), Some("Please input a valid port."));
+///
+/// let user_port: u16 = read_port(None, None);
+/// ```
+pub fn read_port(msg: Option<&str>, err_msg: Option<&str>) -> u16 {
+    read_until_valid(msg, err_msg, parse_port)
+}
+
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as the input prompt. Must be set to Some("...") or None.
+///
+/// 'err_msg' (Option<&str>) - an optional error message which will be printed
+/// if the user inputs an invalid value. Must be set to Some("...") or None.
+///
+/// # DESCRIPTION #
+/// Works like read_port, but if the user chooses a well-known port (below 1024) while the
+/// program is not running as root, a warning is shown and the user is asked to confirm it,
+/// since binding to that port will most likely fail. If the user does not confirm it,
+/// they will be prompted to type another port.
+///
+/// Well-known ports are only restricted on Unix systems, so no warning is shown elsewhere.
+///
+/// # RETURNS #
+/// A port number (u16) provided by the user.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::read_port_with_warning;
+/// let user_port = read_port_with_warning(Some("Port to listen on: "), None);
+/// ```
+pub fn read_port_with_warning(msg: Option<&str>, err_msg: Option<&str>) -> u16 {
+    loop {
+        let port = read_port(msg, err_msg);
+
+        if port >= 1024 || is_privileged() {
+            return port;
+        }
+
+        outln!("Warning: ports below 1024 usually require root privileges.");
+        if ask_yes_no(&format!("Use port {port} anyway? (y / n): ")) {
+            return port;
+        }
+    }
+}
+
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as the input prompt. Must be set to Some("...") or None.
+///
+/// 'err_msg' (Option<&str>) - an optional error message which will be printed
+/// if the user inputs an invalid value. Must be set to Some("...") or None.
+///
+/// # DESCRIPTION #
+/// Prompts the user to type an endpoint with the format host:port, which will then be returned.
+/// The host can be a hostname (Ex: example.com:443), an IPv4 address (Ex: 127.0.0.1:8080)
+/// or an IPv6 address written between brackets (Ex: [::1]:8080).
+/// In case the user writes an invalid value, they will be prompted to try again.
+///
+/// Provides an information message on the same line as the prompt if Some("...")
+/// is provided, and just the prompt if None is provided.
+///
+/// If err_msg is set to None, a default message pointing out which part of the endpoint
+/// (host or port) is wrong will be shown.
+///
+/// # RETURNS #
+/// A tuple (String, u16) with the host (without brackets) and the port provided by the user.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::read_endpoint;
+/// let (host, port) = read_endpoint(Some("Server (host:port): "), Some("Please input a valid endpoint."));
+///
+/// let user_endpoint: (String, u16) = read_endpoint(None, None);
+/// ```
+pub fn read_endpoint(msg: Option<&str>, err_msg: Option<&str>) -> (String, u16) {
+    read_until_valid(msg, err_msg, parse_endpoint)
+}
+
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as the input prompt. Must be set to Some("...") or None.
+///
+/// 'err_msg' (Option<&str>) - an optional error message which will be printed
+/// if the user inputs an invalid value. Must be set to Some("...") or None.
+///
+/// # DESCRIPTION #
+/// Works like read_endpoint, but the host is also resolved (which may perform a DNS lookup).
+/// In case the host cannot be resolved, the user will be prompted to try again.
+///
+/// If err_msg is set to None, a default message will be shown.
+///
+/// # RETURNS #
+/// The first socket address (SocketAddr) the endpoint provided by the user resolves to.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::read_socket_addr;
+/// let user_addr = read_socket_addr(Some("Server (host:port): "), None);
+/// ```
+pub fn read_socket_addr(msg: Option<&str>, err_msg: Option<&str>) -> SocketAddr {
+    read_until_valid(msg, err_msg, |input| {
+        let (host, port) = parse_endpoint(input)?;
+
+        (host.as_str(), port)
+            .to_socket_addrs()
+            .ok()
+            .and_then(|mut addrs| addrs.next())
+            .ok_or_else(|| format!("Could not resolve host '{host}'."))
+    })
+}
+
+fn parse_endpoint(input: &str) -> Result<(String, u16), String> {
+    let (host, port) = if let Some(rest) = input.strip_prefix('[') {
+        let (host, rest) = rest
+            .split_once(']')
+            .ok_or_else(|| String::from("Missing closing bracket after the IPv6 address."))?;
+
+        if host.parse::<Ipv6Addr>().is_err() {
+            return Err(format!("Invalid IPv6 address '{host}'."));
+        }
+
+        let port = rest
+            .strip_prefix(':')
+            .ok_or_else(|| String::from("Missing port, please use the format [address]:port."))?;
+        (host, port)
+    } else {
+        let (host, port) = input
+            .rsplit_once(':')
+            .ok_or_else(|| String::from("Missing port, please use the format host:port."))?;
+
+        if host.contains(':') {
+            return Err(String::from(
+                "IPv6 addresses must be written between brackets (Ex: [::1]:8080).",
+            ));
+        }
+
+        check_host(host)?;
+        (host, port)
+    };
+
+    Ok((host.to_string(), parse_port(port)?))
+}
+
+fn check_host(host: &str) -> Result<(), String> {
+    if host.is_empty() {
+        return Err(String::from(
+            "Missing host, please use the format host:port.",
+        ));
+    }
+
+    if host.chars().all(|c| c.is_ascii_digit() || c == '.') {
+        return match host.parse::<Ipv4Addr>() {
+            Ok(_) => Ok(()),
+            Err(_) => Err(format!("Invalid IPv4 address '{host}'.")),
+        };
+    }
+
+    let valid_label = |label: &str| {
+        (1..=63).contains(&label.len())
+            && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+            && !label.starts_with('-')
+            && !label.ends_with('-')
+    };
+
+    if host.len() > 253 || !host.split('.').all(valid_label) {
+        return Err(format!("Invalid hostname '{host}'."));
+    }
+
+    Ok(())
+}
+
+fn parse_port(input: &str) -> Result<u16, String> {
+    match input.parse::<u16>() {
+        Ok(0) => Err(String::from(
+            "Port 0 cannot be used, please enter a port between 1 and 65535.",
+        )),
+        Ok(port) => Ok(port),
+        Err(_) => Err(String::from("Please enter a valid port (1 - 65535).")),
+    }
+}
+
+#[cfg(unix)]
+fn is_privileged() -> bool {
+    // SAFETY: geteuid has no preconditions and cannot fail.
+    unsafe { libc::geteuid() == 0 }
+}
+
+#[cfg(not(unix))]
+fn is_privileged() -> bool {
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn endpoint_accepts_every_host_kind() {
+        assert_eq!(
+            parse_endpoint("example.com:443"),
+            Ok((String::from("example.com"), 443))
+        );
+        assert_eq!(
+            parse_endpoint("127.0.0.1:8080"),
+            Ok((String::from("127.0.0.1"), 8080))
+        );
+        assert_eq!(parse_endpoint("[::1]:22"), Ok((String::from("::1"), 22)));
+    }
+
+    #[test]
+    fn endpoint_reports_wrong_part() {
+        assert!(
+            parse_endpoint("example.com")
+                .unwrap_err()
+                .contains("Missing port")
+        );
+        assert!(parse_endpoint(":80").unwrap_err().contains("Missing host"));
+        assert!(parse_endpoint("300.1.1.1:80").unwrap_err().contains("IPv4"));
+        assert!(parse_endpoint("::1:80").unwrap_err().contains("brackets"));
+        assert!(
+            parse_endpoint("-bad-.com:80")
+                .unwrap_err()
+                .contains("hostname")
+        );
+        assert!(
+            parse_endpoint("example.com:0")
+                .unwrap_err()
+                .contains("Port 0")
+        );
+    }
+}
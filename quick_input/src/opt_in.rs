@@ -0,0 +1,124 @@
+//! Reader for lists of named options the user opts into (Ex: modules to enable).
+
+use crate::read_until_valid;
+use crate::suggest::{closest_matches, did_you_mean};
+
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as the input prompt. Must be set to Some("...") or None.
+///
+/// 'err_msg' (Option<&str>) - an optional error message which will be printed
+/// if the user inputs an invalid value. Must be set to Some("...") or None.
+///
+/// 'variants' (&[(&str, E)]) - name and value of every option (Ex: an enum variant).
+///
+/// # DESCRIPTION #
+/// Prompts the user to type the names of the options to enable, separated by commas and/or
+/// spaces (Ex: "audio, video net"). Names are not case-sensitive, and options typed more
+/// than once are only returned once. An empty line selects nothing.
+/// In case the user writes an unknown name, they will be prompted to try again, and the
+/// closest names are suggested.
+///
+/// Provides an information message on the same line as the prompt if Some("...")
+/// is provided, and just the prompt if None is provided.
+///
+/// If err_msg is set to None, a default message naming the unknown option will be shown.
+///
+/// # RETURNS #
+/// A Vec<E> with the chosen options, in the order they were typed.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::read_opt_in_list;
+///
+/// #[derive(Debug, Clone, Copy, PartialEq)]
+/// enum Module {
+///     Audio,
+///     Video,
+///     Net,
+/// }
+///
+/// let modules = read_opt_in_list(
+///     Some("Modules to enable (audio, video, net): "),
+///     None,
+///     &[("audio", Module::Audio), ("video", Module::Video), ("net", Module::Net)],
+/// );
+/// ```
+pub fn read_opt_in_list<E: Clone>(
+    msg: Option<&str>,
+    err_msg: Option<&str>,
+    variants: &[(&str, E)],
+) -> Vec<E> {
+    read_until_valid(msg, err_msg, |input| {
+        parse_opt_in_list(input, variants).map(|indexes| {
+            indexes
+                .into_iter()
+                .map(|index| variants[index].1.clone())
+                .collect()
+        })
+    })
+}
+
+/// # Arguments #
+/// 'input' (&str) - Trimmed input typed by the user.
+///
+/// 'variants' (&[(&str, E)]) - Name and value of every option.
+///
+/// # Description #
+/// Private function returning the indexes of the options named in 'input', without
+/// repetitions, or an error naming the first unknown option.
+fn parse_opt_in_list<E>(input: &str, variants: &[(&str, E)]) -> Result<Vec<usize>, String> {
+    let mut chosen = Vec::new();
+
+    for name in input
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|name| !name.is_empty())
+    {
+        let Some(index) = variants
+            .iter()
+            .position(|(variant, _)| variant.eq_ignore_ascii_case(name))
+        else {
+            let names = variants.iter().map(|(variant, _)| *variant);
+            let suggestions = closest_matches(name, names.clone(), 3);
+            return Err(match suggestions.is_empty() {
+                true => format!(
+                    "Unknown option '{name}'. Options: {}.",
+                    names.collect::<Vec<_>>().join(", ")
+                ),
+                false => format!("Unknown option '{name}'.{}", did_you_mean(&suggestions)),
+            });
+        };
+
+        if !chosen.contains(&index) {
+            chosen.push(index);
+        }
+    }
+
+    Ok(chosen)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn names_are_split_deduped_and_suggested() {
+        let variants = [("audio", 'a'), ("video", 'v'), ("net", 'n')];
+
+        assert_eq!(
+            parse_opt_in_list("NET, audio video,net", &variants),
+            Ok(vec![2, 0, 1])
+        );
+        assert_eq!(parse_opt_in_list("", &variants), Ok(vec![]));
+        assert_eq!(
+            parse_opt_in_list("audio, vidio", &variants),
+            Err(String::from("Unknown option 'vidio'. Did you mean video?"))
+        );
+        assert_eq!(
+            parse_opt_in_list("gpu", &variants),
+            Err(String::from(
+                "Unknown option 'gpu'. Options: audio, video, net."
+            ))
+        );
+    }
+}
@@ -0,0 +1,171 @@
+//! Readers for optional values, returning None when the user leaves the input blank.
+
+use std::str::FromStr;
+
+use crate::{parse_real, read_until_valid};
+
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as the input prompt. Must be set to Some("...") or None.
+///
+/// 'err_msg' (Option<&str>) - an optional error message which will be printed
+/// if the user inputs an invalid value. Must be set to Some("...") or None.
+///
+/// # DESCRIPTION #
+/// Prompts the user to type a value of any type implementing FromStr, or to press Enter
+/// without typing anything to skip it, which suits optional fields of setup wizards.
+/// To use words like "none" instead of an empty line, see read_option.
+/// In case the user writes an invalid value, they will be prompted to try again.
+///
+/// Provides an information message on the same line as the prompt if Some("...")
+/// is provided, and just the prompt if None is provided.
+///
+/// If err_msg is set to None, a default message will be shown.
+///
+/// # RETURNS #
+/// Some(T) with the value provided by the user, or None if the input was empty.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::read_opt;
+/// let proxy: Option<std::net::SocketAddr> = read_opt(Some("Proxy (leave blank for none): "), None);
+/// ```
+pub fn read_opt<T: FromStr>(msg: Option<&str>, err_msg: Option<&str>) -> Option<T> {
+    read_optional(msg, err_msg, |input| {
+        input
+            .parse()
+            .map_err(|_| String::from("Please enter a valid value"))
+    })
+}
+
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as the input prompt. Must be set to Some("...") or None.
+///
+/// 'err_msg' (Option<&str>) - an optional error message which will be printed
+/// if the user inputs an invalid value. Must be set to Some("...") or None.
+///
+/// # DESCRIPTION #
+/// Prompts the user to type an integer value (i32), or to leave the input blank to skip it.
+/// In case the user writes an invalid value, they will be prompted to try again.
+///
+/// Provides an information message on the same line as the prompt if Some("...")
+/// is provided, and just the prompt if None is provided.
+///
+/// If err_msg is set to None, a default message will be shown.
+///
+/// # RETURNS #
+/// Some(i32) with the value provided by the user, or None if the input was empty.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::read_opt_i32;
+/// let port = read_opt_i32(Some("Port (leave blank to skip): "), None);
+///
+/// let offset: Option<i32> = read_opt_i32(None, Some("Please input a number or nothing."));
+/// ```
+pub fn read_opt_i32(msg: Option<&str>, err_msg: Option<&str>) -> Option<i32> {
+    read_optional(msg, err_msg, |input| {
+        input
+            .parse()
+            .map_err(|_| String::from("Please enter a valid number (32 bits)"))
+    })
+}
+
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as the input prompt. Must be set to Some("...") or None.
+///
+/// 'err_msg' (Option<&str>) - an optional error message which will be printed
+/// if the user inputs an invalid value. Must be set to Some("...") or None.
+///
+/// # DESCRIPTION #
+/// Prompts the user to type an integer value (u32), or to leave the input blank to skip it.
+/// In case the user writes an invalid value, they will be prompted to try again.
+///
+/// If err_msg is set to None, a default message will be shown.
+///
+/// # RETURNS #
+/// Some(u32) with the value provided by the user, or None if the input was empty.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::read_opt_u32;
+/// let limit = read_opt_u32(Some("Max connections (blank for unlimited): "), None);
+/// ```
+pub fn read_opt_u32(msg: Option<&str>, err_msg: Option<&str>) -> Option<u32> {
+    read_optional(msg, err_msg, |input| {
+        input
+            .parse()
+            .map_err(|_| String::from("Please enter a valid positive number (32 bits)"))
+    })
+}
+
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as the input prompt. Must be set to Some("...") or None.
+///
+/// 'err_msg' (Option<&str>) - an optional error message which will be printed
+/// if the user inputs an invalid value. Must be set to Some("...") or None.
+///
+/// # DESCRIPTION #
+/// Prompts the user to type a real number with double precision (f64), or to leave the
+/// input blank to skip it. Like read_f64, both '.' and ',' are accepted as separators
+/// for the decimal part.
+/// In case the user writes an invalid value, they will be prompted to try again.
+///
+/// If err_msg is set to None, a default message will be shown.
+///
+/// # RETURNS #
+/// Some(f64) with the value provided by the user, or None if the input was empty.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::read_opt_f64;
+/// let discount = read_opt_f64(Some("Discount (optional): "), None);
+/// ```
+pub fn read_opt_f64(msg: Option<&str>, err_msg: Option<&str>) -> Option<f64> {
+    read_optional(msg, err_msg, |input| {
+        parse_real(input, true, "Please enter a valid real number (64 bits)")
+    })
+}
+
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as the input prompt. Must be set to Some("...") or None.
+///
+/// # DESCRIPTION #
+/// Prompts the user to type a string of text, or to leave the input blank to skip it.
+///
+/// # RETURNS #
+/// Some(String) with the trimmed text provided by the user, or None if the input was empty.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::read_opt_string;
+/// let nickname = read_opt_string(Some("Nickname (optional): "));
+/// ```
+pub fn read_opt_string(msg: Option<&str>) -> Option<String> {
+    read_optional(msg, None, |input| Ok(input.to_string()))
+}
+
+/// # Arguments #
+/// 'parse' (impl Fn(&str) -> Result<T, String>) - Closure parsing a non-empty input, whose
+/// error is completed with a reminder that the input may be left blank.
+///
+/// # Description #
+/// Private function shared by the readers of this module.
+fn read_optional<T>(
+    msg: Option<&str>,
+    err_msg: Option<&str>,
+    parse: impl Fn(&str) -> Result<T, String>,
+) -> Option<T> {
+    read_until_valid(msg, err_msg, |input| {
+        if input.is_empty() {
+            return Ok(None);
+        }
+        parse(input)
+            .map(Some)
+            .map_err(|err| format!("{err}, or leave it blank to skip it."))
+    })
+}
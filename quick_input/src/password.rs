@@ -0,0 +1,236 @@
+//! Readers for passwords and other secrets, which are never shown on the screen.
+
+use std::fmt;
+use std::ptr;
+use std::sync::atomic::{Ordering, compiler_fence};
+
+use crate::{Prompt, show_error_message};
+
+/// A sensitive text (Ex: a password or an API token) which is never printed, and whose
+/// memory is overwritten with zeroes once it is dropped, so it does not linger in memory.
+///
+/// Debug and Display show "[REDACTED]" instead of the text, so logging a Secret by mistake
+/// does not leak it. The text can be read with expose, which should be called right where it
+/// is needed, avoiding copies of it (which would not be zeroed).
+///
+/// # EXAMPLES #
+/// ```
+/// use quick_input::Secret;
+/// let token = Secret::new(String::from("hunter2"));
+///
+/// assert_eq!(token.expose(), "hunter2");
+/// assert_eq!(format!("{token} {token:?}"), "[REDACTED] Secret([REDACTED])");
+/// ```
+pub struct Secret(String);
+
+impl Secret {
+    /// Wraps 'text', which will be zeroed once the Secret is dropped.
+    pub fn new(text: String) -> Secret {
+        Secret(text)
+    }
+
+    /// Returns the text of the secret.
+    pub fn expose(&self) -> &str {
+        &self.0
+    }
+
+    /// Length of the text in bytes.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Whether the text is empty.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl From<String> for Secret {
+    fn from(text: String) -> Secret {
+        Secret::new(text)
+    }
+}
+
+impl fmt::Debug for Secret {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Secret([REDACTED])")
+    }
+}
+
+impl fmt::Display for Secret {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[REDACTED]")
+    }
+}
+
+impl Drop for Secret {
+    fn drop(&mut self) {
+        // SAFETY: the String is emptied before its bytes are overwritten, so it never holds
+        // invalid UTF-8, and only the bytes allocated by it (its capacity) are written.
+        let bytes = unsafe { self.0.as_mut_vec() };
+        let capacity = bytes.capacity();
+        bytes.clear();
+
+        let start = bytes.as_mut_ptr();
+        for offset in 0..capacity {
+            // Volatile writes are not optimised away, even though the memory is freed next.
+            unsafe { ptr::write_volatile(start.add(offset), 0) };
+        }
+        compiler_fence(Ordering::SeqCst);
+    }
+}
+
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as the input prompt. Must be set to Some("...") or None.
+///
+/// # DESCRIPTION #
+/// Prompts the user to type a password, disabling the echo of the terminal (termios on Unix,
+/// the Console API on Windows) so the typed characters are not shown on the screen.
+/// The answer is sensitive, so it is never cached, recorded nor shown in error messages.
+///
+/// Provides an information message on the same line as the prompt if Some("...")
+/// is provided, and just the prompt if None is provided.
+///
+/// # RETURNS #
+/// A String with the password typed by the user, including any surrounding spaces.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::read_password;
+/// let password = read_password(Some("Password: "));
+/// ```
+pub fn read_password(msg: Option<&str>) -> String {
+    Prompt::new(msg.unwrap_or_default()).text().hidden().read()
+}
+
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as the input prompt. Must be set to Some("...") or None.
+///
+/// 'mask' (char) - character printed for each typed character (Ex: '*').
+///
+/// # DESCRIPTION #
+/// Works like read_password, but prints 'mask' for every typed character, so the user can
+/// see how many were typed. Backspace and Ctrl+U (clear the line) are supported.
+/// If the keys can't be read one by one (Ex: on Windows), the password is hidden as with
+/// read_password.
+///
+/// # RETURNS #
+/// A String with the password typed by the user, including any surrounding spaces.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::read_password_masked;
+/// let password = read_password_masked(Some("Password: "), '*');
+/// ```
+pub fn read_password_masked(msg: Option<&str>, mask: char) -> String {
+    Prompt::new(msg.unwrap_or_default()).text().mask(mask).read()
+}
+
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as the first input prompt. Must be set to Some("...") or None.
+///
+/// 'confirm_msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as the second input prompt. Must be set to Some("...") or None.
+///
+/// 'mismatch_msg' (Option<&str>) - an optional error message which will be printed
+/// if both passwords are different. Must be set to Some("...") or None.
+///
+/// # DESCRIPTION #
+/// Prompts the user to type a new password twice, hidden as with read_password. If both
+/// passwords are different, the mismatch message is shown and the user is asked for both
+/// again. The passwords which did not match are zeroed, as with a Secret.
+///
+/// If mismatch_msg is set to None, a default message will be shown.
+///
+/// # RETURNS #
+/// A String with the password typed twice by the user, including any surrounding spaces.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::read_password_confirmed;
+/// let password = read_password_confirmed(
+///     Some("New password: "),
+///     Some("Repeat the new password: "),
+///     Some("The passwords do not match."),
+/// );
+/// ```
+pub fn read_password_confirmed(
+    msg: Option<&str>,
+    confirm_msg: Option<&str>,
+    mismatch_msg: Option<&str>,
+) -> String {
+    loop {
+        let password = read_password(msg);
+        let confirmation = Secret::new(read_password(confirm_msg));
+
+        if password == confirmation.expose() {
+            return password;
+        }
+        drop(Secret::new(password));
+        show_error_message(mismatch_msg, "The passwords do not match. Please try again.");
+    }
+}
+
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as the input prompt. Must be set to Some("...") or None.
+///
+/// # DESCRIPTION #
+/// Works like read_password, but returns the answer as a Secret, which can't be printed
+/// by mistake and is zeroed once dropped (Ex: for API tokens or private keys).
+///
+/// # RETURNS #
+/// A Secret with the text typed by the user, including any surrounding spaces.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::read_secret;
+/// let token = read_secret(Some("API token: "));
+///
+/// println!("Using the token {token}"); // Prints "Using the token [REDACTED]".
+/// let header = format!("Bearer {}", token.expose());
+/// ```
+pub fn read_secret(msg: Option<&str>) -> Secret {
+    Secret::new(read_password(msg))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::MockInput;
+
+    #[test]
+    fn passwords_are_read_as_sensitive_text() {
+        let mut mock = MockInput::new(["  hunter2 ", "s3cret "]);
+
+        assert_eq!(mock.run(|| read_password(Some("Password: "))), "  hunter2 ");
+        assert_eq!(mock.run(|| read_password_masked(None, '*')), "s3cret ");
+        mock.assert_prompted("Password: ");
+        mock.assert_all_answered();
+    }
+
+    #[test]
+    fn passwords_are_asked_again_until_they_match() {
+        let mut mock = MockInput::new(["first", "frist", "second", "second"]);
+        let password = mock.run(|| {
+            read_password_confirmed(Some("New password: "), Some("Repeat it: "), None)
+        });
+
+        assert_eq!(password, "second");
+        mock.assert_prompted_times("New password: ", 2);
+        mock.assert_rejected("The passwords do not match. Please try again.");
+    }
+
+    #[test]
+    fn secrets_are_redacted() {
+        let mut mock = MockInput::new(["token"]);
+        let secret = mock.run(|| read_secret(None));
+
+        assert_eq!(secret.expose(), "token");
+        assert_eq!(format!("{secret:?}"), "Secret([REDACTED])");
+        assert_eq!(secret.to_string(), "[REDACTED]");
+    }
+}
@@ -0,0 +1,311 @@
+//! Readers for glob and regular expression patterns.
+
+#[cfg(feature = "glob")]
+use glob::Pattern;
+#[cfg(feature = "regex")]
+use regex::{Captures, Regex};
+
+#[cfg(feature = "glob")]
+use crate::ask_yes_no;
+use crate::read_until_valid;
+use crate::reader::outln;
+
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as the input prompt. Must be set to Some("...") or None.
+///
+/// 'err_msg' (Option<&str>) - an optional error message which will be printed
+/// if the user inputs an invalid value. Must be set to Some("...") or None.
+///
+/// # DESCRIPTION #
+/// Prompts the user to type a glob pattern (Ex: src/**/*.rs) which will then be returned.
+/// In case the pattern does not compile, the user will be prompted to try again.
+///
+/// Provides an information message on the same line as the prompt if Some("...")
+/// is provided, and just the prompt if None is provided.
+///
+/// If err_msg is set to None, a default message explaining why the pattern is invalid will be shown.
+///
+/// # RETURNS #
+/// A compiled glob Pattern provided by the user.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::read_glob;
+/// let user_glob_with_msg = read_glob(Some("Files to include: "), Some("Please input a valid pattern."));
+///
+/// let user_glob: glob::Pattern = read_glob(None, None);
+/// ```
+#[cfg(feature = "glob")]
+pub fn read_glob(msg: Option<&str>, err_msg: Option<&str>) -> Pattern {
+    read_until_valid(msg, err_msg, parse_glob)
+}
+
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as the input prompt. Must be set to Some("...") or None.
+///
+/// 'err_msg' (Option<&str>) - an optional error message which will be printed
+/// if the user inputs an invalid value. Must be set to Some("...") or None.
+///
+/// # DESCRIPTION #
+/// Works like read_glob, but once the pattern compiles the number of files it currently
+/// matches is shown and the user is asked to confirm it (y / n) before it is accepted.
+/// If the user rejects the pattern, they will be prompted to type a new one.
+///
+/// If err_msg is set to None, a default message explaining why the pattern is invalid will be shown.
+///
+/// # RETURNS #
+/// A compiled glob Pattern provided (and confirmed) by the user.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::read_glob_with_preview;
+/// let user_glob = read_glob_with_preview(Some("Files to delete: "), None);
+/// ```
+#[cfg(feature = "glob")]
+pub fn read_glob_with_preview(msg: Option<&str>, err_msg: Option<&str>) -> Pattern {
+    loop {
+        let pattern = read_glob(msg, err_msg);
+
+        let matches = glob::glob(pattern.as_str())
+            .map(|paths| paths.filter_map(Result::ok).count())
+            .unwrap_or(0);
+
+        match matches {
+            1 => outln!("1 file matches this pattern."),
+            n => outln!("{n} files match this pattern."),
+        }
+
+        if ask_yes_no("Use this pattern? (y / n): ") {
+            return pattern;
+        }
+    }
+}
+
+#[cfg(feature = "glob")]
+fn parse_glob(input: &str) -> Result<Pattern, String> {
+    if input.is_empty() {
+        return Err(String::from("Please enter a glob pattern."));
+    }
+
+    Pattern::new(input).map_err(|err| format!("Invalid glob pattern: {err}."))
+}
+
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as the input prompt. Must be set to Some("...") or None.
+///
+/// 'err_msg' (Option<&str>) - an optional error message which will be printed
+/// if the user inputs an invalid value. Must be set to Some("...") or None.
+///
+/// # DESCRIPTION #
+/// Prompts the user to type a regular expression which will then be compiled and returned.
+/// In case the pattern does not compile, the user will be prompted to try again.
+///
+/// Provides an information message on the same line as the prompt if Some("...")
+/// is provided, and just the prompt if None is provided.
+///
+/// The compiler's explanation of what is wrong with the pattern is always shown after
+/// err_msg (or after a default message if err_msg is set to None), so the user can fix it.
+///
+/// # RETURNS #
+/// A compiled Regex provided by the user.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::read_regex;
+/// let user_regex_with_msg = read_regex(Some("Filter: "), Some("That pattern is not valid."));
+///
+/// let user_regex: regex::Regex = read_regex(None, None);
+/// ```
+#[cfg(feature = "regex")]
+pub fn read_regex(msg: Option<&str>, err_msg: Option<&str>) -> Regex {
+    read_until_valid(msg, None, |input| {
+        Regex::new(input).map_err(|err| {
+            let err_msg = err_msg.unwrap_or("Please enter a valid regular expression.");
+            format!("{err_msg}\n{err}")
+        })
+    })
+}
+
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as the input prompt. Must be set to Some("...") or None.
+///
+/// 'err_msg' (Option<&str>) - an optional error message which will be printed
+/// if the user inputs an invalid value. Must be set to Some("...") or None.
+///
+/// 'pattern' (&str) - regular expression the whole answer must match (Ex: r"[A-Z]{3}-\d+").
+///
+/// # DESCRIPTION #
+/// Prompts the user to type a text matching the pattern (Ex: an ID, a ticket number or a
+/// postcode), which will then be returned. The whole answer must match, so ^ and $ are not
+/// needed (Ex: \d{5} does not accept 123456). In case the user writes a text which does not
+/// match, they will be prompted to try again.
+///
+/// Provides an information message on the same line as the prompt if Some("...")
+/// is provided, and just the prompt if None is provided.
+///
+/// If err_msg is set to None, a default message showing the pattern will be shown.
+///
+/// # RETURNS #
+/// A trimmed String value provided by the user which matches the pattern.
+///
+/// # PANICS #
+/// If the pattern is not a valid regular expression.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::read_matching;
+/// let ticket = read_matching(Some("Ticket (ABC-123): "), None, r"[A-Z]+-\d+");
+///
+/// let postcode: String = read_matching(Some("Postcode: "), Some("Please enter 5 digits."), r"\d{5}");
+/// ```
+#[cfg(feature = "regex")]
+pub fn read_matching(msg: Option<&str>, err_msg: Option<&str>, pattern: &str) -> String {
+    read_full_match(msg, err_msg, pattern, |captures| captures[0].to_string())
+}
+
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as the input prompt. Must be set to Some("...") or None.
+///
+/// 'err_msg' (Option<&str>) - an optional error message which will be printed
+/// if the user inputs an invalid value. Must be set to Some("...") or None.
+///
+/// 'regex' (&Regex) - regular expression the whole answer must match, which may be
+/// built once and reused.
+///
+/// # DESCRIPTION #
+/// Works like read_matching, with a regular expression which is already compiled.
+/// Only its pattern is used, so options set with RegexBuilder are not kept: inline flags
+/// should be used instead (Ex: (?i) to ignore the case).
+///
+/// # RETURNS #
+/// A trimmed String value provided by the user which matches the regular expression.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::read_matching_regex;
+/// use regex::Regex;
+/// let sku = Regex::new(r"(?i)sku-\d{6}").unwrap();
+///
+/// let first = read_matching_regex(Some("First SKU: "), None, &sku);
+/// let second = read_matching_regex(Some("Second SKU: "), None, &sku);
+/// ```
+#[cfg(feature = "regex")]
+pub fn read_matching_regex(msg: Option<&str>, err_msg: Option<&str>, regex: &Regex) -> String {
+    read_full_match(msg, err_msg, regex.as_str(), |captures| {
+        captures[0].to_string()
+    })
+}
+
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as the input prompt. Must be set to Some("...") or None.
+///
+/// 'err_msg' (Option<&str>) - an optional error message which will be printed
+/// if the user inputs an invalid value. Must be set to Some("...") or None.
+///
+/// 'regex' (&Regex) - regular expression the whole answer must match, with
+/// capture groups for the parts to return.
+///
+/// # DESCRIPTION #
+/// Works like read_matching_regex, but returns the text of every capture group instead
+/// of the whole answer, so the answer does not have to be split again.
+///
+/// # RETURNS #
+/// A Vec<Option<String>> with one item per capture group, numbered as in regex::Captures
+/// (the item 0 being the whole answer). Optional groups which did not match are None.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::read_matching_captures;
+/// use regex::Regex;
+/// let version = Regex::new(r"(\d+)\.(\d+)(?:\.(\d+))?").unwrap();
+///
+/// let groups = read_matching_captures(Some("Version (1.2 or 1.2.3): "), None, &version);
+/// let major: u32 = groups[1].as_deref().unwrap().parse().unwrap();
+/// let patch = groups[3].as_deref().unwrap_or("0");
+/// ```
+#[cfg(feature = "regex")]
+pub fn read_matching_captures(
+    msg: Option<&str>,
+    err_msg: Option<&str>,
+    regex: &Regex,
+) -> Vec<Option<String>> {
+    read_full_match(msg, err_msg, regex.as_str(), |captures| {
+        captures
+            .iter()
+            .map(|group| group.map(|group| group.as_str().to_string()))
+            .collect()
+    })
+}
+
+/// # Arguments #
+/// 'pattern' (&str) - Regular expression the whole answer must match, also shown in the
+/// default error message.
+///
+/// 'map' (impl Fn(Captures) -> T) - Closure building the value from a matching answer.
+///
+/// # Description #
+/// Private function shared by the readers checking that the whole answer matches a regular
+/// expression. The pattern is anchored at both ends, so every way of matching the whole
+/// answer is tried (Ex: a|ab accepts ab), rather than only the first match found.
+#[cfg(feature = "regex")]
+fn read_full_match<T>(
+    msg: Option<&str>,
+    err_msg: Option<&str>,
+    pattern: &str,
+    map: impl Fn(Captures) -> T,
+) -> T {
+    let regex = Regex::new(&format!("^(?:{pattern})$")).unwrap_or_else(|err| {
+        panic!("The pattern '{pattern}' is not a valid regular expression: {err}")
+    });
+
+    read_until_valid(msg, err_msg, |input| {
+        regex
+            .captures(input)
+            .map(&map)
+            .ok_or_else(|| format!("The answer must match the pattern '{pattern}'."))
+    })
+}
+
+#[cfg(all(test, feature = "regex"))]
+mod tests {
+    use super::*;
+    use crate::testing::MockInput;
+
+    #[test]
+    fn the_whole_answer_must_match() {
+        let mut mock = MockInput::new(["123456", "12345", "1.2", "v1.2", "1.2.3"]);
+
+        assert_eq!(mock.run(|| read_matching(None, None, r"\d{5}")), "12345");
+        mock.assert_rejected("The answer must match the pattern '\\d{5}'.");
+
+        let version = Regex::new(r"(\d+)\.(\d+)(?:\.(\d+))?").unwrap();
+        let groups = mock.run(|| read_matching_captures(None, None, &version));
+        assert_eq!(
+            groups,
+            [Some("1.2"), Some("1"), Some("2"), None].map(|g| g.map(String::from))
+        );
+
+        let groups = mock.run(|| read_matching_captures(None, None, &version));
+        assert_eq!(groups[3].as_deref(), Some("3"));
+        mock.assert_rejected(&format!("The answer must match the pattern '{version}'."));
+    }
+
+    #[test]
+    fn every_alternative_is_tried() {
+        let mut mock = MockInput::new(["ab", "x-y", "ab"]);
+        let prefix = Regex::new("a|ab").unwrap();
+        let lazy = Regex::new(r"(\w+?)-(\w*?)").unwrap();
+
+        assert_eq!(mock.run(|| read_matching_regex(None, None, &prefix)), "ab");
+        let groups = mock.run(|| read_matching_captures(None, None, &lazy));
+        assert_eq!(groups[2].as_deref(), Some("y"));
+        assert_eq!(mock.run(|| read_matching(None, None, "a|ab")), "ab");
+        mock.assert_all_answered();
+    }
+}
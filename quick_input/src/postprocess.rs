@@ -0,0 +1,164 @@
+//! Post-processors run on every accepted answer (Ex: logging, metrics, clean-ups).
+
+use std::sync::RwLock;
+
+/// Information about an accepted answer, given to the post-processors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct AnsweredPrompt<'a> {
+    /// Message of the prompt which was answered (Ex: "Port: "), which may be empty.
+    pub message: &'a str,
+}
+
+type PostProcessor = Box<dyn Fn(&AnsweredPrompt, String) -> String + Send + Sync>;
+
+/// Post-processors added with add_post_processor, in the order they run.
+static POST_PROCESSORS: RwLock<Vec<PostProcessor>> = RwLock::new(Vec::new());
+
+/// # ARGUMENTS #
+/// 'processor' (impl Fn(&AnsweredPrompt, String) -> String) - closure receiving the prompt and
+/// the text of an accepted answer, and returning the text to keep (the same one to leave it as
+/// it is).
+///
+/// # DESCRIPTION #
+/// Registers a post-processor which runs on every answer accepted by a reader, a Prompt, a Form
+/// or a Session, after the previous post-processors, so cross-cutting concerns don't require
+/// wrapping every call. If the text is changed, the value returned by the reader is parsed
+/// again from the new text, and if it is no longer valid, the error is shown and the user is
+/// asked again.
+///
+/// Post-processors don't run in dry-run mode, nor for prompts created with
+/// Prompt::skip_post_processors. They never receive sensitive answers (Ex: passwords, or
+/// prompts marked with Prompt::sensitive), which are kept as typed. They must not read any
+/// input themselves.
+///
+/// # EXAMPLES #
+/// ```
+/// use quick_input::testing::MockInput;
+/// use quick_input::{add_post_processor, read_string};
+/// add_post_processor(|prompt, answer| {
+///     eprintln!("[audit] {}{answer}", prompt.message);
+///     answer
+/// });
+/// add_post_processor(|_, answer| answer.split_whitespace().collect::<Vec<_>>().join(" "));
+///
+/// let mut mock = MockInput::new(["Ada    Lovelace"]);
+/// assert_eq!(mock.run(|| read_string(Some("Full name: "))), "Ada Lovelace");
+/// ```
+pub fn add_post_processor(
+    processor: impl Fn(&AnsweredPrompt, String) -> String + Send + Sync + 'static,
+) {
+    POST_PROCESSORS
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .push(Box::new(processor));
+}
+
+/// Removes every post-processor added with add_post_processor.
+pub fn clear_post_processors() {
+    POST_PROCESSORS
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+        .clear();
+}
+
+/// # Arguments #
+/// 'message' (&str) - Message of the prompt which was answered.
+///
+/// 'input' (&mut String) - Accepted answer, replaced by the text returned by the
+/// post-processors.
+///
+/// 'parse' (impl Fn(&str) -> Result<T, E>) - Closure which accepted the answer.
+///
+/// # Description #
+/// Private function accepting an answer with 'parse' and then running the post-processors
+/// on it. The value is parsed again only if a post-processor changed the text.
+pub(crate) fn accept<T, E>(
+    message: &str,
+    input: &mut String,
+    parse: impl Fn(&str) -> Result<T, E>,
+) -> Result<T, E> {
+    let value = parse(input)?;
+
+    match run(message, input) {
+        true => parse(input),
+        false => Ok(value),
+    }
+}
+
+/// # Arguments #
+/// 'message' (&str) - Message of the prompt which was answered.
+///
+/// 'input' (&mut String) - Accepted answer, replaced by the text returned by the
+/// post-processors.
+///
+/// # Description #
+/// Private function running the post-processors on an answer accepted as it is (Ex: by
+/// read_string), returning whether they changed it.
+pub(crate) fn run(message: &str, input: &mut String) -> bool {
+    let processors = POST_PROCESSORS
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    if processors.is_empty() {
+        return false;
+    }
+
+    let prompt = AnsweredPrompt { message };
+    let processed = processors.iter().fold(input.clone(), |answer, processor| {
+        processor(&prompt, answer)
+    });
+
+    if processed == *input {
+        return false;
+    }
+
+    *input = processed;
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Prompt;
+    use crate::testing::MockInput;
+
+    #[test]
+    fn answers_are_processed_unless_skipped() {
+        // Only affects this prompt, since the tests share the post-processors.
+        add_post_processor(|prompt, answer| match prompt.message {
+            "Post-processed: " => answer.replace('_', ""),
+            _ => answer,
+        });
+
+        let mut mock = MockInput::new(["1_000", "1_000"]);
+        let processed = mock.run(|| Prompt::new("Post-processed: ").text().read());
+        let skipped = mock.run(|| {
+            Prompt::new("Post-processed: ")
+                .text()
+                .skip_post_processors()
+                .read()
+        });
+
+        assert_eq!(processed, "1000");
+        assert_eq!(skipped, "1_000");
+    }
+
+    #[test]
+    fn sensitive_answers_never_reach_the_processors() {
+        static SEEN: RwLock<Vec<String>> = RwLock::new(Vec::new());
+        add_post_processor(|prompt, answer| match prompt.message {
+            "Secret: " => {
+                SEEN.write().unwrap().push(answer.clone());
+                answer.to_uppercase()
+            }
+            _ => answer,
+        });
+
+        let mut mock = MockInput::new(["hunter2", "hunter2"]);
+        let sensitive = mock.run(|| Prompt::new("Secret: ").text().sensitive().read());
+        let hidden = mock.run(|| Prompt::new("Secret: ").text().hidden().read());
+
+        assert_eq!([sensitive, hidden], ["hunter2", "hunter2"]);
+        assert!(SEEN.read().unwrap().is_empty());
+    }
+}
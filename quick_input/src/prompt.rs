@@ -0,0 +1,1348 @@
+//! A configurable prompt builder.
+//!
+//! The builder uses typestate: a Prompt starts Untyped, and methods only become available
+//! once they make sense. For instance default() needs a type to be chosen first, hidden()
+//! only exists for text prompts and range() only for values whose type can be ordered.
+//! Misusing the builder is therefore a compile error rather than a runtime surprise.
+//!
+//! ```compile_fail
+//! use quick_input::Prompt;
+//! // hidden() is only available for text prompts.
+//! let pin = Prompt::new("PIN: ").value::<u32>().hidden();
+//! ```
+//!
+//! ```compile_fail
+//! use quick_input::Prompt;
+//! // default() is only available once the type has been chosen.
+//! let port = Prompt::new("Port: ").default(8080);
+//! ```
+
+use std::fmt::Display;
+use std::io;
+use std::marker::PhantomData;
+use std::ops::{Bound, RangeBounds};
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use crate::record::record_answer;
+use crate::tty::HiddenInput;
+use crate::messages::{self, Messages};
+use crate::InputError;
+use crate::{
+    dry_run, editor, eof, examples, input, multiline, postprocess, read_until_valid, render,
+    show_error_message, strip_line_end,
+};
+use crate::reader::{self, outln};
+
+/// State of a Prompt whose type has not been chosen yet.
+pub struct Untyped;
+
+/// State of a Prompt reading free text (String).
+pub struct Text;
+
+/// State of a Prompt reading a value parsed with FromStr.
+pub struct Value;
+
+pub(crate) mod sealed {
+    pub trait Typed {}
+    impl Typed for super::Text {}
+    impl Typed for super::Value {}
+}
+
+type Parser<T> = fn(&str) -> Result<T, String>;
+type Check<T> = Box<dyn Fn(&T) -> Result<(), String>>;
+type Echo<T> = Box<dyn Fn(&T) -> String>;
+type Normalizer = Box<dyn Fn(&str) -> String>;
+
+/// How an accepted value is echoed back to the user (see Prompt::echo).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EchoFormat {
+    /// Text printed before the value ("Using " by default).
+    pub prefix: String,
+    /// Character used to group the thousands of the integer part (',' by default),
+    /// or None to print the value as it is.
+    pub thousands_separator: Option<char>,
+    /// Unit printed after the value, separated by a space (none by default).
+    pub unit: Option<String>,
+}
+
+impl Default for EchoFormat {
+    fn default() -> Self {
+        EchoFormat {
+            prefix: String::from("Using "),
+            thousands_separator: Some(','),
+            unit: None,
+        }
+    }
+}
+
+/// Which spellings of a number a value prompt accepts (see Prompt::number_policy).
+/// The input is checked and normalized with it before being parsed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NumberPolicy {
+    /// Whether an explicit '+' sign is accepted (Ex: +5). True by default.
+    pub allow_plus_sign: bool,
+    /// Whether leading zeros are accepted (Ex: 007). True by default.
+    pub allow_leading_zeros: bool,
+    /// Whether spaces inside the number are accepted and ignored (Ex: 1 000). False by default.
+    pub allow_spaces: bool,
+    /// Whether scientific notation is accepted (Ex: 1e-3). True by default.
+    pub allow_scientific: bool,
+}
+
+impl Default for NumberPolicy {
+    fn default() -> Self {
+        NumberPolicy {
+            allow_plus_sign: true,
+            allow_leading_zeros: true,
+            allow_spaces: false,
+            allow_scientific: true,
+        }
+    }
+}
+
+impl NumberPolicy {
+    fn normalize(&self, input: &str) -> Result<String, String> {
+        let number = if self.allow_spaces {
+            input.chars().filter(|c| !c.is_whitespace()).collect()
+        } else if input.contains(char::is_whitespace) {
+            return Err(String::from("Please enter the number without spaces."));
+        } else {
+            input.to_string()
+        };
+
+        if !self.allow_scientific && number.contains(['e', 'E']) {
+            return Err(String::from(
+                "Please enter the number without scientific notation (Ex: 0.001 instead of 1e-3).",
+            ));
+        }
+
+        if !self.allow_plus_sign && number.starts_with('+') {
+            return Err(String::from("Please enter the number without a '+' sign."));
+        }
+
+        let mut digits = number.trim_start_matches(['+', '-']).chars();
+        if !self.allow_leading_zeros
+            && digits.next() == Some('0')
+            && digits.next().is_some_and(|c| c.is_ascii_digit())
+        {
+            return Err(String::from(
+                "Please enter the number without leading zeros.",
+            ));
+        }
+
+        Ok(number)
+    }
+}
+
+/// How a value with too many decimal places is rounded (see Prompt::decimals).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rounding {
+    /// Rounds halves away from zero (Ex: 2.345 -> 2.35, -2.345 -> -2.35).
+    HalfUp,
+    /// Rounds halves to the nearest even digit, also known as banker's rounding
+    /// (Ex: 2.345 -> 2.34, 2.355 -> 2.36).
+    HalfEven,
+    /// Drops the extra decimal places (Ex: 2.349 -> 2.34).
+    Truncate,
+}
+
+/// Builder used to configure a prompt before reading from it.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::Prompt;
+/// let port: u16 = Prompt::new("Port: ")
+///     .value()
+///     .range(1024..)
+///     .default(8080)
+///     .err_msg("Please input a port above 1023.")
+///     .read();
+///
+/// let password: String = Prompt::new("Password: ").text().hidden().read();
+/// ```
+pub struct Prompt<T = (), S = Untyped> {
+    parser: Parser<T>,
+    msg: String,
+    err_msg: Option<String>,
+    help: Option<String>,
+    default: Option<T>,
+    hidden: bool,
+    mask: Option<char>,
+    edit_on_retry: bool,
+    all_errors: bool,
+    sensitive: bool,
+    number_policy: Option<NumberPolicy>,
+    decimals: Option<(usize, Option<Rounding>)>,
+    /// Range checks and validators, in the order they were added. Range checks keep the
+    /// description of their bounds, reported by try_read.
+    checks: Vec<(Check<T>, Option<String>)>,
+    dry_run_hints: Vec<String>,
+    echo: Option<Echo<T>>,
+    normalizer: Option<Normalizer>,
+    confirm_normalization: bool,
+    messages: Option<Messages>,
+    max_attempts: Option<usize>,
+    cancel_word: Option<String>,
+    timeout: Option<Duration>,
+    post_process: bool,
+    state: PhantomData<S>,
+}
+
+impl Prompt {
+    /// # ARGUMENTS #
+    /// 'msg' (&str) - message which will be printed at the same line as the input prompt.
+    /// May be left empty to show just the prompt.
+    ///
+    /// # DESCRIPTION #
+    /// Creates an Untyped Prompt. Either text() or value() must be called before reading.
+    pub fn new(msg: &str) -> Self {
+        Prompt {
+            parser: |_| Ok(()),
+            msg: msg.to_string(),
+            err_msg: None,
+            help: None,
+            default: None,
+            hidden: false,
+            mask: None,
+            edit_on_retry: false,
+            all_errors: false,
+            sensitive: false,
+            number_policy: None,
+            decimals: None,
+            checks: Vec::new(),
+            dry_run_hints: Vec::new(),
+            echo: None,
+            normalizer: None,
+            confirm_normalization: false,
+            messages: None,
+            max_attempts: None,
+            cancel_word: None,
+            timeout: None,
+            post_process: true,
+            state: PhantomData,
+        }
+    }
+
+    /// Turns the Prompt into a text prompt, which returns the trimmed String typed by the user.
+    pub fn text(self) -> Prompt<String, Text> {
+        self.into_state(|input| Ok(input.to_string()))
+    }
+
+    /// Turns the Prompt into a value prompt, which loops until the input can be parsed as T.
+    pub fn value<T: FromStr>(self) -> Prompt<T, Value> {
+        self.into_state(|input| {
+            input
+                .parse::<T>()
+                .map_err(|_| String::from("Please enter a valid value."))
+        })
+    }
+
+    fn into_state<T, S>(self, parser: Parser<T>) -> Prompt<T, S> {
+        Prompt {
+            parser,
+            msg: self.msg,
+            err_msg: self.err_msg,
+            help: self.help,
+            default: None,
+            hidden: false,
+            mask: None,
+            edit_on_retry: false,
+            all_errors: false,
+            sensitive: self.sensitive,
+            number_policy: None,
+            decimals: None,
+            checks: Vec::new(),
+            dry_run_hints: Vec::new(),
+            echo: None,
+            normalizer: None,
+            confirm_normalization: false,
+            messages: self.messages,
+            max_attempts: self.max_attempts,
+            cancel_word: self.cancel_word,
+            timeout: self.timeout,
+            post_process: self.post_process,
+            state: PhantomData,
+        }
+    }
+}
+
+impl<T, S> Prompt<T, S> {
+    /// Sets the error message printed when the user inputs an invalid value.
+    /// If it is not set, a default message describing the problem will be shown.
+    pub fn err_msg(mut self, err_msg: &str) -> Self {
+        self.err_msg = Some(err_msg.to_string());
+        self
+    }
+
+    /// Sets a longer explanation shown when the user types '?', after which the prompt
+    /// is shown again. This keeps the prompt message short, although it is a good idea to
+    /// mention the help in it (Ex: "Port (? for help): ").
+    pub fn help(mut self, help: &str) -> Self {
+        self.help = Some(help.to_string());
+        self
+    }
+
+    /// Lets the user give up on the prompt by typing ":q" (see cancel_word) or, when the keys
+    /// can be read one by one (see term::supports_raw_mode), by pressing Ctrl+C, which then
+    /// no longer interrupts the program. try_read (and Session::try_ask) return
+    /// InputError::Cancelled when the prompt is cancelled, and read panics, restoring the
+    /// terminal first.
+    ///
+    /// # EXAMPLES #
+    /// ```no_run
+    /// use quick_input::{InputError, Prompt};
+    /// match Prompt::new("Branch to delete (:q to cancel): ").text().cancellable().try_read() {
+    ///     Ok(branch) => println!("Deleting {branch}"),
+    ///     Err(InputError::Cancelled) => println!("Nothing was deleted."),
+    ///     Err(err) => eprintln!("{err}"),
+    /// }
+    /// ```
+    pub fn cancellable(self) -> Self {
+        self.cancel_word(":q")
+    }
+
+    /// Makes the prompt cancellable (see cancellable), with 'word' as the answer which
+    /// cancels it (Ex: "quit"). It is compared with the trimmed answer, case-sensitively.
+    pub fn cancel_word(mut self, word: &str) -> Self {
+        self.cancel_word = Some(word.to_string());
+        self
+    }
+
+    /// # ARGUMENTS #
+    /// 'timeout' (Duration) - time the user has to answer, counted from the moment the
+    /// prompt is shown (invalid answers don't restart it).
+    ///
+    /// # DESCRIPTION #
+    /// Stops waiting for the user once 'timeout' passes, so unattended scripts never hang
+    /// forever. Then, the default value is returned (with a notice) if the Prompt has one,
+    /// and otherwise try_read returns InputError::Timeout and read panics.
+    /// An answer typed after the time limit is kept for the next prompt. Line editing
+    /// (see edit_on_retry), masking and Ctrl+C cancellation are not available with a timeout.
+    ///
+    /// # EXAMPLES #
+    /// ```no_run
+    /// use quick_input::Prompt;
+    /// use std::time::Duration;
+    /// let overwrite = Prompt::new("Overwrite the backup? (true / false): ")
+    ///     .value()
+    ///     .default(false)
+    ///     .timeout(Duration::from_secs(30))
+    ///     .read();
+    /// ```
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Marks the answer as sensitive, so it is replaced by "***" wherever answers are recorded
+    /// (such as the session history used for suggestions) and never given to the
+    /// post-processors. Hidden prompts are always sensitive.
+    pub fn sensitive(mut self) -> Self {
+        self.sensitive = true;
+        self
+    }
+
+    /// Keeps the post-processors added with add_post_processor from running on the answers
+    /// to this Prompt (Ex: to skip logging for a single question).
+    pub fn skip_post_processors(mut self) -> Self {
+        self.post_process = false;
+        self
+    }
+
+    /// # ARGUMENTS #
+    /// 'attempts' (usize) - number of invalid answers allowed before giving up.
+    ///
+    /// # DESCRIPTION #
+    /// Stops asking after 'attempts' invalid answers instead of looping until a valid one
+    /// is typed. The default value of the Prompt (if any) is then used as a fallback, and
+    /// otherwise try_read returns InputError::MaxAttemptsExceeded (and read panics).
+    /// With a limit, try_read asks again after an invalid answer, like read does.
+    ///
+    /// # PANICS #
+    /// If 'attempts' is 0.
+    ///
+    /// # EXAMPLES #
+    /// ```no_run
+    /// use quick_input::Prompt;
+    /// let copies: u32 = Prompt::new("Copies: ").value().range(1..=100).max_attempts(3).default(1).read();
+    ///
+    /// let seats = Prompt::new("Seats: ").value::<u8>().max_attempts(3).try_read();
+    /// ```
+    pub fn max_attempts(mut self, attempts: usize) -> Self {
+        assert!(attempts > 0, "At least one attempt must be allowed.");
+        self.max_attempts = Some(attempts);
+        self
+    }
+
+    /// # ARGUMENTS #
+    /// 'messages' (Messages) - translations of the built-in messages for this Prompt.
+    ///
+    /// # DESCRIPTION #
+    /// Overrides the global catalog (see set_messages) for this Prompt only, so a single
+    /// question can be asked in another language. Messages left as None fall back to the
+    /// global catalog.
+    ///
+    /// # EXAMPLES #
+    /// ```no_run
+    /// use quick_input::{Messages, Prompt};
+    /// let edad: u8 = Prompt::new("Edad: ")
+    ///     .messages(Messages {
+    ///         invalid_value: Some(String::from("Por favor, introduzca un número.")),
+    ///         ..Messages::default()
+    ///     })
+    ///     .value()
+    ///     .read();
+    /// ```
+    pub fn messages(mut self, messages: Messages) -> Self {
+        self.messages = Some(messages);
+        self
+    }
+
+    /// Returns the translation of a built-in message, falling back to the global catalog.
+    fn message(&self, field: fn(&Messages) -> &Option<String>, default: &str) -> String {
+        messages::lookup(self.messages.as_ref(), field, default)
+    }
+}
+
+impl<T, S: sealed::Typed> Prompt<T, S> {
+    /// Sets the value returned if the user presses Enter without typing anything.
+    pub fn default(mut self, default: T) -> Self {
+        self.default = Some(default);
+        self
+    }
+
+    /// # ARGUMENTS #
+    /// 'validator' (impl Fn(&T) -> Result<(), String>) - closure receiving the parsed value,
+    /// which returns the error message to show if the value is not acceptable.
+    ///
+    /// # DESCRIPTION #
+    /// Adds a rule the value must follow besides being parsed. Validators are run in the
+    /// order they were added, after range(); by default the first failing one is reported.
+    /// If err_msg is set, it is shown instead of the message returned by the validator.
+    /// Otherwise, if one of a few common answers (Ex: 42, 1, abc) passes every check, it is
+    /// added to the message as an example (Ex: "Must be even (e.g. 42).").
+    ///
+    /// # EXAMPLES #
+    /// ```no_run
+    /// use quick_input::Prompt;
+    /// let code: String = Prompt::new("Code: ")
+    ///     .text()
+    ///     .validate(|code: &String| if code.len() >= 6 { Ok(()) } else { Err(String::from("Too short.")) })
+    ///     .validate(|code: &String| match code.chars().any(|c| c.is_ascii_digit()) {
+    ///         true => Ok(()),
+    ///         false => Err(String::from("Must contain a digit.")),
+    ///     })
+    ///     .all_errors()
+    ///     .read();
+    /// ```
+    pub fn validate(mut self, validator: impl Fn(&T) -> Result<(), String> + 'static) -> Self {
+        self.checks.push((Box::new(validator), None));
+        self
+    }
+
+    /// Runs every range check and validator, even after one fails, and reports all of
+    /// their errors at once (one per line), so the user can fix everything in a single retry.
+    pub fn all_errors(mut self) -> Self {
+        self.all_errors = true;
+        self
+    }
+
+    /// After an invalid answer, the next attempt starts with the previous one already typed,
+    /// so the user can fix a single character of a long value instead of typing it again.
+    /// Only available when stdin is a terminal on Unix; elsewhere the prompt behaves as usual.
+    /// It has no effect on hidden prompts.
+    pub fn edit_on_retry(mut self) -> Self {
+        self.edit_on_retry = true;
+        self
+    }
+
+    pub(crate) fn msg(&self) -> &str {
+        &self.msg
+    }
+
+    pub(crate) fn default_value(&self) -> Option<&T> {
+        self.default.as_ref()
+    }
+
+    pub(crate) fn is_sensitive(&self) -> bool {
+        self.sensitive
+    }
+
+    pub(crate) fn with_msg(mut self, msg: String) -> Self {
+        self.msg = msg;
+        self
+    }
+}
+
+impl Prompt<String, Text> {
+    /// Hides the typed characters (Ex: for passwords), so they are not echoed to the terminal.
+    /// Hidden prompts are also marked as sensitive, and their answers are not trimmed, since
+    /// spaces may be part of them.
+    pub fn hidden(mut self) -> Self {
+        self.hidden = true;
+        self.sensitive = true;
+        self
+    }
+
+    /// Hides the typed characters like hidden, but prints 'mask' (Ex: '*') for each of them,
+    /// so the user can see how many were typed. If the keys can't be read one by one (Ex: on
+    /// Windows or when stdin is not a terminal), nothing is printed at all, as with hidden.
+    pub fn mask(mut self, mask: char) -> Self {
+        self.mask = Some(mask);
+        self.hidden()
+    }
+
+    /// # ARGUMENTS #
+    /// 'normalizer' (impl Fn(&str) -> String) - closure receiving the trimmed text typed by
+    /// the user, which returns its normalized form (Ex: without trailing punctuation, in
+    /// title case or fixed by a spellchecker).
+    ///
+    /// # DESCRIPTION #
+    /// Normalizes every answer before it is validated and returned. Setting a new
+    /// normalizer replaces the previous one.
+    ///
+    /// # EXAMPLES #
+    /// ```no_run
+    /// use quick_input::Prompt;
+    /// let city: String = Prompt::new("City: ")
+    ///     .text()
+    ///     .normalize(|city| city.trim_end_matches(['.', ',']).to_string())
+    ///     .read();
+    /// ```
+    pub fn normalize(mut self, normalizer: impl Fn(&str) -> String + 'static) -> Self {
+        self.normalizer = Some(Box::new(normalizer));
+        self.confirm_normalization = false;
+        self
+    }
+
+    /// # ARGUMENTS #
+    /// 'corrector' (impl Fn(&str) -> String) - closure receiving the trimmed text typed by
+    /// the user, which returns the suggested correction (or the same text if it is fine).
+    ///
+    /// # DESCRIPTION #
+    /// Like normalize, but whenever the correction differs from the answer the user is asked
+    /// whether to accept it ("Did you mean '...'? [Y/n]"), keeping the answer as typed if they
+    /// decline. On hidden prompts the correction is applied without asking, so the answer is
+    /// never printed.
+    ///
+    /// # EXAMPLES #
+    /// ```no_run
+    /// use quick_input::Prompt;
+    /// let language: String = Prompt::new("Language: ")
+    ///     .text()
+    ///     .suggest_correction(|language| match language {
+    ///         "pyhton" => String::from("python"),
+    ///         "rsut" => String::from("rust"),
+    ///         other => other.to_string(),
+    ///     })
+    ///     .read();
+    /// ```
+    pub fn suggest_correction(mut self, corrector: impl Fn(&str) -> String + 'static) -> Self {
+        self.normalizer = Some(Box::new(corrector));
+        self.confirm_normalization = true;
+        self
+    }
+
+    /// # ARGUMENTS #
+    /// 'max' (usize) - maximum number of words of the answer.
+    ///
+    /// # DESCRIPTION #
+    /// Rejects answers with more than 'max' words (separated by whitespace). If err_msg is
+    /// not set, the default error message reports how many words were typed and the limit.
+    ///
+    /// # EXAMPLES #
+    /// ```no_run
+    /// use quick_input::Prompt;
+    /// let subject: String = Prompt::new("Commit subject: ").text().max_words(12).read();
+    /// ```
+    pub fn max_words(self, max: usize) -> Self {
+        self.validate(move |text| multiline::check_words(text, max))
+    }
+}
+
+impl<T: PartialOrd + Display + 'static> Prompt<T, Value> {
+    /// # ARGUMENTS #
+    /// 'range' (impl RangeBounds<T>) - any range (Ex: 1..=10, 0.0..1.0, 18..) the value must be within.
+    ///
+    /// # DESCRIPTION #
+    /// Rejects values outside of the range. If err_msg is not set,
+    /// the default error message will show the allowed bounds and an example of a valid value
+    /// (Ex: "Please enter a value between 1 and 10 (e.g. 5).").
+    pub fn range(mut self, range: impl RangeBounds<T> + 'static) -> Self {
+        let bounds = describe_bounds(&range);
+        if let Bound::Included(start) | Bound::Excluded(start) = range.start_bound() {
+            self.dry_run_hints.push(start.to_string());
+        }
+        if let Bound::Included(end) | Bound::Excluded(end) = range.end_bound() {
+            self.dry_run_hints.push(end.to_string());
+        }
+
+        let message = format!("Please enter a value {bounds}.");
+        let check: Check<T> = Box::new(move |value| match range.contains(value) {
+            true => Ok(()),
+            false => Err(message.clone()),
+        });
+        self.checks.push((check, Some(bounds)));
+        self
+    }
+}
+
+impl<T> Prompt<T, Value> {
+    /// # ARGUMENTS #
+    /// 'policy' (NumberPolicy) - which spellings of a number are accepted.
+    ///
+    /// # DESCRIPTION #
+    /// Checks the typed number against the policy before parsing it, rejecting
+    /// explicit '+' signs or leading zeros with a dedicated error message, or removing
+    /// the spaces used to group digits (Ex: 1 000 000) if they are allowed.
+    ///
+    /// # EXAMPLES #
+    /// ```no_run
+    /// use quick_input::Prompt;
+    /// use quick_input::prompt::NumberPolicy;
+    /// let population: u64 = Prompt::new("Population: ")
+    ///     .value()
+    ///     .number_policy(NumberPolicy { allow_leading_zeros: false, allow_spaces: true, ..NumberPolicy::default() })
+    ///     .read();
+    /// ```
+    pub fn number_policy(mut self, policy: NumberPolicy) -> Self {
+        self.number_policy = Some(policy);
+        self
+    }
+
+    /// # ARGUMENTS #
+    /// 'places' (usize) - maximum number of decimal places.
+    ///
+    /// 'rounding' (Option<Rounding>) - how inputs with more decimal places are rounded,
+    /// or None to reject them. Must be set to Some(...) or None.
+    ///
+    /// # DESCRIPTION #
+    /// Limits the decimal places of the number typed by the user. Rounding is done on the
+    /// typed digits before parsing, so it is exact (Ex: 2.675 rounds half up to 2.68, even if
+    /// that number cannot be represented exactly by an f64). Scientific notation is not
+    /// accepted while the limit is set.
+    ///
+    /// # EXAMPLES #
+    /// ```no_run
+    /// use quick_input::Prompt;
+    /// use quick_input::prompt::Rounding;
+    /// let price: f64 = Prompt::new("Price: ")
+    ///     .value()
+    ///     .decimals(2, Some(Rounding::HalfEven))
+    ///     .read();
+    /// ```
+    pub fn decimals(mut self, places: usize, rounding: Option<Rounding>) -> Self {
+        self.decimals = Some((places, rounding));
+        self
+    }
+}
+
+impl<T: Display + 'static> Prompt<T, Value> {
+    /// # ARGUMENTS #
+    /// 'format' (EchoFormat) - how the value is printed. EchoFormat::default() prints
+    /// "Using 1,500,000".
+    ///
+    /// # DESCRIPTION #
+    /// Once a value is accepted, it is printed back to the user in its parsed and normalized form,
+    /// which helps catching magnitude mistakes (Ex: an extra zero) before the program proceeds.
+    ///
+    /// # EXAMPLES #
+    /// ```no_run
+    /// use quick_input::Prompt;
+    /// use quick_input::prompt::EchoFormat;
+    /// let timeout: u64 = Prompt::new("Timeout: ")
+    ///     .value()
+    ///     .echo(EchoFormat { unit: Some(String::from("ms")), ..EchoFormat::default() })
+    ///     .read();
+    /// ```
+    pub fn echo(mut self, format: EchoFormat) -> Self {
+        self.echo = Some(Box::new(move |value| {
+            let mut text = value.to_string();
+
+            if let Some(separator) = format.thousands_separator {
+                text = group_thousands(&text, separator);
+            }
+            if let Some(unit) = &format.unit {
+                text = format!("{text} {unit}");
+            }
+
+            format!("{}{text}", format.prefix)
+        }));
+        self
+    }
+}
+
+impl<T: Clone, S: sealed::Typed> Prompt<T, S> {
+    /// Prompts the user until a valid value is typed, which will then be returned.
+    ///
+    /// # RETURNS #
+    /// A value of type T provided by the user (a trimmed String for text prompts),
+    /// or the default value if the input was empty.
+    ///
+    /// # PANICS #
+    /// If max_attempts was set, the user used every attempt and there is no default value.
+    /// If the input ends before a value is typed, the EofPolicy set with set_eof_policy is
+    /// applied, which panics by default.
+    /// If the prompt is cancellable and the user cancels it (try_read handles it instead),
+    /// or if the timeout passes and there is no default value.
+    pub fn read(&self) -> T {
+        self.read_until(None, &mut 0).unwrap_or_else(|err| match err {
+            InputError::Eof => eof::reached(&self.msg),
+            InputError::Cancelled => panic!("The prompt '{}' was cancelled.", self.msg.trim()),
+            InputError::Timeout => panic!(
+                "The prompt '{}' was not answered in time and has no default value.",
+                self.msg.trim()
+            ),
+            err @ InputError::Io(_) => panic!("{err}"),
+            _ => panic!(
+                "Too many invalid answers to the prompt '{}', which has no default value.",
+                self.msg.trim()
+            ),
+        })
+    }
+
+    /// # DESCRIPTION #
+    /// Prompts the user once, like try_read does for plain values: instead of asking again,
+    /// an invalid answer is returned as an InputError telling whether it could not be parsed,
+    /// was out of range or was rejected by a validator. The help and the default value of the
+    /// Prompt work as in read.
+    ///
+    /// If max_attempts was set, the user is asked again after an invalid answer instead, and
+    /// InputError::MaxAttemptsExceeded is returned once every attempt is used (unless the
+    /// Prompt has a default value, which is returned then).
+    ///
+    /// # RETURNS #
+    /// The value typed by the user or the default value, or the InputError explaining why
+    /// no value could be read.
+    ///
+    /// # EXAMPLES #
+    /// ```no_run
+    /// use quick_input::{InputError, Prompt};
+    /// let level = Prompt::new("Level (1-5): ").value::<u8>().range(1..=5).try_read();
+    ///
+    /// match level {
+    ///     Ok(level) => println!("Level {level}"),
+    ///     Err(InputError::OutOfRange { expected, .. }) => eprintln!("The level must be {expected}."),
+    ///     Err(err) => eprintln!("{err}"),
+    /// }
+    /// ```
+    pub fn try_read(&self) -> Result<T, InputError> {
+        if dry_run::is_dry_run() {
+            return Ok(self.dry_run_value());
+        }
+        if self.max_attempts.is_some() {
+            return self.read_until(None, &mut 0);
+        }
+
+        let deadline = self.timeout.map(|timeout| Instant::now() + timeout);
+        let input = loop {
+            render::show_prompt(&self.msg, self.hidden, self.help.as_deref());
+            let mut input = String::new();
+            match self.read_answer(&mut input, None, deadline) {
+                Err(InputError::Timeout) => return self.timed_out(),
+                result => result?,
+            }
+
+            match (self.answer_text(&input), &self.help) {
+                ("?", Some(help)) => outln!("{help}"),
+                (input, _) => break input.to_string(),
+            }
+        };
+
+        if let (true, Some(default)) = (input.is_empty(), &self.default) {
+            return Ok(default.clone());
+        }
+
+        let mut input = self.normalized(&input);
+        match self.processed(&mut input, |input| self.evaluate(input)) {
+            Ok(value) => {
+                record_answer(&self.msg, &input, self.sensitive);
+                if let Some(echo) = &self.echo {
+                    outln!("{}", echo(&value));
+                }
+                Ok(value)
+            }
+            Err(rejections) => {
+                let raw = match self.sensitive {
+                    true => String::from("***"),
+                    false => input,
+                };
+                let first = rejections.into_iter().next();
+                let first = first.expect("Rejected answers have at least one reason.");
+                Err(first.into_error(raw))
+            }
+        }
+    }
+
+    /// Like read, but gives up once 'deadline' (if any) passes, returning InputError::Timeout
+    /// (the timeout of the Prompt is handled like in read), or once max_attempts (if any) is
+    /// reached without a default value, returning InputError::MaxAttemptsExceeded. Every
+    /// invalid attempt of the user is added to 'invalid_attempts'.
+    pub(crate) fn read_until(
+        &self,
+        deadline: Option<Instant>,
+        invalid_attempts: &mut usize,
+    ) -> Result<T, InputError> {
+        if dry_run::is_dry_run() {
+            return Ok(self.dry_run_value());
+        }
+
+        let timeout = self.timeout.map(|timeout| Instant::now() + timeout);
+        let deadline = match (deadline, timeout) {
+            (Some(deadline), Some(timeout)) => Some(deadline.min(timeout)),
+            (deadline, timeout) => deadline.or(timeout),
+        };
+
+        let value = match self.read_until_accepted(deadline, invalid_attempts) {
+            Err(InputError::MaxAttemptsExceeded) if self.default.is_some() => {
+                outln!("(too many invalid attempts, using the default value)");
+                return Ok(self.default.clone().expect("The default value was checked."));
+            }
+            // Otherwise, the deadline given by the caller (Ex: a Session budget) passed.
+            Err(InputError::Timeout) if timeout.is_some_and(|at| Instant::now() >= at) => {
+                return self.timed_out();
+            }
+            result => result?,
+        };
+
+        if let Some(echo) = &self.echo {
+            outln!("{}", echo(&value));
+        }
+
+        Ok(value)
+    }
+
+    fn read_until_accepted(
+        &self,
+        deadline: Option<Instant>,
+        invalid_attempts: &mut usize,
+    ) -> Result<T, InputError> {
+        let mut rejected: Option<String> = None;
+        let mut attempts = 0;
+
+        loop {
+            let mut input = String::new();
+
+            render::show_prompt(&self.msg, self.hidden, self.help.as_deref());
+            self.read_answer(&mut input, rejected.take().as_deref(), deadline)?;
+
+            let input = self.answer_text(&input);
+            if let (true, Some(help)) = (input == "?", &self.help) {
+                outln!("{help}");
+                continue;
+            }
+            if let (true, Some(default)) = (input.is_empty(), &self.default) {
+                return Ok(default.clone());
+            }
+
+            let mut input = self.normalized(input);
+            match self.processed(&mut input, |input| self.accept(input)) {
+                Ok(value) => {
+                    record_answer(&self.msg, &input, self.sensitive);
+                    return Ok(value);
+                }
+                Err(def_err_msg) => {
+                    let def_err_msg = self.with_example(def_err_msg);
+                    show_error_message(self.err_msg.as_deref(), &def_err_msg);
+                    rejected = Some(input);
+                    *invalid_attempts += 1;
+                    attempts += 1;
+                }
+            }
+
+            if self.max_attempts.is_some_and(|max| attempts >= max) {
+                return Err(InputError::MaxAttemptsExceeded);
+            }
+        }
+    }
+
+    /// Text of the answer read into 'input': trimmed, unless the answer is hidden, since
+    /// spaces may be part of it (Ex: a password), in which case only the line break is removed.
+    fn answer_text<'a>(&self, input: &'a str) -> &'a str {
+        match self.hidden {
+            true => strip_line_end(input),
+            false => input.trim(),
+        }
+    }
+
+    /// Accepts the input with 'accept' and runs the post-processors on it, unless
+    /// skip_post_processors was called or the answer is sensitive.
+    fn processed<E>(
+        &self,
+        input: &mut String,
+        accept: impl Fn(&str) -> Result<T, E>,
+    ) -> Result<T, E> {
+        match self.post_process && !self.sensitive {
+            true => postprocess::accept(&self.msg, input, accept),
+            false => accept(input),
+        }
+    }
+
+    /// Adds a valid answer to the default error message of prompts with a range or a
+    /// validator, so the user sees what is expected. Hidden prompts get no example.
+    fn with_example(&self, message: String) -> String {
+        if self.checks.is_empty() || self.err_msg.is_some() || self.hidden || self.sensitive {
+            return message;
+        }
+
+        let example = examples::find_example(&self.dry_run_hints, |input| self.evaluate(input));
+        examples::with_example(&message, example.as_deref())
+    }
+
+    /// Applies the normalizer (if any) to the input, asking the user first if required.
+    fn normalized(&self, input: &str) -> String {
+        let Some(normalizer) = &self.normalizer else {
+            return input.to_string();
+        };
+
+        let corrected = normalizer(input);
+        if corrected == input || !self.confirm_normalization || self.hidden {
+            return corrected;
+        }
+
+        let question = self
+            .message(|m| &m.did_you_mean, "Did you mean '{value}'? [Y/n]: ")
+            .replace("{value}", &corrected);
+        let accepted = read_until_valid(Some(&question), None, |answer| {
+            match answer.to_lowercase().as_str() {
+                "" | "y" | "yes" => Ok(true),
+                "n" | "no" => Ok(false),
+                _ => Err(self.message(
+                    |m| &m.yes_or_no,
+                    "Please answer yes or no (y / n).",
+                )),
+            }
+        });
+
+        match accepted {
+            true => corrected,
+            false => input.to_string(),
+        }
+    }
+
+    /// Returns the default value once the timeout passed, or InputError::Timeout without one.
+    fn timed_out(&self) -> Result<T, InputError> {
+        match &self.default {
+            Some(default) => {
+                outln!("(no answer in time, using the default value)");
+                Ok(default.clone())
+            }
+            None => {
+                outln!("(no answer in time)");
+                Err(InputError::Timeout)
+            }
+        }
+    }
+
+    /// Reads an answer into 'input', letting the user edit 'previous' (the rejected answer)
+    /// if edit_on_retry was called. Fails with InputError::Timeout if the deadline passes,
+    /// InputError::Cancelled if the user cancels the prompt and InputError::Eof if the
+    /// input ended.
+    fn read_answer(
+        &self,
+        input: &mut String,
+        previous: Option<&str>,
+        deadline: Option<Instant>,
+    ) -> Result<(), InputError> {
+        let read = match previous.and_then(|previous| self.edit(previous, input, deadline)) {
+            Some(read) => read,
+            None => self
+                .read_line(input, deadline)
+                .ok_or(InputError::Timeout)?,
+        };
+
+        match read {
+            Ok(0) => Err(InputError::Eof),
+            Ok(_) if self.cancel_word.as_deref() == Some(input.trim()) => {
+                Err(InputError::Cancelled)
+            }
+            Ok(_) => Ok(()),
+            // Only returned by the editor, when Ctrl+C is pressed on a cancellable prompt.
+            Err(err) if err.kind() == io::ErrorKind::Interrupted => Err(InputError::Cancelled),
+            Err(err) => Err(InputError::Io(err)),
+        }
+    }
+
+    fn edit(
+        &self,
+        previous: &str,
+        input: &mut String,
+        deadline: Option<Instant>,
+    ) -> Option<io::Result<usize>> {
+        if !self.edit_on_retry || self.hidden || deadline.is_some() {
+            return None;
+        }
+
+        editor::edit_line(&self.msg, previous, input, self.cancel_word.is_some())
+    }
+
+    fn read_line(
+        &self,
+        input: &mut String,
+        deadline: Option<Instant>,
+    ) -> Option<io::Result<usize>> {
+        if let Err(err) = reader::flush() {
+            return Some(Err(err));
+        }
+
+        let cancellable = self.cancel_word.is_some();
+        if deadline.is_none() {
+            if let Some(mask) = self.mask
+                && let Some(read) = editor::read_masked(mask, input, cancellable)
+            {
+                return Some(read);
+            }
+            // Ctrl+C can only be told apart from other keys when they are read one by one.
+            if cancellable
+                && !self.hidden
+                && let Some(read) = editor::edit_line(&self.msg, "", input, true)
+            {
+                return Some(read);
+            }
+        }
+
+        let _hidden = self.hidden.then(HiddenInput::start);
+
+        match deadline {
+            Some(deadline) => input::read_line_before(input, deadline),
+            None => Some(input::read_line(input)),
+        }
+    }
+
+    fn dry_run_value(&self) -> T {
+        match &self.default {
+            Some(default) => {
+                outln!("{}", self.msg);
+                default.clone()
+            }
+            None => dry_run::placeholder(Some(&self.msg), &self.dry_run_hints, |input| {
+                self.accept(input)
+            }),
+        }
+    }
+
+    pub(crate) fn accept(&self, input: &str) -> Result<T, String> {
+        self.evaluate(input).map_err(|rejections| {
+            rejections
+                .into_iter()
+                .map(Rejection::into_message)
+                .collect::<Vec<_>>()
+                .join("\n")
+        })
+    }
+
+    /// Parses the input and runs the checks, returning why it was rejected otherwise.
+    /// Only the first failing check is reported, unless all_errors() was called.
+    fn evaluate(&self, input: &str) -> Result<T, Vec<Rejection>> {
+        let value = self
+            .parse_input(input)
+            .map_err(|message| vec![Rejection::Parse(message)])?;
+
+        let mut rejections = Vec::new();
+        for (check, bounds) in &self.checks {
+            let Err(message) = check(&value) else {
+                continue;
+            };
+
+            rejections.push(match bounds {
+                Some(bounds) => Rejection::OutOfRange(message, bounds.clone()),
+                None => Rejection::Invalid(message),
+            });
+            if !self.all_errors {
+                break;
+            }
+        }
+
+        match rejections.is_empty() {
+            true => Ok(value),
+            false => Err(rejections),
+        }
+    }
+
+    fn parse_input(&self, input: &str) -> Result<T, String> {
+        let mut input = match &self.number_policy {
+            Some(policy) => policy.normalize(input)?,
+            None => input.to_string(),
+        };
+        if let Some((places, rounding)) = self.decimals {
+            input = limit_decimals(&input, places, rounding)?;
+        }
+
+        (self.parser)(&input).map_err(|default| self.message(|m| &m.invalid_value, &default))
+    }
+}
+
+/// Reason why a Prompt rejected an answer.
+enum Rejection {
+    Parse(String),
+    /// Error message and description of the bounds.
+    OutOfRange(String, String),
+    Invalid(String),
+}
+
+impl Rejection {
+    fn into_message(self) -> String {
+        match self {
+            Rejection::Parse(message)
+            | Rejection::OutOfRange(message, _)
+            | Rejection::Invalid(message) => message,
+        }
+    }
+
+    fn into_error(self, raw: String) -> InputError {
+        match self {
+            Rejection::Parse(_) => InputError::Parse { raw },
+            Rejection::OutOfRange(_, expected) => InputError::OutOfRange { raw, expected },
+            Rejection::Invalid(reason) => InputError::Invalid { raw, reason },
+        }
+    }
+}
+
+pub(crate) fn describe_bounds<T: Display>(range: &impl RangeBounds<T>) -> String {
+    match (range.start_bound(), range.end_bound()) {
+        (Bound::Included(start), Bound::Included(end)) => format!("between {start} and {end}"),
+        (Bound::Included(start), Bound::Excluded(end)) => {
+            format!("from {start} up to (but not including) {end}")
+        }
+        (Bound::Excluded(start), Bound::Included(end)) => {
+            format!("greater than {start} and at most {end}")
+        }
+        (Bound::Excluded(start), Bound::Excluded(end)) => {
+            format!("greater than {start} and less than {end}")
+        }
+        (Bound::Included(start), Bound::Unbounded) => format!("of at least {start}"),
+        (Bound::Excluded(start), Bound::Unbounded) => format!("greater than {start}"),
+        (Bound::Unbounded, Bound::Included(end)) => format!("of at most {end}"),
+        (Bound::Unbounded, Bound::Excluded(end)) => format!("less than {end}"),
+        (Bound::Unbounded, Bound::Unbounded) => String::from("of any size"),
+    }
+}
+
+/// Rounds (or rejects, if 'rounding' is None) a decimal number written with more than
+/// 'places' decimal places. Inputs which are not plain decimal numbers are returned as they are,
+/// so the parser reports them.
+fn limit_decimals(
+    number: &str,
+    places: usize,
+    rounding: Option<Rounding>,
+) -> Result<String, String> {
+    if number.contains(['e', 'E']) {
+        return Err(String::from(
+            "Please enter the number without scientific notation.",
+        ));
+    }
+
+    let (sign, unsigned) = match number.strip_prefix(['+', '-']) {
+        Some(unsigned) => (&number[..1], unsigned),
+        None => ("", number),
+    };
+    let (integer, fraction) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+
+    let is_plain = |digits: &str| digits.chars().all(|c| c.is_ascii_digit());
+    if fraction.len() <= places || !is_plain(integer) || !is_plain(fraction) {
+        return Ok(number.to_string());
+    }
+
+    let Some(rounding) = rounding else {
+        return Err(format!(
+            "Please enter a number with at most {places} decimal places."
+        ));
+    };
+
+    let mut digits: Vec<u8> = format!("{integer}{}", &fraction[..places]).into_bytes();
+    let dropped = &fraction.as_bytes()[places..];
+    let round_up = match rounding {
+        Rounding::Truncate => false,
+        Rounding::HalfUp => dropped[0] >= b'5',
+        Rounding::HalfEven => {
+            let odd = digits.last().is_some_and(|digit| (digit - b'0') % 2 == 1);
+            dropped[0] > b'5'
+                || (dropped[0] == b'5' && (dropped[1..].iter().any(|&d| d != b'0') || odd))
+        }
+    };
+
+    if round_up {
+        let mut carry = true;
+        for digit in digits.iter_mut().rev() {
+            if *digit == b'9' {
+                *digit = b'0';
+            } else {
+                *digit += 1;
+                carry = false;
+                break;
+            }
+        }
+        if carry {
+            digits.insert(0, b'1');
+        }
+    }
+
+    let digits = String::from_utf8(digits).expect("Digits are always ASCII.");
+    let (integer, fraction) = digits.split_at(digits.len() - places);
+    let integer = if integer.is_empty() { "0" } else { integer };
+
+    Ok(match fraction {
+        "" => format!("{sign}{integer}"),
+        fraction => format!("{sign}{integer}.{fraction}"),
+    })
+}
+
+/// Inserts 'separator' between every group of three digits of the integer part of a
+/// formatted number, leaving its sign and decimals untouched.
+fn group_thousands(number: &str, separator: char) -> String {
+    let digits_start = number
+        .find(|c: char| c.is_ascii_digit())
+        .unwrap_or(number.len());
+    let digits_end = number[digits_start..]
+        .find(|c: char| !c.is_ascii_digit())
+        .map_or(number.len(), |end| digits_start + end);
+    let digits = &number[digits_start..digits_end];
+
+    let mut grouped = String::from(&number[..digits_start]);
+    for (index, digit) in digits.chars().enumerate() {
+        if index > 0 && (digits.len() - index).is_multiple_of(3) {
+            grouped.push(separator);
+        }
+        grouped.push(digit);
+    }
+    grouped.push_str(&number[digits_end..]);
+
+    grouped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bounds_are_described() {
+        assert_eq!(describe_bounds(&(1..=10)), "between 1 and 10");
+        assert_eq!(describe_bounds(&(18..)), "of at least 18");
+        assert_eq!(describe_bounds(&(..0.5)), "less than 0.5");
+    }
+
+    #[test]
+    fn number_policy_normalizes_input() {
+        let strict = NumberPolicy {
+            allow_plus_sign: false,
+            allow_leading_zeros: false,
+            allow_spaces: false,
+            allow_scientific: false,
+        };
+        assert!(strict.normalize("2E5").is_err());
+        assert!(strict.normalize("+5").is_err());
+        assert!(strict.normalize("-007").is_err());
+        assert!(strict.normalize("1 000").is_err());
+        assert_eq!(strict.normalize("0.5"), Ok(String::from("0.5")));
+
+        let spaced = NumberPolicy {
+            allow_spaces: true,
+            ..NumberPolicy::default()
+        };
+        assert_eq!(spaced.normalize("+1 000 000"), Ok(String::from("+1000000")));
+    }
+
+    #[test]
+    fn decimals_are_rounded_exactly() {
+        let round = |number, rounding| limit_decimals(number, 2, Some(rounding)).unwrap();
+        assert_eq!(round("2.675", Rounding::HalfUp), "2.68");
+        assert_eq!(round("-2.345", Rounding::HalfUp), "-2.35");
+        assert_eq!(round("2.345", Rounding::HalfEven), "2.34");
+        assert_eq!(round("2.3451", Rounding::HalfEven), "2.35");
+        assert_eq!(round("9.999", Rounding::HalfUp), "10.00");
+        assert_eq!(round(".999", Rounding::Truncate), "0.99");
+        assert_eq!(
+            limit_decimals("2.5", 0, Some(Rounding::HalfEven)).unwrap(),
+            "2"
+        );
+        assert_eq!(limit_decimals("1.5", 2, None).unwrap(), "1.5");
+        assert!(limit_decimals("1.555", 2, None).is_err());
+    }
+
+    #[test]
+    fn all_errors_are_reported_together() {
+        let prompt = Prompt::new("")
+            .value::<u32>()
+            .range(10..)
+            .validate(|value| match value % 2 {
+                0 => Ok(()),
+                _ => Err(String::from("Must be even.")),
+            });
+
+        assert_eq!(
+            prompt.accept("3"),
+            Err(String::from("Please enter a value of at least 10."))
+        );
+        assert_eq!(
+            prompt.all_errors().accept("3"),
+            Err(String::from(
+                "Please enter a value of at least 10.\nMust be even."
+            ))
+        );
+    }
+
+    #[test]
+    fn rejections_are_told_apart() {
+        let prompt = Prompt::new("")
+            .value::<u32>()
+            .range(10..)
+            .validate(|value| match value % 2 {
+                0 => Ok(()),
+                _ => Err(String::from("Must be even.")),
+            });
+        let error = |input: &str| {
+            let first = prompt.evaluate(input).err().unwrap().into_iter().next();
+            first.unwrap().into_error(input.to_string())
+        };
+
+        assert!(matches!(error("ten"), InputError::Parse { raw } if raw == "ten"));
+        assert!(matches!(
+            error("3"),
+            InputError::OutOfRange { expected, .. } if expected == "of at least 10"
+        ));
+        assert!(matches!(
+            error("11"),
+            InputError::Invalid { reason, .. } if reason == "Must be even."
+        ));
+    }
+
+    #[test]
+    fn normalizer_is_applied_before_validation() {
+        let prompt = Prompt::new("")
+            .text()
+            .normalize(|text| text.trim_end_matches('.').to_string())
+            .validate(|text: &String| match text.ends_with('.') {
+                true => Err(String::from("Trailing dot.")),
+                false => Ok(()),
+            });
+
+        let input = prompt.normalized("Valencia..");
+        assert_eq!(prompt.accept(&input), Ok(String::from("Valencia")));
+    }
+
+    #[test]
+    fn thousands_are_grouped() {
+        assert_eq!(group_thousands("1500000", ','), "1,500,000");
+        assert_eq!(group_thousands("-1234.5678", '.'), "-1.234.5678");
+        assert_eq!(group_thousands("999", ','), "999");
+    }
+
+    #[test]
+    fn cancel_word_cancels_the_prompt() {
+        let mut mock = crate::testing::MockInput::new(["abc", ":q", "quit", "7"]);
+        let retried = mock.run(|| Prompt::new("Age: ").value::<u8>().cancellable().try_read());
+        let cancelled = mock.run(|| {
+            Prompt::new("Age: ")
+                .value::<u8>()
+                .cancel_word("quit")
+                .max_attempts(2)
+                .try_read()
+        });
+        let answered = mock.run(|| Prompt::new("Age: ").value::<u8>().cancellable().try_read());
+
+        assert!(matches!(retried, Err(InputError::Parse { .. })));
+        assert!(matches!(cancelled, Err(InputError::Cancelled)));
+        assert_eq!(answered.unwrap(), 7);
+    }
+}
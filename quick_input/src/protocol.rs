@@ -0,0 +1,239 @@
+//! Machine-readable protocol mode, used by programs driving a quick_input based CLI.
+
+use std::iter::Peekable;
+use std::str::Chars;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use crate::PromptView;
+
+static PROTOCOL_MODE: AtomicBool = AtomicBool::new(false);
+
+/// # ARGUMENTS #
+/// 'enabled' (bool) - whether protocol mode is enabled.
+///
+/// # DESCRIPTION #
+/// Enables or disables protocol mode for every reader of the library, so graphical wrappers
+/// or test harnesses can drive the program by exchanging JSON lines instead of text meant
+/// for people.
+///
+/// In protocol mode, every prompt is announced with a JSON object on its own line of stdout
+/// instead of its message, and every error message is sent the same way:
+///
+/// ```text
+/// {"type":"prompt","message":"Port: ","hidden":false}
+/// {"type":"error","message":"Please enter a valid number (16 bits)."}
+/// ```
+///
+/// Prompts with a help text include it in a "help" field. Answers are read from stdin one per
+/// line, either as a JSON string ("8080") or as an object with an "answer" field
+/// ({"answer": 8080}), whose value may also be a number or a boolean. Lines which are not
+/// valid JSON are used as they are. Any other output of the program (Ex: the options listed
+/// by a menu) is still printed as plain text, so lines which are not JSON objects should be
+/// shown to the user as they are.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::{read_u16, set_protocol_mode};
+/// set_protocol_mode(std::env::var("APP_PROTOCOL").is_ok());
+///
+/// let port = read_u16(Some("Port: "), None);
+/// ```
+pub fn set_protocol_mode(enabled: bool) {
+    PROTOCOL_MODE.store(enabled, Ordering::Relaxed);
+}
+
+/// # RETURNS #
+/// Whether protocol mode is enabled (see set_protocol_mode).
+pub fn is_protocol_mode() -> bool {
+    PROTOCOL_MODE.load(Ordering::Relaxed)
+}
+
+/// Returns the JSON object announcing a prompt in protocol mode.
+pub(crate) fn prompt_json(prompt: &PromptView) -> String {
+    let help = prompt
+        .help
+        .map(|help| format!(",\"help\":{}", json_string(help)))
+        .unwrap_or_default();
+
+    format!(
+        "{{\"type\":\"prompt\",\"message\":{},\"hidden\":{}{help}}}",
+        json_string(prompt.message),
+        prompt.hidden
+    )
+}
+
+/// Returns the JSON object reporting an error message in protocol mode.
+pub(crate) fn error_json(message: &str) -> String {
+    format!(
+        "{{\"type\":\"error\",\"message\":{}}}",
+        json_string(message)
+    )
+}
+
+/// # Arguments #
+/// 'line' (&str) - Line read from stdin in protocol mode.
+///
+/// # Description #
+/// Returns the answer sent in the line, which is a JSON string or an object with an "answer"
+/// field. Lines which are not valid JSON are returned as they are, without the line break.
+pub(crate) fn decode_answer(line: &str) -> String {
+    let line = line.trim_end_matches(['\n', '\r']);
+    let mut chars = line.trim().chars().peekable();
+
+    let answer = match chars.peek() {
+        Some('"') => parse_string(&mut chars),
+        Some('{') => parse_answer_object(&mut chars),
+        _ => None,
+    };
+
+    match answer {
+        Some(answer) if chars.all(char::is_whitespace) => answer,
+        _ => line.to_string(),
+    }
+}
+
+/// Writes 'text' as a JSON string, quotes included.
+pub(crate) fn json_string(text: &str) -> String {
+    let mut json = String::with_capacity(text.len() + 2);
+    json.push('"');
+    for c in text.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if c.is_control() => json.push_str(&format!("\\u{:04x}", u32::from(c))),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    json
+}
+
+/// Parses an object whose values are strings, numbers, booleans or null, returning its
+/// "answer" field as text.
+fn parse_answer_object(chars: &mut Peekable<Chars>) -> Option<String> {
+    chars.next();
+    let mut answer = None;
+
+    loop {
+        skip_whitespace(chars);
+        let key = parse_string(chars)?;
+        skip_whitespace(chars);
+        if chars.next()? != ':' {
+            return None;
+        }
+        skip_whitespace(chars);
+        let value = parse_scalar(chars)?;
+        if key == "answer" {
+            answer = Some(value);
+        }
+
+        skip_whitespace(chars);
+        match chars.next()? {
+            ',' => continue,
+            '}' => return answer,
+            _ => return None,
+        }
+    }
+}
+
+fn parse_scalar(chars: &mut Peekable<Chars>) -> Option<String> {
+    if chars.peek() == Some(&'"') {
+        return parse_string(chars);
+    }
+
+    let mut literal = String::new();
+    while let Some(&c) = chars.peek() {
+        if !(c.is_ascii_alphanumeric() || matches!(c, '-' | '+' | '.')) {
+            break;
+        }
+        literal.push(c);
+        chars.next();
+    }
+
+    match literal.as_str() {
+        "true" | "false" => Some(literal),
+        "null" => Some(String::new()),
+        _ if literal.parse::<f64>().is_ok() => Some(literal),
+        _ => None,
+    }
+}
+
+fn parse_string(chars: &mut Peekable<Chars>) -> Option<String> {
+    if chars.next()? != '"' {
+        return None;
+    }
+
+    let mut text = String::new();
+    loop {
+        match chars.next()? {
+            '"' => return Some(text),
+            '\\' => match chars.next()? {
+                'n' => text.push('\n'),
+                'r' => text.push('\r'),
+                't' => text.push('\t'),
+                'b' => text.push('\u{8}'),
+                'f' => text.push('\u{c}'),
+                'u' => text.push(parse_unicode_escape(chars)?),
+                escaped @ ('"' | '\\' | '/') => text.push(escaped),
+                _ => return None,
+            },
+            c => text.push(c),
+        }
+    }
+}
+
+/// Parses the XXXX of a \uXXXX escape, combining surrogate pairs (Ex: \ud83d\ude00).
+fn parse_unicode_escape(chars: &mut Peekable<Chars>) -> Option<char> {
+    let high = parse_hex(chars)?;
+    if !(0xD800..0xDC00).contains(&high) {
+        return char::from_u32(high);
+    }
+
+    if (chars.next()?, chars.next()?) != ('\\', 'u') {
+        return None;
+    }
+    let low = parse_hex(chars)?;
+    if !(0xDC00..0xE000).contains(&low) {
+        return None;
+    }
+    char::from_u32(0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00))
+}
+
+fn parse_hex(chars: &mut Peekable<Chars>) -> Option<u32> {
+    let digits: String = chars.by_ref().take(4).collect();
+    match digits.len() {
+        4 => u32::from_str_radix(&digits, 16).ok(),
+        _ => None,
+    }
+}
+
+fn skip_whitespace(chars: &mut Peekable<Chars>) {
+    while chars.next_if(|c| c.is_whitespace()).is_some() {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn answers_are_decoded() {
+        assert_eq!(decode_answer("\"8080\"\n"), "8080");
+        assert_eq!(decode_answer("\"a\\\"b\\u00e9\\ud83d\\ude00\""), "a\"bé😀");
+        assert_eq!(decode_answer("{\"id\": 3, \"answer\": \"yes\"}"), "yes");
+        assert_eq!(decode_answer("{\"answer\": 8080}"), "8080");
+        assert_eq!(decode_answer("plain text\r\n"), "plain text");
+        assert_eq!(decode_answer("\"unclosed"), "\"unclosed");
+        assert_eq!(decode_answer("{\"other\": 1}"), "{\"other\": 1}");
+    }
+
+    #[test]
+    fn strings_are_escaped() {
+        assert_eq!(
+            json_string("Say \"hi\"\n\u{1}"),
+            "\"Say \\\"hi\\\"\\n\\u0001\""
+        );
+    }
+}
@@ -0,0 +1,272 @@
+//! Readers using any input and output instead of stdin and stdout.
+
+use std::cell::{Ref, RefCell};
+use std::fmt;
+use std::io::{self, BufRead, Write};
+use std::rc::Rc;
+
+thread_local! {
+    /// Input and output of the Reader running on this thread, if any.
+    static ACTIVE: RefCell<Option<Box<dyn Streams>>> = const { RefCell::new(None) };
+}
+
+/// Input and output of a Reader (or a MockInput), without its type parameters.
+pub(crate) trait Streams {
+    fn read_line(&mut self, line: &mut String) -> io::Result<usize>;
+    fn write(&mut self, args: fmt::Arguments) -> io::Result<()>;
+    fn flush(&mut self) -> io::Result<()>;
+
+    /// Called with the message of every prompt shown, before it is written.
+    fn prompted(&mut self, _msg: &str) {}
+
+    /// Called with every error message shown, before it is written.
+    fn rejected(&mut self, _message: &str) {}
+}
+
+/// Input and output of a Reader, shared with it while it runs.
+struct Shared<R, W> {
+    input: Rc<RefCell<R>>,
+    output: Rc<RefCell<W>>,
+}
+
+impl<R: BufRead, W: Write> Streams for Shared<R, W> {
+    fn read_line(&mut self, line: &mut String) -> io::Result<usize> {
+        self.input.borrow_mut().read_line(line)
+    }
+
+    fn write(&mut self, args: fmt::Arguments) -> io::Result<()> {
+        self.output.borrow_mut().write_fmt(args)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.output.borrow_mut().flush()
+    }
+}
+
+/// Prints to the output of the Reader running on this thread, or to stdout. Used instead of
+/// print! by every reader of this crate.
+macro_rules! out {
+    ($($arg:tt)*) => {
+        $crate::reader::write_output(format_args!($($arg)*))
+    };
+}
+
+/// Like out!, adding a line break (println!).
+macro_rules! outln {
+    () => {
+        $crate::reader::write_output(format_args!("\n"))
+    };
+    ($($arg:tt)*) => {
+        $crate::reader::write_output(format_args!("{}\n", format_args!($($arg)*)))
+    };
+}
+
+pub(crate) use {out, outln};
+
+/// Runs the readers of this crate on any input and output (Ex: a Cursor, a socket or a PTY)
+/// instead of stdin and stdout, which allows testing interactive programs or embedding them.
+///
+/// The most common readers have a method of the same name. Any other reader, Prompt, Form or
+/// Session can be used through run(), which redirects everything called inside it.
+/// Terminal features (hidden input, line editing, raw mode) are disabled while a Reader runs,
+/// and so is the renderer set with set_renderer. To script the answers of a test and check
+/// which prompts were shown, see testing::MockInput.
+///
+/// # EXAMPLES #
+/// ```
+/// use quick_input::{Prompt, Reader, read_bool};
+/// use std::io::Cursor;
+///
+/// let mut reader = Reader::new(Cursor::new("forty\n42\nyes\ntrue\n"), Vec::new());
+/// assert_eq!(reader.read_i32(Some("Age: "), None), 42);
+///
+/// let answer: String = reader.run(|| Prompt::new("Continue? ").text().read());
+/// assert_eq!(answer, "yes");
+/// assert!(reader.run(|| read_bool(None, None)));
+///
+/// let (_, output) = reader.into_inner();
+/// assert!(String::from_utf8(output).unwrap().starts_with("Age: Please enter"));
+/// ```
+pub struct Reader<R, W> {
+    input: Rc<RefCell<R>>,
+    output: Rc<RefCell<W>>,
+}
+
+impl<R: BufRead + 'static, W: Write + 'static> Reader<R, W> {
+    /// # ARGUMENTS #
+    /// 'input' (R) - source of the lines typed by the user (Ex: Cursor::new("42\n")).
+    ///
+    /// 'output' (W) - destination of the prompts and messages (Ex: Vec::new(), io::sink()).
+    ///
+    /// # DESCRIPTION #
+    /// Creates a Reader using 'input' and 'output' instead of stdin and stdout.
+    pub fn new(input: R, output: W) -> Self {
+        Reader {
+            input: Rc::new(RefCell::new(input)),
+            output: Rc::new(RefCell::new(output)),
+        }
+    }
+
+    /// # ARGUMENTS #
+    /// 'f' (impl FnOnce() -> T) - code calling any reader, Prompt, Form or Session.
+    ///
+    /// # DESCRIPTION #
+    /// Runs 'f' with every reader of this crate using the input and output of the Reader.
+    /// Calls may be nested, in which case the innermost Reader is used.
+    ///
+    /// # RETURNS #
+    /// The value returned by 'f'.
+    pub fn run<T>(&mut self, f: impl FnOnce() -> T) -> T {
+        let streams = Shared {
+            input: self.input.clone(),
+            output: self.output.clone(),
+        };
+
+        let _active = activate(streams);
+        f()
+    }
+
+    /// Returns the output, to check what was written to it so far.
+    pub fn output(&self) -> Ref<'_, W> {
+        self.output.borrow()
+    }
+
+    /// Returns the input and the output of the Reader.
+    pub fn into_inner(self) -> (R, W) {
+        (unshare(self.input), unshare(self.output))
+    }
+}
+
+fn unshare<T>(shared: Rc<RefCell<T>>) -> T {
+    match Rc::try_unwrap(shared) {
+        Ok(cell) => cell.into_inner(),
+        Err(_) => unreachable!("The streams are only shared while run() is running."),
+    }
+}
+
+/// Restores the Reader which was running before run() was called, even if it panics.
+pub(crate) struct Activation(Option<Box<dyn Streams>>);
+
+/// Makes every reader on this thread use 'streams' until the returned guard is dropped.
+pub(crate) fn activate(streams: impl Streams + 'static) -> Activation {
+    Activation(ACTIVE.with(|active| active.replace(Some(Box::new(streams)))))
+}
+
+impl Drop for Activation {
+    fn drop(&mut self) {
+        ACTIVE.with(|active| *active.borrow_mut() = self.0.take());
+    }
+}
+
+/// Generates the methods of Reader mirroring the readers with the same arguments.
+macro_rules! mirror {
+    ($($name:ident($($arg:ident: $ty:ty),*) -> $ret:ty;)*) => {
+        impl<R: BufRead + 'static, W: Write + 'static> Reader<R, W> {
+            $(
+                #[doc = concat!("Works like ", stringify!($name), ", using the input and output of the Reader.")]
+                pub fn $name(&mut self, $($arg: $ty),*) -> $ret {
+                    self.run(|| crate::$name($($arg),*))
+                }
+            )*
+        }
+    };
+}
+
+mirror! {
+    read_string(msg: Option<&str>) -> String;
+    read_string_untrimmed(msg: Option<&str>) -> String;
+    read_password(msg: Option<&str>) -> String;
+    read_secret(msg: Option<&str>) -> crate::Secret;
+    read_password_confirmed(msg: Option<&str>, confirm_msg: Option<&str>, mismatch_msg: Option<&str>) -> String;
+    read_char(msg: Option<&str>) -> char;
+    read_bool(msg: Option<&str>, err_msg: Option<&str>) -> bool;
+    read_yes_no(msg: Option<&str>, err_msg: Option<&str>) -> bool;
+    read_i8(msg: Option<&str>, err_msg: Option<&str>) -> i8;
+    read_u8(msg: Option<&str>, err_msg: Option<&str>) -> u8;
+    read_i16(msg: Option<&str>, err_msg: Option<&str>) -> i16;
+    read_u16(msg: Option<&str>, err_msg: Option<&str>) -> u16;
+    read_i32(msg: Option<&str>, err_msg: Option<&str>) -> i32;
+    read_u32(msg: Option<&str>, err_msg: Option<&str>) -> u32;
+    read_i64(msg: Option<&str>, err_msg: Option<&str>) -> i64;
+    read_u64(msg: Option<&str>, err_msg: Option<&str>) -> u64;
+    read_i128(msg: Option<&str>, err_msg: Option<&str>) -> i128;
+    read_u128(msg: Option<&str>, err_msg: Option<&str>) -> u128;
+    read_isize(msg: Option<&str>, err_msg: Option<&str>) -> isize;
+    read_usize(msg: Option<&str>, err_msg: Option<&str>) -> usize;
+    read_f32(msg: Option<&str>, err_msg: Option<&str>) -> f32;
+    read_f64(msg: Option<&str>, err_msg: Option<&str>) -> f64;
+}
+
+/// Whether a Reader is running on this thread, in which case stdin and stdout are not used.
+pub(crate) fn is_active() -> bool {
+    ACTIVE.with(|active| active.borrow().is_some())
+}
+
+/// Reads a line from the running Reader, returning None if there is none.
+pub(crate) fn read_line(line: &mut String) -> Option<io::Result<usize>> {
+    ACTIVE.with(|active| {
+        active
+            .borrow_mut()
+            .as_mut()
+            .map(|streams| streams.read_line(line))
+    })
+}
+
+/// Tells the running Reader (if any) that a prompt is being shown.
+pub(crate) fn note_prompt(msg: &str) {
+    ACTIVE.with(|active| {
+        if let Some(streams) = active.borrow_mut().as_mut() {
+            streams.prompted(msg);
+        }
+    });
+}
+
+/// Tells the running Reader (if any) that an error message is being shown.
+pub(crate) fn note_error(message: &str) {
+    ACTIVE.with(|active| {
+        if let Some(streams) = active.borrow_mut().as_mut() {
+            streams.rejected(message);
+        }
+    });
+}
+
+/// Writes to the running Reader, or to stdout. Failing to write panics, like print! does.
+pub(crate) fn write_output(args: fmt::Arguments) {
+    let written = ACTIVE.with(|active| {
+        active
+            .borrow_mut()
+            .as_mut()
+            .map(|streams| streams.write(args))
+    });
+
+    written
+        .unwrap_or_else(|| io::stdout().write_fmt(args))
+        .expect("Unable to write the output.");
+}
+
+/// Flushes the output of the running Reader, or stdout.
+pub(crate) fn flush() -> io::Result<()> {
+    ACTIVE
+        .with(|active| active.borrow_mut().as_mut().map(|streams| streams.flush()))
+        .unwrap_or_else(|| io::stdout().flush())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Prompt;
+    use std::io::Cursor;
+
+    #[test]
+    fn prompts_and_errors_go_to_the_output() {
+        let mut reader = Reader::new(Cursor::new("300\n7\n"), Vec::new());
+        let value: u8 = reader.run(|| Prompt::new("Level: ").value().range(1..=9).read());
+
+        assert_eq!(value, 7);
+        assert!(!is_active());
+        assert_eq!(
+            String::from_utf8_lossy(&reader.output()),
+            "Level: Please enter a valid value (e.g. 5).\n---\nLevel: "
+        );
+    }
+}
@@ -0,0 +1,62 @@
+//! Central point every accepted answer goes through before reaching a sink.
+//!
+//! Sinks (currently the session history used for suggestions) are private to this module,
+//! so answers marked as sensitive are redacted consistently instead of per sink.
+
+use std::sync::Mutex;
+
+/// Text stored instead of sensitive answers.
+pub(crate) const REDACTED: &str = "***";
+
+/// Maximum number of answers remembered by the session history.
+const HISTORY_CAPACITY: usize = 100;
+
+/// Message of the prompt and answer given to it, from the oldest to the most recent.
+static HISTORY: Mutex<Vec<(String, String)>> = Mutex::new(Vec::new());
+
+/// # Arguments #
+/// 'message' (&str) - Message of the prompt which was answered.
+///
+/// 'answer' (&str) - Answer given by the user.
+///
+/// 'sensitive' (bool) - Whether the answer must be replaced by REDACTED in every sink.
+///
+/// # Description #
+/// Records an accepted answer in every sink. Empty answers are not recorded.
+pub(crate) fn record_answer(message: &str, answer: &str, sensitive: bool) {
+    if answer.is_empty() {
+        return;
+    }
+
+    let answer = if sensitive { REDACTED } else { answer };
+    let mut history = HISTORY
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+    history.retain(|(asked, given)| asked != message || given != answer);
+    history.push((message.to_string(), answer.to_string()));
+
+    if history.len() > HISTORY_CAPACITY {
+        history.remove(0);
+    }
+}
+
+/// # Arguments #
+/// 'message' (&str) - Message of the prompt whose answers are returned.
+///
+/// # Description #
+/// Returns the answers given to prompts with this message stored in the session history,
+/// from the most recent to the oldest. Redacted answers are left out, since they cannot be
+/// suggested.
+pub(crate) fn recent_answers(message: &str) -> Vec<String> {
+    let history = HISTORY
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+    history
+        .iter()
+        .rev()
+        .filter(|(asked, answer)| asked == message && answer != REDACTED)
+        .map(|(_, answer)| answer.clone())
+        .collect()
+}
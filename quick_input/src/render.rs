@@ -0,0 +1,152 @@
+//! Rendering of prompts, which can be handed over to a graphical interface.
+
+use std::sync::RwLock;
+
+use crate::{idle, protocol};
+use crate::reader::{self, out, outln};
+
+/// Renderer set with set_renderer, used instead of the terminal.
+static RENDERER: RwLock<Option<Box<dyn PromptRenderer>>> = RwLock::new(None);
+
+/// Description of a prompt about to be answered, given to PromptRenderer::show_prompt.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct PromptView<'a> {
+    /// Message of the prompt (Ex: "Port: "), which may be empty.
+    pub message: &'a str,
+    /// Whether the answer should be hidden while typed (Ex: a password).
+    pub hidden: bool,
+    /// Help text of the prompt, if it has one.
+    pub help: Option<&'a str>,
+}
+
+/// Presents prompts to the user and collects their answers, replacing the terminal.
+///
+/// Once set with set_renderer, every reader and Prompt (and therefore every Form and Session)
+/// goes through it, so the same code can ask its questions in a graphical dialog (Ex: an egui
+/// or tauri window) or answer them from a test. Validation, defaults and retries work the same:
+/// for every attempt, show_prompt is called and then collect_answer, and if the answer is
+/// rejected, show_error is called before the next attempt.
+///
+/// Other messages printed by some readers (Ex: the options listed by a menu) are still
+/// printed to stdout, and time limits are not applied while a renderer is set.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::{read_u16, set_renderer, PromptRenderer, PromptView};
+/// use std::sync::Mutex;
+///
+/// /// Answers every prompt from a list, as a test would.
+/// struct Scripted(Mutex<Vec<&'static str>>);
+///
+/// impl PromptRenderer for Scripted {
+///     fn show_prompt(&self, prompt: &PromptView) {
+///         println!("asked: {}", prompt.message);
+///     }
+///
+///     fn show_error(&self, message: &str) {
+///         println!("rejected: {message}");
+///     }
+///
+///     fn collect_answer(&self) -> Option<String> {
+///         self.0.lock().unwrap().pop().map(String::from)
+///     }
+/// }
+///
+/// set_renderer(Scripted(Mutex::new(vec!["8080", "not a port"])));
+/// assert_eq!(read_u16(Some("Port: "), None), 8080);
+/// ```
+pub trait PromptRenderer: Send + Sync {
+    /// Shows the prompt that is about to be answered.
+    fn show_prompt(&self, prompt: &PromptView);
+
+    /// Shows why the last answer was rejected.
+    fn show_error(&self, message: &str);
+
+    /// Waits for the answer to the last prompt shown, returning None if no answer can be given
+    /// anymore (Ex: the window was closed), which is handled like a closed stdin.
+    fn collect_answer(&self) -> Option<String>;
+}
+
+/// # ARGUMENTS #
+/// 'renderer' (impl PromptRenderer) - renderer used by every prompt from now on.
+///
+/// # DESCRIPTION #
+/// Replaces the terminal (or the protocol mode) with a custom renderer for every prompt,
+/// until reset_renderer is called.
+pub fn set_renderer(renderer: impl PromptRenderer + 'static) {
+    *RENDERER
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner()) = Some(Box::new(renderer));
+}
+
+/// Removes the renderer set with set_renderer, so prompts are shown on the terminal again.
+pub fn reset_renderer() {
+    *RENDERER
+        .write()
+        .unwrap_or_else(|poisoned| poisoned.into_inner()) = None;
+}
+
+/// Calls 'f' with the renderer set with set_renderer, returning None if there is none
+/// or a Reader is running.
+pub(crate) fn with_renderer<R>(f: impl FnOnce(&dyn PromptRenderer) -> R) -> Option<R> {
+    if reader::is_active() {
+        return None;
+    }
+
+    let renderer = RENDERER
+        .read()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    renderer.as_deref().map(f)
+}
+
+/// Whether a renderer was set with set_renderer, in which case the terminal is not used.
+pub(crate) fn is_custom() -> bool {
+    with_renderer(|_| ()).is_some()
+}
+
+/// # Arguments #
+/// 'msg' (&str) - Message of the prompt.
+///
+/// 'hidden' (bool) - Whether the answer is hidden while typed.
+///
+/// 'help' (Option<&str>) - Help text of the prompt, if any.
+///
+/// # Description #
+/// Function shared by the readers to show a prompt, through the renderer if one is set,
+/// as a JSON object in protocol mode, or else by printing its message as it is.
+pub(crate) fn show_prompt(msg: &str, hidden: bool, help: Option<&str>) {
+    let prompt = PromptView {
+        message: msg,
+        hidden,
+        help,
+    };
+
+    if with_renderer(|renderer| renderer.show_prompt(&prompt)).is_some() {
+        return;
+    }
+
+    if protocol::is_protocol_mode() {
+        outln!("{}", protocol::prompt_json(&prompt));
+        let _ = reader::flush();
+    } else {
+        idle::remember_prompt(msg);
+        reader::note_prompt(msg);
+        out!("{msg}");
+    }
+}
+
+/// Shows an error message like show_prompt shows prompts.
+pub(crate) fn show_error(message: &str) {
+    if with_renderer(|renderer| renderer.show_error(message)).is_some() {
+        return;
+    }
+
+    if protocol::is_protocol_mode() {
+        outln!("{}", protocol::error_json(message));
+    } else {
+        reader::note_error(message);
+        outln!("{message}");
+        outln!("---");
+    }
+}
@@ -0,0 +1,39 @@
+//! Delay applied after every rejected answer.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::thread;
+use std::time::Duration;
+
+/// Delay in nanoseconds, 0 meaning no delay.
+static RETRY_DELAY: AtomicU64 = AtomicU64::new(0);
+
+/// # ARGUMENTS #
+/// 'delay' (Duration) - time to wait after an invalid answer before prompting again.
+/// Duration::ZERO (the default) disables the delay.
+///
+/// # DESCRIPTION #
+/// Makes every reader wait a little after rejecting an answer. Users typing at a terminal
+/// barely notice a short delay, but a program whose input is piped from a file full of
+/// invalid lines no longer floods the terminal with thousands of error messages per second.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::{read_u16, set_retry_delay};
+/// use std::time::Duration;
+/// set_retry_delay(Duration::from_millis(200));
+///
+/// let port = read_u16(Some("Port: "), None);
+/// ```
+pub fn set_retry_delay(delay: Duration) {
+    let nanos = u64::try_from(delay.as_nanos()).unwrap_or(u64::MAX);
+    RETRY_DELAY.store(nanos, Ordering::Relaxed);
+}
+
+/// # Description #
+/// Private function sleeping for the delay set with set_retry_delay, if any.
+pub(crate) fn wait_before_retry() {
+    let nanos = RETRY_DELAY.load(Ordering::Relaxed);
+    if nanos > 0 {
+        thread::sleep(Duration::from_nanos(nanos));
+    }
+}
@@ -0,0 +1,118 @@
+//! Summaries of the collected answers, shown before asking for a final confirmation.
+
+use std::fmt::Display;
+
+use crate::tty;
+use crate::reader::out;
+
+/// Width used when the terminal width is unknown (Ex: output redirected to a file).
+const DEFAULT_WIDTH: usize = 80;
+
+/// Separator printed between both columns.
+const SEPARATOR: &str = " : ";
+
+/// # ARGUMENTS #
+/// 'rows' (impl IntoIterator<Item = (K, V)>) - keys and values to show, such as
+/// answers.iter() from a Form or the entries of a HashMap.
+///
+/// # DESCRIPTION #
+/// Prints the rows as a two-column table with the values aligned, fitting the
+/// width of the terminal. Keys and values too long to fit are truncated with '…'.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::{print_review, FieldKind, Form};
+/// let answers = Form::new()
+///     .field("name", "Project name: ", FieldKind::Text)
+///     .field("port", "Port: ", FieldKind::Unsigned)
+///     .run();
+///
+/// print_review(answers.iter());
+/// ```
+pub fn print_review<K: Display, V: Display>(rows: impl IntoIterator<Item = (K, V)>) {
+    let width = tty::terminal_width().unwrap_or(DEFAULT_WIDTH);
+    out!("{}", render_review(rows, width));
+}
+
+/// # ARGUMENTS #
+/// 'rows' (impl IntoIterator<Item = (K, V)>) - keys and values to show, such as
+/// answers.iter() from a Form or the entries of a HashMap.
+///
+/// 'width' (usize) - maximum number of characters of every line.
+///
+/// # DESCRIPTION #
+/// Renders the rows as a two-column table with the values aligned. The key column takes
+/// at most a third of the width, and keys or values too long to fit are truncated with '…'.
+/// Line breaks inside values are shown as spaces, so every row takes a single line.
+///
+/// # RETURNS #
+/// A String with one line per row, each one ending with a line break.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::render_review;
+/// use std::collections::HashMap;
+/// let settings = HashMap::from([("host", "localhost"), ("port", "8080")]);
+///
+/// println!("{}", render_review(&settings, 40));
+/// ```
+pub fn render_review<K: Display, V: Display>(
+    rows: impl IntoIterator<Item = (K, V)>,
+    width: usize,
+) -> String {
+    let rows: Vec<(String, String)> = rows
+        .into_iter()
+        .map(|(key, value)| (single_line(&key), single_line(&value)))
+        .collect();
+
+    let longest_key = rows
+        .iter()
+        .map(|(key, _)| key.chars().count())
+        .max()
+        .unwrap_or(0);
+    let key_width = longest_key.min(width / 3).max(1);
+    let value_width = width.saturating_sub(key_width + SEPARATOR.len()).max(1);
+
+    rows.iter()
+        .map(|(key, value)| {
+            let line = format!(
+                "{:<key_width$}{SEPARATOR}{}",
+                truncate(key, key_width),
+                truncate(value, value_width)
+            );
+            format!("{}\n", line.trim_end())
+        })
+        .collect()
+}
+
+fn single_line(value: &impl Display) -> String {
+    value.to_string().lines().collect::<Vec<_>>().join(" ")
+}
+
+fn truncate(text: &str, width: usize) -> String {
+    if text.chars().count() <= width {
+        return text.to_string();
+    }
+
+    let mut truncated: String = text.chars().take(width - 1).collect();
+    truncated.push('…');
+    truncated
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rows_are_aligned_and_truncated() {
+        let rows = [
+            ("name", "demo"),
+            ("description", "a rather long description"),
+        ];
+
+        assert_eq!(
+            render_review(rows, 30),
+            "name       : demo\ndescripti… : a rather long de…\n"
+        );
+    }
+}
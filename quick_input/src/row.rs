@@ -0,0 +1,291 @@
+//! Readers for several values typed on a single line (Ex: 1 2 3).
+
+use std::str::FromStr;
+
+use crate::{dry_run, read_until_valid};
+
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as the input prompt. Must be set to Some("...") or None.
+///
+/// 'err_msg' (Option<&str>) - an optional error message which will be printed
+/// if the user inputs an invalid value. Must be set to Some("...") or None.
+///
+/// 'separator' (&str) - text between the values (Ex: ","). If it is blank (Ex: " "), the
+/// values are separated by any amount of whitespace.
+///
+/// # DESCRIPTION #
+/// Prompts the user to type a list of values on a single line, every value being parsed as T.
+/// Spaces around values are ignored, and an empty line is an empty list.
+/// In case any value is invalid, the error message will point at it and the user will be
+/// prompted to try again.
+///
+/// Provides an information message on the same line as the prompt if Some("...")
+/// is provided, and just the prompt if None is provided.
+///
+/// If err_msg is set to None, a default message explaining the problem will be shown.
+///
+/// # RETURNS #
+/// A Vec<T> with the values in the order they were typed.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::read_vec;
+/// let numbers: Vec<i64> = read_vec(Some("Numbers: "), None, " ");
+///
+/// let tags: Vec<String> = read_vec(Some("Tags (a, b, ...): "), None, ",");
+/// ```
+pub fn read_vec<T: FromStr>(msg: Option<&str>, err_msg: Option<&str>, separator: &str) -> Vec<T> {
+    read_until_valid(msg, err_msg, |input| parse_values(input, separator))
+}
+
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as the input prompt. Must be set to Some("...") or None.
+///
+/// 'err_msg' (Option<&str>) - an optional error message which will be printed
+/// if the user inputs an invalid value. Must be set to Some("...") or None.
+///
+/// 'n' (usize) - number of values the user must type.
+///
+/// # DESCRIPTION #
+/// Prompts the user to type exactly n values on a single line, separated by whitespace,
+/// every value being parsed as T. In case there are more or fewer values, or any value
+/// is invalid, the user will be prompted to try again.
+///
+/// Provides an information message on the same line as the prompt if Some("...")
+/// is provided, and just the prompt if None is provided.
+///
+/// If err_msg is set to None, a default message explaining the problem will be shown.
+///
+/// # RETURNS #
+/// A Vec<T> with the n values in the order they were typed.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::read_n;
+/// let row: Vec<f64> = read_n(Some("Row (3 numbers): "), None, 3);
+/// ```
+pub fn read_n<T: FromStr>(msg: Option<&str>, err_msg: Option<&str>, n: usize) -> Vec<T> {
+    read_exactly(msg, err_msg, n, " ", |input| parse_exactly(input, n, " "))
+}
+
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as the input prompt. Must be set to Some("...") or None.
+///
+/// 'err_msg' (Option<&str>) - an optional error message which will be printed
+/// if the user inputs an invalid value. Must be set to Some("...") or None.
+///
+/// # DESCRIPTION #
+/// Works like read_n, with the number of values given by the length of the array.
+///
+/// # RETURNS #
+/// An array with the N values in the order they were typed.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::read_array;
+/// let [x, y]: [i32; 2] = read_array(Some("Coordinates (x y): "), None);
+///
+/// let rgb = read_array::<u8, 3>(Some("Color (r g b): "), None);
+/// ```
+pub fn read_array<T: FromStr, const N: usize>(msg: Option<&str>, err_msg: Option<&str>) -> [T; N] {
+    read_exactly(msg, err_msg, N, " ", |input| {
+        parse_exactly(input, N, " ")?
+            .try_into()
+            .map_err(|_| describe_count(N))
+    })
+}
+
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as the input prompt. Must be set to Some("...") or None.
+///
+/// 'err_msg' (Option<&str>) - an optional error message which will be printed
+/// if the user inputs an invalid value. Must be set to Some("...") or None.
+///
+/// 'separator' (&str) - text between both values (Ex: ";"). If it is blank (Ex: " "), the
+/// values are separated by any amount of whitespace.
+///
+/// # DESCRIPTION #
+/// Prompts the user to type two values of different types on a single line (Ex: Ann;32),
+/// parsed as A and B. Spaces around values are ignored. In case there are more or fewer
+/// values, or any value is invalid, the user will be prompted to try again.
+///
+/// Provides an information message on the same line as the prompt if Some("...")
+/// is provided, and just the prompt if None is provided.
+///
+/// If err_msg is set to None, a default message explaining the problem will be shown.
+///
+/// # RETURNS #
+/// A tuple (A, B) with the values in the order they were typed.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::read_pair;
+/// let (name, age): (String, u8) = read_pair(Some("Name;age: "), None, ";");
+///
+/// let (x, y) = read_pair::<f64, f64>(Some("Point (x y): "), None, " ");
+/// ```
+pub fn read_pair<A: FromStr, B: FromStr>(
+    msg: Option<&str>,
+    err_msg: Option<&str>,
+    separator: &str,
+) -> (A, B) {
+    read_exactly(msg, err_msg, 2, separator, |input| {
+        match split_values(input, separator).as_slice() {
+            [a, b] => Ok((parse_value(a, 0)?, parse_value(b, 1)?)),
+            _ => Err(describe_count(2)),
+        }
+    })
+}
+
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as the input prompt. Must be set to Some("...") or None.
+///
+/// 'err_msg' (Option<&str>) - an optional error message which will be printed
+/// if the user inputs an invalid value. Must be set to Some("...") or None.
+///
+/// 'separator' (&str) - text between the values (Ex: ","). If it is blank (Ex: " "), the
+/// values are separated by any amount of whitespace.
+///
+/// # DESCRIPTION #
+/// Works like read_pair, with three values parsed as A, B and C.
+///
+/// # RETURNS #
+/// A tuple (A, B, C) with the values in the order they were typed.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::read_triple;
+/// let (name, year, rating): (String, u16, f32) =
+///     read_triple(Some("Title, year, rating: "), None, ",");
+/// ```
+pub fn read_triple<A: FromStr, B: FromStr, C: FromStr>(
+    msg: Option<&str>,
+    err_msg: Option<&str>,
+    separator: &str,
+) -> (A, B, C) {
+    read_exactly(msg, err_msg, 3, separator, |input| {
+        match split_values(input, separator).as_slice() {
+            [a, b, c] => Ok((parse_value(a, 0)?, parse_value(b, 1)?, parse_value(c, 2)?)),
+            _ => Err(describe_count(3)),
+        }
+    })
+}
+
+/// # Arguments #
+/// 'input' (&str) - Trimmed input typed by the user.
+///
+/// 'separator' (&str) - Text between the values, or a blank text for any whitespace.
+///
+/// # Description #
+/// Private function splitting the input on the separator and parsing every value as T.
+/// The error message names the first invalid value and its position.
+fn parse_values<T: FromStr>(input: &str, separator: &str) -> Result<Vec<T>, String> {
+    split_values(input, separator)
+        .iter()
+        .enumerate()
+        .map(|(index, value)| parse_value(value, index))
+        .collect()
+}
+
+/// Parses exactly 'n' values, like parse_values.
+fn parse_exactly<T: FromStr>(input: &str, n: usize, separator: &str) -> Result<Vec<T>, String> {
+    if split_values(input, separator).len() != n {
+        return Err(describe_count(n));
+    }
+    parse_values(input, separator)
+}
+
+/// Splits the input on the separator (or on whitespace if it is blank), trimming every value.
+/// An empty input has no values.
+fn split_values<'a>(input: &'a str, separator: &str) -> Vec<&'a str> {
+    if input.is_empty() {
+        Vec::new()
+    } else if separator.trim().is_empty() {
+        input.split_whitespace().collect()
+    } else {
+        input.split(separator).map(str::trim).collect()
+    }
+}
+
+fn parse_value<T: FromStr>(value: &str, index: usize) -> Result<T, String> {
+    value
+        .parse()
+        .map_err(|_| format!("'{value}' (value {}) is not a valid value.", index + 1))
+}
+
+/// Reads a line of 'n' values with read_until_valid. A dry run answers with 'n' zeroes (or
+/// 'n' times false), since the usual placeholders are single values.
+fn read_exactly<T>(
+    msg: Option<&str>,
+    err_msg: Option<&str>,
+    n: usize,
+    separator: &str,
+    parse: impl Fn(&str) -> Result<T, String>,
+) -> T {
+    if dry_run::is_dry_run() {
+        let separator = match separator.trim() {
+            "" => " ",
+            _ => separator,
+        };
+        let hints = ["0", "false"].map(|value| vec![value; n].join(separator));
+        return dry_run::placeholder(msg, &hints, parse);
+    }
+
+    read_until_valid(msg, err_msg, parse)
+}
+
+fn describe_count(n: usize) -> String {
+    match n {
+        1 => String::from("Please enter exactly 1 value."),
+        n => format!("Please enter exactly {n} values."),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::MockInput;
+
+    #[test]
+    fn values_are_split_and_parsed() {
+        assert_eq!(parse_values::<i32>("1  2\t3", " "), Ok(vec![1, 2, 3]));
+        assert_eq!(parse_values::<i32>("4, 5,6", ","), Ok(vec![4, 5, 6]));
+        assert_eq!(parse_values::<i32>("", ","), Ok(Vec::new()));
+        assert_eq!(
+            parse_values::<i32>("4,x,6", ","),
+            Err(String::from("'x' (value 2) is not a valid value."))
+        );
+    }
+
+    #[test]
+    fn the_number_of_values_is_checked() {
+        let mut mock = MockInput::new(["1 2", "1 2 3 4", "1 x 3", "1 2 3", "4 5"]);
+
+        assert_eq!(
+            mock.run(|| read_n::<i32>(Some("Row: "), None, 3)),
+            [1, 2, 3]
+        );
+        assert_eq!(mock.run(|| read_array::<u8, 2>(None, None)), [4, 5]);
+        mock.assert_prompted_times("Row: ", 4);
+        mock.assert_rejected("Please enter exactly 3 values.");
+        mock.assert_rejected("'x' (value 2) is not a valid value.");
+    }
+
+    #[test]
+    fn tuples_have_values_of_different_types() {
+        let mut mock = MockInput::new(["Ann", "Ann; old", "Ann Lee ; 32", "1 2 x"]);
+
+        let (name, age): (String, u8) = mock.run(|| read_pair(None, None, ";"));
+        assert_eq!((name.as_str(), age), ("Ann Lee", 32));
+        mock.assert_rejected("Please enter exactly 2 values.");
+        mock.assert_rejected("'old' (value 2) is not a valid value.");
+
+        let triple: (i32, f64, char) = mock.run(|| read_triple(None, None, " "));
+        assert_eq!(triple, (1, 2.0, 'x'));
+    }
+}
@@ -0,0 +1,194 @@
+//! Times of the day and readers for schedules (Ex: opening hours).
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{dry_run, read_until_valid};
+
+/// Schedule used as the dry-run answer of read_paired_ranges.
+const DRY_RUN_SCHEDULE: &str = "09:00-17:00";
+
+/// A time of the day with minute precision, from 00:00 to 23:59.
+/// Times are ordered chronologically and parsed from H:MM or HH:MM / displayed as HH:MM.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Time {
+    hour: u8,
+    minute: u8,
+}
+
+impl Time {
+    /// # ARGUMENTS #
+    /// 'hour' (u8) - hour, from 0 to 23.
+    ///
+    /// 'minute' (u8) - minute, from 0 to 59.
+    ///
+    /// # RETURNS #
+    /// The Time, or None if it does not exist (Ex: 24:00).
+    pub fn new(hour: u8, minute: u8) -> Option<Time> {
+        (hour < 24 && minute < 60).then_some(Time { hour, minute })
+    }
+
+    /// Hour of the time, from 0 to 23.
+    pub fn hour(self) -> u8 {
+        self.hour
+    }
+
+    /// Minute of the time, from 0 to 59.
+    pub fn minute(self) -> u8 {
+        self.minute
+    }
+
+    /// # RETURNS #
+    /// The number of minutes from this time to 'other', which is negative if 'other' is earlier.
+    pub fn minutes_until(self, other: Time) -> i32 {
+        other.minutes_since_midnight() - self.minutes_since_midnight()
+    }
+
+    fn minutes_since_midnight(self) -> i32 {
+        i32::from(self.hour) * 60 + i32::from(self.minute)
+    }
+}
+
+impl fmt::Display for Time {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02}:{:02}", self.hour, self.minute)
+    }
+}
+
+impl FromStr for Time {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Time, String> {
+        let format_error = || format!("'{input}' is not a time in HH:MM format (Ex: 09:30).");
+
+        let (hour, minute) = input.split_once(':').ok_or_else(format_error)?;
+        let digits = |text: &str, lens: &[usize]| {
+            lens.contains(&text.len()) && text.bytes().all(|byte| byte.is_ascii_digit())
+        };
+        if !digits(hour, &[1, 2]) || !digits(minute, &[2]) {
+            return Err(format_error());
+        }
+
+        let (hour, minute) = (
+            hour.parse().map_err(|_| format_error())?,
+            minute.parse().map_err(|_| format_error())?,
+        );
+
+        Time::new(hour, minute).ok_or_else(|| format!("The time {input} does not exist."))
+    }
+}
+
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as the input prompt. Must be set to Some("...") or None.
+///
+/// 'err_msg' (Option<&str>) - an optional error message which will be printed
+/// if the user inputs an invalid value. Must be set to Some("...") or None.
+///
+/// # DESCRIPTION #
+/// Prompts the user to type one or more time ranges of the same day, separated by commas
+/// (Ex: 09:00-12:30, 14:00-18:00), which suits opening hours and booking slots.
+/// Every range must end after it starts, and the ranges must be in chronological order
+/// without overlapping, although one may start right when the previous one ends.
+/// In case the user writes an invalid value, they will be prompted to try again.
+///
+/// Provides an information message on the same line as the prompt if Some("...")
+/// is provided, and just the prompt if None is provided.
+///
+/// If err_msg is set to None, a default message explaining the problem will be shown.
+///
+/// # RETURNS #
+/// A Vec<(Time, Time)> with the start and the end of every range, in chronological order.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::read_paired_ranges;
+/// let hours = read_paired_ranges(Some("Opening hours (Ex: 09:00-13:00, 15:00-19:00): "), None);
+///
+/// for (open, close) in hours {
+///     println!("Open from {open} to {close} ({} minutes).", open.minutes_until(close));
+/// }
+/// ```
+pub fn read_paired_ranges(msg: Option<&str>, err_msg: Option<&str>) -> Vec<(Time, Time)> {
+    if dry_run::is_dry_run() {
+        return dry_run::placeholder(msg, &[String::from(DRY_RUN_SCHEDULE)], parse_ranges);
+    }
+
+    read_until_valid(msg, err_msg, parse_ranges)
+}
+
+/// # Arguments #
+/// 'input' (&str) - Trimmed input typed by the user.
+///
+/// # Description #
+/// Private function parsing comma-separated ranges written as START-END, checking
+/// that they are ordered and do not overlap.
+fn parse_ranges(input: &str) -> Result<Vec<(Time, Time)>, String> {
+    if input.is_empty() {
+        return Err(String::from(
+            "Please enter at least one range (Ex: 09:00-12:30, 14:00-18:00).",
+        ));
+    }
+
+    let mut ranges: Vec<(Time, Time)> = Vec::new();
+    for range in input.split(',').map(str::trim) {
+        let (start, end) = range.split_once('-').ok_or_else(|| {
+            format!("'{range}' is not a range. Please write it as START-END (Ex: 09:00-12:30).")
+        })?;
+        let (start, end): (Time, Time) = (start.trim().parse()?, end.trim().parse()?);
+
+        if end <= start {
+            return Err(format!("The range {start}-{end} must end after it starts."));
+        }
+        if let Some(&(previous_start, previous_end)) = ranges.last() {
+            if start < previous_start {
+                return Err(format!(
+                    "The ranges must be in chronological order, but {start}-{end} comes before {previous_start}-{previous_end}."
+                ));
+            }
+            if start < previous_end {
+                return Err(format!(
+                    "The range {start}-{end} overlaps {previous_start}-{previous_end}."
+                ));
+            }
+        }
+
+        ranges.push((start, end));
+    }
+
+    Ok(ranges)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn time(hour: u8, minute: u8) -> Time {
+        Time::new(hour, minute).unwrap()
+    }
+
+    #[test]
+    fn schedules_are_parsed_and_checked() {
+        assert_eq!(
+            parse_ranges("9:00-12:30, 12:30-18:00"),
+            Ok(vec![
+                (time(9, 0), time(12, 30)),
+                (time(12, 30), time(18, 0))
+            ])
+        );
+        assert_eq!(
+            parse_ranges("09:00-12:30,12:00-13:00"),
+            Err(String::from("The range 12:00-13:00 overlaps 09:00-12:30."))
+        );
+
+        for input in [
+            "",
+            "14:00-18:00, 09:00-12:30",
+            "12:00-09:00",
+            "09:00",
+            "24:00-25:00",
+        ] {
+            assert!(parse_ranges(input).is_err(), "{input} was accepted");
+        }
+    }
+}
@@ -0,0 +1,306 @@
+//! Sessions, which keep state shared by several prompts.
+
+use std::str::FromStr;
+use std::time::{Duration, Instant};
+
+use crate::prompt::{Prompt, sealed};
+use crate::{InputError, eof};
+use crate::reader::{out, outln};
+
+/// State shared by a group of related prompts, such as a loop confirming many items.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::Session;
+/// let mut session = Session::new();
+///
+/// for file in ["a.txt", "b.txt", "c.txt"] {
+///     if session.read_yes_to_all(Some(&format!("Delete {file}? [y/n/a/q]: "))) {
+///         println!("Deleting {file}");
+///     }
+/// }
+/// ```
+#[derive(Debug, Default)]
+pub struct Session {
+    batch_answer: Option<bool>,
+    deadline: Option<Instant>,
+    stats: SessionStats,
+}
+
+/// Statistics about the prompts asked through a Session, which help finding the
+/// questions users struggle with (see Session::stats).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SessionStats {
+    /// Number of prompts which waited for the user.
+    pub prompts: usize,
+    /// Number of answers rejected because they were not valid.
+    pub invalid_attempts: usize,
+    /// Total time spent waiting for the user to answer.
+    pub waiting_time: Duration,
+}
+
+/// Answer to read_yes_to_all, and whether it applies to every following call.
+#[derive(Debug, Clone, Copy)]
+struct BatchAnswer(bool, bool);
+
+impl FromStr for BatchAnswer {
+    type Err = ();
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input.to_lowercase().as_str() {
+            "y" | "yes" => Ok(BatchAnswer(true, false)),
+            "n" | "no" => Ok(BatchAnswer(false, false)),
+            "a" | "all" => Ok(BatchAnswer(true, true)),
+            "q" | "quit" => Ok(BatchAnswer(false, true)),
+            _ => Err(()),
+        }
+    }
+}
+
+impl Session {
+    /// Creates a new Session.
+    pub fn new() -> Self {
+        Session::default()
+    }
+
+    /// # ARGUMENTS #
+    /// 'budget' (Duration) - total time the user has to answer every prompt asked through
+    /// this Session, counted from now.
+    ///
+    /// # DESCRIPTION #
+    /// Creates a Session with a time budget. Once the budget is exceeded, prompts read with
+    /// ask() stop waiting for the user and use their default value instead, which keeps
+    /// unattended runs (Ex: CI jobs without a terminal) from hanging forever.
+    ///
+    /// # EXAMPLES #
+    /// ```no_run
+    /// use quick_input::{Prompt, Session};
+    /// use std::time::Duration;
+    /// let mut session = Session::with_budget(Duration::from_secs(60));
+    ///
+    /// let name: String = session.ask(&Prompt::new("Name: ").text().default(String::from("demo")));
+    /// let port: u16 = session.ask(&Prompt::new("Port: ").value().default(8080));
+    /// ```
+    pub fn with_budget(budget: Duration) -> Self {
+        Session {
+            deadline: Some(Instant::now() + budget),
+            ..Session::default()
+        }
+    }
+
+    /// # ARGUMENTS #
+    /// 'prompt' (&Prompt<T, S>) - a text or value Prompt.
+    ///
+    /// # DESCRIPTION #
+    /// Reads from the Prompt, like Prompt::read. If the Session has a time budget and it runs
+    /// out (before or while the user is typing), a notice is printed and the default
+    /// value of the Prompt is used instead.
+    ///
+    /// # RETURNS #
+    /// The value typed by the user, or the default value of the Prompt.
+    ///
+    /// # PANICS #
+    /// If the time budget runs out (or the max_attempts of the Prompt are used) and the Prompt
+    /// has no default value, or if the user cancels a cancellable Prompt. If the input ends,
+    /// the EofPolicy set with set_eof_policy is applied instead.
+    pub fn ask<T: Clone, S: sealed::Typed>(&mut self, prompt: &Prompt<T, S>) -> T {
+        self.try_ask(prompt).unwrap_or_else(|err| match err {
+            InputError::Timeout if self.expired() => panic!(
+                "The session time budget was exceeded and the prompt '{}' has no default value.",
+                prompt.msg().trim()
+            ),
+            InputError::Timeout => panic!(
+                "The prompt '{}' was not answered in time and has no default value.",
+                prompt.msg().trim()
+            ),
+            InputError::Cancelled => panic!("The prompt '{}' was cancelled.", prompt.msg().trim()),
+            InputError::Eof => eof::reached(prompt.msg()),
+            err => panic!("The prompt '{}' was not answered: {err}", prompt.msg().trim()),
+        })
+    }
+
+    /// # ARGUMENTS #
+    /// 'prompt' (&Prompt<T, S>) - a text or value Prompt.
+    ///
+    /// # DESCRIPTION #
+    /// Works like ask, but if the time budget runs out and the Prompt has no default value,
+    /// InputError::Timeout is returned instead of panicking.
+    ///
+    /// # RETURNS #
+    /// The value typed by the user, the default value of the Prompt, or InputError::Timeout
+    /// (also if the timeout of the Prompt passed, InputError::Cancelled if the user cancelled
+    /// a cancellable Prompt, InputError::MaxAttemptsExceeded if the max_attempts of the Prompt
+    /// were used, and InputError::Eof if the input ended).
+    ///
+    /// # EXAMPLES #
+    /// ```no_run
+    /// use quick_input::{Prompt, Session};
+    /// use std::time::Duration;
+    /// let mut session = Session::with_budget(Duration::from_secs(30));
+    ///
+    /// match session.try_ask(&Prompt::new("Reason for the change: ").text()) {
+    ///     Ok(reason) => println!("Logged: {reason}"),
+    ///     Err(err) => eprintln!("{err}"),
+    /// }
+    /// ```
+    pub fn try_ask<T: Clone, S: sealed::Typed>(
+        &mut self,
+        prompt: &Prompt<T, S>,
+    ) -> Result<T, InputError> {
+        if self.expired() {
+            out!("{}", prompt.msg());
+        } else {
+            let start = Instant::now();
+            let mut invalid_attempts = 0;
+            let value = prompt.read_until(self.deadline, &mut invalid_attempts);
+            self.record(start, invalid_attempts);
+
+            match value {
+                // Otherwise, the prompt timed out on its own (see Prompt::timeout).
+                Err(InputError::Timeout) if self.expired() => {}
+                result => return result,
+            }
+        }
+
+        match prompt.default_value() {
+            Some(default) => {
+                outln!("(time budget exceeded, using the default value)");
+                Ok(default.clone())
+            }
+            None => {
+                outln!("(time budget exceeded)");
+                Err(InputError::Timeout)
+            }
+        }
+    }
+
+    /// # ARGUMENTS #
+    /// 'msg' (Option<&str>) - an optional message which will be printed at
+    /// the same line as the input prompt. Must be set to Some("...") or None.
+    ///
+    /// # DESCRIPTION #
+    /// Asks for a confirmation which accepts 'y' (yes), 'n' (no), 'a' (yes to all) and
+    /// 'q' (no to all), like `rm -i` or `git add -p`. This function is not case-sensitive,
+    /// and the full words yes, no, all and quit are accepted as well.
+    ///
+    /// Answering 'a' or 'q' makes the answer sticky: every following call on this Session
+    /// prints its message along with the answer and returns it without waiting for the user,
+    /// until reset_batch is called.
+    ///
+    /// If the Session has a time budget and it runs out, the answer is no (unless a sticky
+    /// answer was given), without waiting for the user.
+    ///
+    /// # RETURNS #
+    /// A boolean value (bool), true if the user confirmed.
+    pub fn read_yes_to_all(&mut self, msg: Option<&str>) -> bool {
+        if let Some(answer) = self.batch_answer {
+            outln!(
+                "{}{}",
+                msg.unwrap_or_default(),
+                if answer { "y" } else { "n" }
+            );
+            return answer;
+        }
+
+        let prompt = Prompt::new(msg.unwrap_or_default())
+            .value::<BatchAnswer>()
+            .err_msg("Please answer y (yes), n (no), a (yes to all) or q (no to all).");
+
+        let BatchAnswer(answer, sticky) = match self.try_ask(&prompt) {
+            Ok(answer) => answer,
+            // The time budget ran out: answer no, like a user who did not confirm.
+            Err(InputError::Timeout) => return false,
+            Err(InputError::Eof) => eof::reached(prompt.msg()),
+            Err(err) => panic!("The prompt '{}' was not answered: {err}", prompt.msg().trim()),
+        };
+
+        if sticky {
+            self.batch_answer = Some(answer);
+        }
+
+        answer
+    }
+
+    /// Forgets any sticky answer given to read_yes_to_all, so the user is asked again.
+    pub fn reset_batch(&mut self) {
+        self.batch_answer = None;
+    }
+
+    /// # DESCRIPTION #
+    /// Returns the statistics of the prompts asked through this Session so far: how many
+    /// waited for the user, how many answers were rejected and how long the user took in total.
+    /// Answers given automatically (sticky answers of read_yes_to_all, or default values used
+    /// once the time budget was exceeded) are not counted.
+    ///
+    /// # RETURNS #
+    /// The SessionStats of this Session.
+    ///
+    /// # EXAMPLES #
+    /// ```no_run
+    /// use quick_input::{Prompt, Session};
+    /// let mut session = Session::new();
+    ///
+    /// let port: u16 = session.ask(&Prompt::new("Port: ").value().range(1024..));
+    /// let workers: u8 = session.ask(&Prompt::new("Workers: ").value().range(1..=16));
+    ///
+    /// let stats = session.stats();
+    /// eprintln!(
+    ///     "{} prompts, {} invalid attempts, {:?} spent answering",
+    ///     stats.prompts, stats.invalid_attempts, stats.waiting_time
+    /// );
+    /// ```
+    pub fn stats(&self) -> SessionStats {
+        self.stats
+    }
+
+    /// Whether the time budget (if any) has run out.
+    fn expired(&self) -> bool {
+        self.deadline
+            .is_some_and(|deadline| Instant::now() >= deadline)
+    }
+
+    fn record(&mut self, start: Instant, invalid_attempts: usize) {
+        self.stats.prompts += 1;
+        self.stats.invalid_attempts += invalid_attempts;
+        self.stats.waiting_time += start.elapsed();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::MockInput;
+
+    #[test]
+    fn exceeded_budget_times_out() {
+        let mut mock = MockInput::new(Vec::<&str>::new());
+        let mut session = Session::with_budget(Duration::ZERO);
+
+        assert!(matches!(
+            mock.run(|| session.try_ask(&Prompt::new("Name: ").text())),
+            Err(InputError::Timeout)
+        ));
+        let port = mock.run(|| session.ask(&Prompt::new("Port: ").value().default(8080)));
+        assert_eq!(port, 8080);
+    }
+
+    #[test]
+    fn yes_to_all_is_sticky() {
+        let mut mock = MockInput::new(["x", "A"]);
+        let mut session = Session::new();
+
+        assert!(mock.run(|| session.read_yes_to_all(Some("Delete a? "))));
+        assert!(mock.run(|| session.read_yes_to_all(Some("Delete b? "))));
+        mock.assert_rejected("Please answer y (yes), n (no), a (yes to all) or q (no to all).");
+        mock.assert_prompted_times("Delete a? ", 2);
+        assert_eq!(session.stats().invalid_attempts, 1);
+    }
+
+    #[test]
+    fn exceeded_budget_answers_no_without_reading() {
+        let mut mock = MockInput::new(Vec::<&str>::new());
+        let mut session = Session::with_budget(Duration::ZERO);
+
+        assert!(!mock.run(|| session.read_yes_to_all(Some("Delete a? "))));
+    }
+}
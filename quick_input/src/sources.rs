@@ -0,0 +1,213 @@
+//! Answers which can be provided ahead of time, so interactive programs can be automated.
+
+use std::collections::{HashMap, VecDeque};
+use std::env;
+use std::str::FromStr;
+
+use crate::read_until_valid;
+use crate::reader::outln;
+
+/// Places an AnswerSource can take an answer from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnswerOrigin {
+    /// Answers seeded beforehand, usually from `--answer key=value` command line arguments.
+    Preset,
+    /// Environment variables named after the prompt key (see AnswerSource::env_prefix).
+    Environment,
+    /// The user, through the usual interactive prompt.
+    Interactive,
+}
+
+/// Hybrid source of answers: each prompt is identified by a key, and its answer is taken from
+/// the first origin which provides a valid one. By default preset answers are checked first,
+/// then environment variables (if a prefix was set), and finally the user is asked.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::AnswerSource;
+/// // Running "my-tool --answer port=8080" skips the port prompt.
+/// let mut answers = AnswerSource::new()
+///     .args(std::env::args())
+///     .env_prefix("MY_TOOL");
+///
+/// let port: u16 = answers.read("port", Some("Port: "), None);
+/// let name = answers.read_string("name", Some("Name: "));
+/// ```
+#[derive(Debug, Clone)]
+pub struct AnswerSource {
+    presets: HashMap<String, VecDeque<String>>,
+    env_prefix: Option<String>,
+    order: Vec<AnswerOrigin>,
+}
+
+impl Default for AnswerSource {
+    fn default() -> Self {
+        AnswerSource {
+            presets: HashMap::new(),
+            env_prefix: None,
+            order: vec![
+                AnswerOrigin::Preset,
+                AnswerOrigin::Environment,
+                AnswerOrigin::Interactive,
+            ],
+        }
+    }
+}
+
+impl AnswerSource {
+    /// Creates an AnswerSource without preset answers which does not read the environment.
+    pub fn new() -> Self {
+        AnswerSource::default()
+    }
+
+    /// Queues a preset answer for 'key'. Answers queued for the same key are used in order,
+    /// one per prompt.
+    pub fn answer(mut self, key: &str, value: &str) -> Self {
+        self.presets
+            .entry(key.to_string())
+            .or_default()
+            .push_back(value.to_string());
+        self
+    }
+
+    /// Queues the preset answers found in command line arguments, written either as
+    /// `--answer key=value` or `--answer=key=value`. Other arguments are ignored.
+    pub fn args<I, S>(mut self, args: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut args = args.into_iter();
+
+        while let Some(arg) = args.next() {
+            let pair = match arg.as_ref() {
+                "--answer" => args.next().map(|next| next.as_ref().to_string()),
+                other => other.strip_prefix("--answer=").map(str::to_string),
+            };
+
+            if let Some((key, value)) = pair.as_deref().and_then(|pair| pair.split_once('=')) {
+                self = self.answer(key, value);
+            }
+        }
+
+        self
+    }
+
+    /// Enables environment variables as an origin. The variable checked for a key is the
+    /// prefix and the key joined by '_', uppercased, with any other symbol replaced by '_'
+    /// (Ex: prefix "MY_TOOL" and key "db.port" check MY_TOOL_DB_PORT).
+    pub fn env_prefix(mut self, prefix: &str) -> Self {
+        self.env_prefix = Some(prefix.to_string());
+        self
+    }
+
+    /// Sets the origins checked for every answer, from highest to lowest precedence.
+    /// Leaving AnswerOrigin::Interactive out makes the source fully non-interactive.
+    pub fn order(mut self, order: &[AnswerOrigin]) -> Self {
+        self.order = order.to_vec();
+        self
+    }
+
+    /// # ARGUMENTS #
+    /// 'key' (&str) - identifier of the prompt, used to look up preset and environment answers.
+    ///
+    /// 'msg' (Option<&str>) - an optional message which will be printed at
+    /// the same line as the input prompt. Must be set to Some("...") or None.
+    ///
+    /// # DESCRIPTION #
+    /// Returns the answer for 'key' from the first origin which provides one,
+    /// prompting the user (like read_string) if the Interactive origin is reached.
+    ///
+    /// # PANICS #
+    /// If no origin provides an answer and AnswerOrigin::Interactive is not part of the order.
+    ///
+    /// # RETURNS #
+    /// A trimmed String value.
+    pub fn read_string(&mut self, key: &str, msg: Option<&str>) -> String {
+        self.read(key, msg, None)
+    }
+
+    /// # ARGUMENTS #
+    /// 'key' (&str) - identifier of the prompt, used to look up preset and environment answers.
+    ///
+    /// 'msg' (Option<&str>) - an optional message which will be printed at
+    /// the same line as the input prompt. Must be set to Some("...") or None.
+    ///
+    /// 'err_msg' (Option<&str>) - an optional error message which will be printed
+    /// if the user inputs an invalid value. Must be set to Some("...") or None.
+    ///
+    /// # DESCRIPTION #
+    /// Returns the answer for 'key' from the first origin which provides a value that can be
+    /// parsed as T. Preset or environment values which cannot be parsed are reported and skipped.
+    /// If the Interactive origin is reached, the user is prompted until a valid value is typed.
+    ///
+    /// # PANICS #
+    /// If no origin provides a valid answer and AnswerOrigin::Interactive is not part of the order.
+    ///
+    /// # RETURNS #
+    /// A value of type T.
+    pub fn read<T: FromStr>(&mut self, key: &str, msg: Option<&str>, err_msg: Option<&str>) -> T {
+        for origin in self.order.clone() {
+            let raw = match origin {
+                AnswerOrigin::Preset => self.presets.get_mut(key).and_then(VecDeque::pop_front),
+                AnswerOrigin::Environment => self.env_value(key),
+                AnswerOrigin::Interactive => {
+                    return read_until_valid(msg, err_msg, |input| {
+                        input
+                            .parse::<T>()
+                            .map_err(|_| String::from("Please enter a valid value."))
+                    });
+                }
+            };
+
+            match raw.map(|raw| (raw.trim().parse::<T>(), raw)) {
+                Some((Ok(value), _)) => return value,
+                Some((Err(_), raw)) => {
+                    outln!("Ignoring invalid {origin:?} answer '{raw}' for '{key}'.")
+                }
+                None => {}
+            }
+        }
+
+        panic!("No answer was provided for '{key}' and interactive input is disabled.");
+    }
+
+    fn env_value(&self, key: &str) -> Option<String> {
+        let prefix = self.env_prefix.as_ref()?;
+        let name: String = format!("{prefix}_{key}")
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() {
+                    c.to_ascii_uppercase()
+                } else {
+                    '_'
+                }
+            })
+            .collect();
+
+        env::var(name).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn presets_are_parsed_from_args_and_used_in_order() {
+        let mut source = AnswerSource::new()
+            .args(["my-tool", "--answer", "port=80", "-v", "--answer=port=81"])
+            .order(&[AnswerOrigin::Preset]);
+
+        assert_eq!(source.read::<u16>("port", None, None), 80);
+        assert_eq!(source.read::<u16>("port", None, None), 81);
+    }
+
+    #[test]
+    #[should_panic(expected = "No answer was provided for 'name'")]
+    fn missing_answer_panics_when_not_interactive() {
+        AnswerSource::new()
+            .order(&[AnswerOrigin::Preset, AnswerOrigin::Environment])
+            .read_string("name", None);
+    }
+}
@@ -0,0 +1,255 @@
+//! Readers for compact structured inputs split on two levels (Ex: a=1;b=2;c=3).
+
+use std::str::FromStr;
+
+use crate::read_until_valid;
+
+/// Delimiters used by read_semicolon_list_of_pairs and read_nested_list.
+///
+/// Text between quotes is kept as it is, so values can contain the delimiters
+/// (Ex: title="Hello; world"). A quote character is typed inside quotes by doubling it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Delimiters {
+    /// Separator between the items of the list (';' by default).
+    pub outer: char,
+    /// Separator between the parts of every item ('=' by default).
+    pub inner: char,
+    /// Quote character, or None to disable quoting ('"' by default).
+    pub quote: Option<char>,
+}
+
+impl Default for Delimiters {
+    fn default() -> Self {
+        Delimiters {
+            outer: ';',
+            inner: '=',
+            quote: Some('"'),
+        }
+    }
+}
+
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as the input prompt. Must be set to Some("...") or None.
+///
+/// 'err_msg' (Option<&str>) - an optional error message which will be printed
+/// if the user inputs an invalid value. Must be set to Some("...") or None.
+///
+/// 'delimiters' (&Delimiters) - separators and quote character. Delimiters::default()
+/// reads lists such as a=1;b=2;c=3.
+///
+/// # DESCRIPTION #
+/// Prompts the user to type a list of key / value pairs on a single line. Every item must
+/// have exactly one inner delimiter, and its key cannot be empty. Spaces around keys and values
+/// are ignored, as well as empty items (Ex: a trailing ';'), so an empty line is an empty list.
+/// In case the user writes an invalid value, they will be prompted to try again.
+///
+/// Provides an information message on the same line as the prompt if Some("...")
+/// is provided, and just the prompt if None is provided.
+///
+/// If err_msg is set to None, a default message explaining the problem will be shown.
+///
+/// # RETURNS #
+/// A Vec<(String, String)> with the pairs in the order they were typed.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::{read_semicolon_list_of_pairs, Delimiters};
+/// let labels = read_semicolon_list_of_pairs(Some("Labels (key=value;...): "), None, &Delimiters::default());
+///
+/// let headers = read_semicolon_list_of_pairs(
+///     Some("Headers (name: value, ...): "),
+///     None,
+///     &Delimiters { outer: ',', inner: ':', ..Delimiters::default() },
+/// );
+/// ```
+pub fn read_semicolon_list_of_pairs(
+    msg: Option<&str>,
+    err_msg: Option<&str>,
+    delimiters: &Delimiters,
+) -> Vec<(String, String)> {
+    read_until_valid(msg, err_msg, |input| parse_pairs(input, delimiters))
+}
+
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as the input prompt. Must be set to Some("...") or None.
+///
+/// 'err_msg' (Option<&str>) - an optional error message which will be printed
+/// if the user inputs an invalid value. Must be set to Some("...") or None.
+///
+/// 'delimiters' (&Delimiters) - separators and quote character (Ex: outer ';' and
+/// inner ',' to read 1,2;3,4 as two groups of two values).
+///
+/// # DESCRIPTION #
+/// Prompts the user to type groups of values on a single line, every value being parsed as T.
+/// Spaces around values and empty groups (Ex: a trailing ';') are ignored.
+/// In case any value is invalid, the error message will point at it and the user will be
+/// prompted to try again.
+///
+/// Provides an information message on the same line as the prompt if Some("...")
+/// is provided, and just the prompt if None is provided.
+///
+/// If err_msg is set to None, a default message explaining the problem will be shown.
+///
+/// # RETURNS #
+/// A Vec<Vec<T>> with one Vec per group, in the order they were typed.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::{read_nested_list, Delimiters};
+/// let delimiters = Delimiters { inner: ',', ..Delimiters::default() };
+/// let matrix: Vec<Vec<f64>> = read_nested_list(Some("Rows (1,2;3,4): "), None, &delimiters);
+/// ```
+pub fn read_nested_list<T: FromStr>(
+    msg: Option<&str>,
+    err_msg: Option<&str>,
+    delimiters: &Delimiters,
+) -> Vec<Vec<T>> {
+    read_until_valid(msg, err_msg, |input| {
+        let groups = split_nested(input, delimiters)?;
+
+        groups
+            .iter()
+            .enumerate()
+            .map(|(index, group)| {
+                group
+                    .iter()
+                    .map(|value| {
+                        value.parse().map_err(|_| {
+                            format!("'{value}' in group {} is not a valid value.", index + 1)
+                        })
+                    })
+                    .collect()
+            })
+            .collect()
+    })
+}
+
+fn parse_pairs(input: &str, delimiters: &Delimiters) -> Result<Vec<(String, String)>, String> {
+    split_nested(input, delimiters)?
+        .into_iter()
+        .map(|parts| match parts.as_slice() {
+            [key, value] if !key.is_empty() => Ok((key.clone(), value.clone())),
+            [_, value] => Err(format!("Missing key for the value '{value}'.")),
+            _ => Err(format!(
+                "'{}' must be written as key{}value.",
+                parts.join(&delimiters.inner.to_string()),
+                delimiters.inner
+            )),
+        })
+        .collect()
+}
+
+/// # Arguments #
+/// 'input' (&str) - Trimmed input typed by the user.
+///
+/// 'delimiters' (&Delimiters) - Separators and quote character.
+///
+/// # Description #
+/// Private function splitting the input on both delimiters, honouring quotes. Spaces outside
+/// quotes are trimmed from every part, and groups with no content are dropped.
+pub(crate) fn split_nested(input: &str, delimiters: &Delimiters) -> Result<Vec<Vec<String>>, String> {
+    let mut groups = Vec::new();
+    let mut group = Vec::new();
+    let mut part = Part::default();
+    let mut chars = input.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if Some(c) == delimiters.quote {
+            loop {
+                match chars.next() {
+                    Some(quoted) if Some(quoted) == delimiters.quote => {
+                        if chars.peek() != Some(&quoted) {
+                            break;
+                        }
+                        chars.next();
+                        part.push_quoted(quoted);
+                    }
+                    Some(quoted) => part.push_quoted(quoted),
+                    None => return Err(String::from("A quote was not closed.")),
+                }
+            }
+            part.quoted = true;
+        } else if c == delimiters.outer {
+            finish_group(&mut groups, &mut group, std::mem::take(&mut part));
+        } else if c == delimiters.inner {
+            group.push(std::mem::take(&mut part).finish());
+        } else {
+            part.push(c);
+        }
+    }
+
+    finish_group(&mut groups, &mut group, part);
+    Ok(groups)
+}
+
+/// Adds the last part to the group and the group to the list, unless the group has
+/// no content at all (Ex: the empty group after a trailing delimiter).
+fn finish_group(groups: &mut Vec<Vec<String>>, group: &mut Vec<String>, last: Part) {
+    let empty = group.is_empty() && last.text.is_empty() && !last.quoted;
+    group.push(last.finish());
+
+    let group = std::mem::take(group);
+    if !empty {
+        groups.push(group);
+    }
+}
+
+/// A part being split, which remembers where its last quoted text ended so
+/// the spaces inside quotes are not trimmed.
+#[derive(Default)]
+struct Part {
+    text: String,
+    quoted_end: usize,
+    quoted: bool,
+}
+
+impl Part {
+    fn push(&mut self, c: char) {
+        if !self.text.is_empty() || !c.is_whitespace() {
+            self.text.push(c);
+        }
+    }
+
+    fn push_quoted(&mut self, c: char) {
+        self.text.push(c);
+        self.quoted_end = self.text.len();
+    }
+
+    fn finish(self) -> String {
+        let (quoted, rest) = self.text.split_at(self.quoted_end);
+        format!("{quoted}{}", rest.trim_end())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pairs_are_split() {
+        let pairs = parse_pairs(" a = 1; b=2;c=\"x; y\";", &Delimiters::default()).unwrap();
+        assert_eq!(
+            pairs,
+            [("a", "1"), ("b", "2"), ("c", "x; y")].map(|(k, v)| (k.to_string(), v.to_string()))
+        );
+
+        assert!(parse_pairs("a=1;b", &Delimiters::default()).is_err());
+        assert!(parse_pairs("=1", &Delimiters::default()).is_err());
+        assert!(parse_pairs("a=\"1", &Delimiters::default()).is_err());
+        assert_eq!(parse_pairs("", &Delimiters::default()), Ok(Vec::new()));
+    }
+
+    #[test]
+    fn quotes_keep_spaces_and_can_be_escaped() {
+        let delimiters = Delimiters {
+            inner: ',',
+            ..Delimiters::default()
+        };
+        assert_eq!(
+            split_nested("\" padded \", \"say \"\"hi\"\"\";3", &delimiters).unwrap(),
+            [vec![" padded ", "say \"hi\""], vec!["3"]]
+        );
+    }
+}
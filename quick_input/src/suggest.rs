@@ -0,0 +1,94 @@
+//! Helpers used to suggest close matches when the user misspells a known value.
+
+/// # Arguments #
+/// 'a' (&str) - First string to compare.
+///
+/// 'b' (&str) - Second string to compare.
+///
+/// # Description #
+/// Computes the Levenshtein distance between both strings (counting chars, not bytes),
+/// which is the number of insertions, deletions and substitutions needed to turn 'a' into 'b'.
+pub(crate) fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    let mut current = vec![0; b.len() + 1];
+
+    for (i, a_char) in a.chars().enumerate() {
+        current[0] = i + 1;
+
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a_char != *b_char);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    previous[b.len()]
+}
+
+/// # Arguments #
+/// 'input' (&str) - Value typed by the user.
+///
+/// 'candidates' (impl IntoIterator<Item = &str>) - Every value which would have been accepted.
+///
+/// 'max' (usize) - Maximum number of suggestions returned.
+///
+/// # Description #
+/// Returns the candidates closest to 'input' (not case-sensitive), ordered from closest to
+/// furthest. Candidates too different from the input to be a plausible typo are left out.
+pub(crate) fn closest_matches<'a>(
+    input: &str,
+    candidates: impl IntoIterator<Item = &'a str>,
+    max: usize,
+) -> Vec<&'a str> {
+    let input = input.to_lowercase();
+    let threshold = (input.chars().count() / 3).max(1);
+
+    let mut matches: Vec<(usize, &str)> = candidates
+        .into_iter()
+        .map(|candidate| (edit_distance(&input, &candidate.to_lowercase()), candidate))
+        .filter(|(distance, _)| *distance <= threshold)
+        .collect();
+
+    matches.sort();
+    matches
+        .into_iter()
+        .take(max)
+        .map(|(_, candidate)| candidate)
+        .collect()
+}
+
+/// # Arguments #
+/// 'suggestions' (&[&str]) - Suggested values, usually obtained from closest_matches.
+///
+/// # Description #
+/// Builds a " Did you mean ...?" sentence to append to an error message,
+/// or an empty String if there are no suggestions.
+pub(crate) fn did_you_mean(suggestions: &[&str]) -> String {
+    match suggestions {
+        [] => String::new(),
+        [only] => format!(" Did you mean {only}?"),
+        [rest @ .., last] => format!(" Did you mean {} or {last}?", rest.join(", ")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn edit_distance_counts_chars() {
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+        assert_eq!(edit_distance("", "abc"), 3);
+        assert_eq!(edit_distance("Málaga", "Malaga"), 1);
+    }
+
+    #[test]
+    fn closest_matches_ignores_case_and_distant_values() {
+        let candidates = ["Madrid", "Manila", "Paris"];
+
+        assert_eq!(closest_matches("madird", candidates, 3), vec!["Madrid"]);
+        assert!(closest_matches("Tokyo", candidates, 3).is_empty());
+    }
+}
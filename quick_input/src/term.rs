@@ -0,0 +1,152 @@
+//! Detection of what the terminal attached to stdin and stdout can do.
+//!
+//! Every check is cheap and answers for the current moment, so a program can call them right
+//! before drawing something and fall back to plain text when a capability is missing. The
+//! readers of this crate use the same checks, so both degrade the same way. Nothing is
+//! reported as available while a Reader runs, since its output is not a terminal.
+//!
+//! ```no_run
+//! use quick_input::term;
+//!
+//! let bullet = if term::supports_unicode() { "•" } else { "*" };
+//! if term::supports_color() {
+//!     println!("\x1b[1m{bullet} Setup\x1b[0m");
+//! } else {
+//!     println!("{bullet} Setup");
+//! }
+//! ```
+
+use std::env;
+use std::io::{self, IsTerminal};
+
+use crate::reader;
+
+/// Capabilities of the terminal, all detected at once.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Capabilities {
+    /// Whether ANSI colors and styles can be printed to stdout (see supports_color).
+    pub color: bool,
+    /// Whether characters outside ASCII are displayed properly (see supports_unicode).
+    pub unicode: bool,
+    /// Whether keys can be read as soon as they are pressed (see supports_raw_mode).
+    pub raw_mode: bool,
+    /// Whether the cursor can be moved and lines redrawn (see supports_cursor_movement).
+    pub cursor_movement: bool,
+}
+
+impl Capabilities {
+    /// Detects every capability of the terminal.
+    pub fn detect() -> Capabilities {
+        Capabilities {
+            color: supports_color(),
+            unicode: supports_unicode(),
+            raw_mode: supports_raw_mode(),
+            cursor_movement: supports_cursor_movement(),
+        }
+    }
+}
+
+/// # DESCRIPTION #
+/// Checks whether colors can be printed to stdout: it must be a terminal which is not "dumb"
+/// (TERM), and the NO_COLOR environment variable must not be set. CLICOLOR_FORCE (set to
+/// anything but 0) turns colors on regardless, following the usual conventions.
+///
+/// # RETURNS #
+/// True if ANSI colors and styles can be used.
+pub fn supports_color() -> bool {
+    if reader::is_active() {
+        return false;
+    }
+    if env::var_os("CLICOLOR_FORCE").is_some_and(|force| force != "0") {
+        return true;
+    }
+    if env::var_os("NO_COLOR").is_some_and(|no_color| !no_color.is_empty()) {
+        return false;
+    }
+
+    supports_cursor_movement()
+}
+
+/// # DESCRIPTION #
+/// Checks whether characters outside ASCII (Ex: "✓", "•") are displayed properly. On
+/// Windows, this is assumed for every console; elsewhere, the locale (LC_ALL, LC_CTYPE or
+/// LANG, the first one set) must use UTF-8.
+///
+/// # RETURNS #
+/// True if Unicode symbols can be printed.
+pub fn supports_unicode() -> bool {
+    if reader::is_active() {
+        return false;
+    }
+    if cfg!(windows) {
+        return true;
+    }
+
+    ["LC_ALL", "LC_CTYPE", "LANG"]
+        .iter()
+        .filter_map(|name| env::var(name).ok())
+        .find(|value| !value.is_empty())
+        .is_some_and(|locale| is_utf8_locale(&locale))
+}
+
+/// # DESCRIPTION #
+/// Checks whether stdin can be put in raw mode, so every key is received as soon as it is
+/// pressed. This requires stdin to be a terminal, on a platform where raw mode is supported
+/// (currently Unix).
+///
+/// # RETURNS #
+/// True if features reading single keys (Ex: editing a previous answer) are available.
+pub fn supports_raw_mode() -> bool {
+    cfg!(unix) && !reader::is_active() && io::stdin().is_terminal()
+}
+
+/// # DESCRIPTION #
+/// Checks whether the cursor can be moved on stdout (ANSI escape sequences), which is needed
+/// to redraw a line or hide the cursor. Stdout must be a terminal which is not "dumb" (TERM).
+///
+/// # RETURNS #
+/// True if escape sequences moving the cursor can be printed.
+pub fn supports_cursor_movement() -> bool {
+    !reader::is_active()
+        && io::stdout().is_terminal()
+        && env::var_os("TERM").is_none_or(|term| term != "dumb")
+}
+
+/// # Arguments #
+/// 'locale' (&str) - Value of a locale variable (Ex: "en_US.UTF-8").
+///
+/// # Description #
+/// Private function checking whether the locale uses UTF-8, whatever the spelling.
+fn is_utf8_locale(locale: &str) -> bool {
+    let locale = locale.to_ascii_lowercase();
+    locale.contains("utf-8") || locale.contains("utf8")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn utf8_locales_are_recognised() {
+        assert!(is_utf8_locale("en_US.UTF-8"));
+        assert!(is_utf8_locale("C.utf8"));
+        assert!(!is_utf8_locale("C"));
+        assert!(!is_utf8_locale("es_ES.ISO-8859-1"));
+    }
+
+    #[test]
+    fn nothing_is_available_while_a_reader_runs() {
+        let mut reader = crate::Reader::new(std::io::Cursor::new(""), Vec::new());
+
+        assert_eq!(
+            reader.run(Capabilities::detect),
+            Capabilities {
+                color: false,
+                unicode: false,
+                raw_mode: false,
+                cursor_movement: false,
+            }
+        );
+    }
+}
@@ -0,0 +1,211 @@
+//! Scripted answers for testing interactive programs without a terminal.
+//!
+//! A MockInput answers the readers of this crate with a list of lines and records every prompt
+//! and error message shown, so a test can check both what a flow returns and what it asked.
+//! For plain input and output streams instead of a script, see Reader.
+//!
+//! ```
+//! use quick_input::testing::MockInput;
+//! use quick_input::{read_bool, read_u8};
+//!
+//! let mut mock = MockInput::new(["forty-two", "42", "true"]);
+//! let (age, agreed) = mock.run(|| (read_u8(Some("Age: "), None), read_bool(Some("Agree? "), None)));
+//!
+//! assert_eq!((age, agreed), (42, true));
+//! mock.assert_prompted("Age: ");
+//! mock.assert_prompted_times("Age: ", 2);
+//! mock.assert_rejected("Please enter a valid positive number (8 bits).");
+//! mock.assert_all_answered();
+//! ```
+
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::fmt;
+use std::io;
+use std::rc::Rc;
+
+use crate::reader::{self, Streams};
+
+/// Readers' input scripted as a list of answers, recording what they show.
+#[derive(Debug, Default)]
+pub struct MockInput {
+    log: Rc<RefCell<Log>>,
+}
+
+/// Answers left and everything shown so far, shared with the readers while run() runs.
+#[derive(Debug, Default)]
+struct Log {
+    answers: VecDeque<String>,
+    prompts: Vec<String>,
+    errors: Vec<String>,
+    output: String,
+}
+
+impl Streams for Rc<RefCell<Log>> {
+    fn read_line(&mut self, line: &mut String) -> io::Result<usize> {
+        let mut log = self.borrow_mut();
+        let Some(answer) = log.answers.pop_front() else {
+            let last = log.prompts.last().map_or("", String::as_str);
+            panic!(
+                "MockInput ran out of answers at the prompt '{}'.",
+                last.trim()
+            );
+        };
+
+        log.output.push_str(&answer);
+        log.output.push('\n');
+        line.push_str(&answer);
+        line.push('\n');
+        Ok(answer.len() + 1)
+    }
+
+    fn write(&mut self, args: fmt::Arguments) -> io::Result<()> {
+        fmt::write(&mut self.borrow_mut().output, args).map_err(io::Error::other)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn prompted(&mut self, msg: &str) {
+        self.borrow_mut().prompts.push(msg.to_string());
+    }
+
+    fn rejected(&mut self, message: &str) {
+        self.borrow_mut().errors.push(message.to_string());
+    }
+}
+
+impl MockInput {
+    /// # ARGUMENTS #
+    /// 'answers' (impl IntoIterator<Item = impl Into<String>>) - lines typed by the user,
+    /// in order, without their line breaks (Ex: ["42", "yes", "hello"]).
+    ///
+    /// # DESCRIPTION #
+    /// Creates a MockInput answering the readers with 'answers'. If a reader asks for more
+    /// lines than were given, it panics naming the prompt left unanswered.
+    pub fn new(answers: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        let log = Log {
+            answers: answers.into_iter().map(Into::into).collect(),
+            ..Log::default()
+        };
+
+        MockInput {
+            log: Rc::new(RefCell::new(log)),
+        }
+    }
+
+    /// # ARGUMENTS #
+    /// 'f' (impl FnOnce() -> T) - code calling any reader, Prompt, Form or Session.
+    ///
+    /// # DESCRIPTION #
+    /// Runs 'f' with every reader of this crate answered by the script, like Reader::run.
+    ///
+    /// # RETURNS #
+    /// The value returned by 'f'.
+    pub fn run<T>(&mut self, f: impl FnOnce() -> T) -> T {
+        let _active = reader::activate(self.log.clone());
+        f()
+    }
+
+    /// Returns the messages of the prompts shown so far, in order, including repeated ones.
+    pub fn prompts(&self) -> Vec<String> {
+        self.log.borrow().prompts.clone()
+    }
+
+    /// Returns the error messages shown so far, in order.
+    pub fn errors(&self) -> Vec<String> {
+        self.log.borrow().errors.clone()
+    }
+
+    /// Returns everything written so far, with the answers echoed as if typed on a terminal.
+    pub fn output(&self) -> String {
+        self.log.borrow().output.clone()
+    }
+
+    /// Returns the number of answers not used yet.
+    pub fn remaining(&self) -> usize {
+        self.log.borrow().answers.len()
+    }
+
+    /// Panics unless a prompt with the message 'msg' (Ex: "Age: ") was shown.
+    #[track_caller]
+    pub fn assert_prompted(&self, msg: &str) {
+        let prompts = self.prompts();
+        assert!(
+            prompts.iter().any(|prompt| prompt == msg),
+            "The prompt {msg:?} was not shown. Prompts shown: {prompts:?}"
+        );
+    }
+
+    /// Panics unless a prompt with the message 'msg' was shown exactly 'times' times.
+    #[track_caller]
+    pub fn assert_prompted_times(&self, msg: &str, times: usize) {
+        let shown = self
+            .prompts()
+            .iter()
+            .filter(|prompt| *prompt == msg)
+            .count();
+        assert_eq!(
+            shown, times,
+            "The prompt {msg:?} was shown {shown} times instead of {times}."
+        );
+    }
+
+    /// Panics unless the error message 'message' was shown after an invalid answer.
+    #[track_caller]
+    pub fn assert_rejected(&self, message: &str) {
+        let errors = self.errors();
+        assert!(
+            errors.iter().any(|error| error == message),
+            "The error {message:?} was not shown. Errors shown: {errors:?}"
+        );
+    }
+
+    /// Panics if any error message was shown, meaning every answer was accepted.
+    #[track_caller]
+    pub fn assert_no_errors(&self) {
+        let errors = self.errors();
+        assert!(errors.is_empty(), "Some answers were rejected: {errors:?}");
+    }
+
+    /// Panics if some answers were not used, which usually means the flow asked fewer
+    /// questions than expected.
+    #[track_caller]
+    pub fn assert_all_answered(&self) {
+        let log = self.log.borrow();
+        assert!(
+            log.answers.is_empty(),
+            "{} answers were not used: {:?}",
+            log.answers.len(),
+            log.answers
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Prompt, read_i32};
+
+    #[test]
+    fn prompts_and_errors_are_recorded() {
+        let mut mock = MockInput::new(["", "3", "12"]);
+        let total = mock.run(|| {
+            let level: u8 = Prompt::new("Level: ").value().range(1..=9).read();
+            level as i32 + read_i32(Some("Bonus: "), None)
+        });
+
+        assert_eq!(total, 15);
+        assert_eq!(mock.prompts(), ["Level: ", "Level: ", "Bonus: "]);
+        assert_eq!(mock.errors().len(), 1);
+        assert!(mock.output().starts_with("Level: \n"));
+        mock.assert_all_answered();
+    }
+
+    #[test]
+    #[should_panic(expected = "ran out of answers at the prompt 'Name:'")]
+    fn running_out_of_answers_panics() {
+        MockInput::new(Vec::<String>::new()).run(|| crate::read_string(Some("Name: ")));
+    }
+}
@@ -0,0 +1,452 @@
+//! Readers for strings which must follow a set of rules.
+
+use crate::record::{recent_answers, record_answer};
+use crate::{dry_run, read_string, read_until_valid};
+use crate::reader::outln;
+
+/// Maximum number of suggestions listed by read_string_with_suggestions.
+const MAX_SUGGESTIONS: usize = 9;
+
+/// Rules checked by read_username. Every rule produces its own error message,
+/// so the user knows exactly what to change.
+///
+/// Letters and digits are always allowed (ASCII only); any other character must be
+/// listed in 'allowed_symbols'.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UsernameRules<'a> {
+    /// Minimum number of characters (3 by default).
+    pub min_len: usize,
+    /// Maximum number of characters (32 by default).
+    pub max_len: usize,
+    /// Characters allowed besides letters and digits ("_-." by default).
+    pub allowed_symbols: &'a str,
+    /// Whether the first character must be a letter (true by default).
+    pub must_start_with_letter: bool,
+    /// Names which cannot be used, compared without case-sensitivity (none by default).
+    pub reserved: &'a [&'a str],
+}
+
+impl Default for UsernameRules<'_> {
+    fn default() -> Self {
+        UsernameRules {
+            min_len: 3,
+            max_len: 32,
+            allowed_symbols: "_-.",
+            must_start_with_letter: true,
+            reserved: &[],
+        }
+    }
+}
+
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as the input prompt. Must be set to Some("...") or None.
+///
+/// 'err_msg' (Option<&str>) - an optional error message which will be printed
+/// if the user inputs an invalid value. Must be set to Some("...") or None.
+///
+/// # DESCRIPTION #
+/// Prompts the user to type a text (String) which will then be returned.
+/// In case the user writes nothing but spaces, they will be prompted to try again.
+///
+/// Provides an information message on the same line as the prompt if Some("...")
+/// is provided, and just the prompt if None is provided.
+///
+/// If err_msg is set to None, a default message will be shown.
+///
+/// # RETURNS #
+/// A trimmed, non-empty String value provided by the user.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::read_nonempty_string;
+/// let title = read_nonempty_string(Some("Title: "), Some("The title can't be empty."));
+///
+/// let user_str: String = read_nonempty_string(None, None);
+/// ```
+pub fn read_nonempty_string(msg: Option<&str>, err_msg: Option<&str>) -> String {
+    read_string_bounded(msg, err_msg, 1, usize::MAX)
+}
+
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as the input prompt. Must be set to Some("...") or None.
+///
+/// 'err_msg' (Option<&str>) - an optional error message which will be printed
+/// if the user inputs an invalid value. Must be set to Some("...") or None.
+///
+/// 'min_len' (usize) - minimum number of characters of the text.
+///
+/// 'max_len' (usize) - maximum number of characters of the text.
+///
+/// # DESCRIPTION #
+/// Prompts the user to type a text (String) of min_len to max_len characters, which will
+/// then be returned. Characters are counted rather than bytes (Ex: "ñandú" has 5), once the
+/// surrounding whitespace is removed. In case the text is empty, too short or too long,
+/// a message explaining the problem will be shown and the user will be prompted to try again.
+///
+/// Provides an information message on the same line as the prompt if Some("...")
+/// is provided, and just the prompt if None is provided.
+///
+/// If err_msg is set to None, a default message explaining the problem will be shown.
+///
+/// # RETURNS #
+/// A trimmed String value provided by the user, with min_len to max_len characters.
+///
+/// # PANICS #
+/// If min_len is greater than max_len.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::read_string_bounded;
+/// let nickname = read_string_bounded(Some("Nickname (3-16 characters): "), None, 3, 16);
+///
+/// let tweet: String = read_string_bounded(None, None, 1, 280);
+/// ```
+pub fn read_string_bounded(
+    msg: Option<&str>,
+    err_msg: Option<&str>,
+    min_len: usize,
+    max_len: usize,
+) -> String {
+    assert!(
+        min_len <= max_len,
+        "The minimum length ({min_len}) is greater than the maximum length ({max_len})."
+    );
+
+    let parse = |input: &str| check_length(input, min_len, max_len).map(|_| input.to_string());
+
+    if dry_run::is_dry_run() {
+        return dry_run::placeholder(msg, &["A".repeat(min_len)], parse);
+    }
+
+    read_until_valid(msg, err_msg, parse)
+}
+
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as the input prompt. Must be set to Some("...") or None.
+///
+/// 'rules' (&UsernameRules) - rules the username must follow. UsernameRules::default()
+/// can be used as a starting point.
+///
+/// # DESCRIPTION #
+/// Prompts the user to type a username which will then be returned.
+/// In case the username breaks any of the rules, a message explaining which rule
+/// was broken will be shown and the user will be prompted to try again.
+///
+/// Provides an information message on the same line as the prompt if Some("...")
+/// is provided, and just the prompt if None is provided.
+///
+/// # RETURNS #
+/// A trimmed String value provided by the user which follows every rule.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::{read_username, UsernameRules};
+/// let rules = UsernameRules { reserved: &["root", "admin"], ..UsernameRules::default() };
+/// let user_name_with_msg = read_username(Some("Username: "), &rules);
+///
+/// let user_name: String = read_username(None, &UsernameRules::default());
+/// ```
+pub fn read_username(msg: Option<&str>, rules: &UsernameRules) -> String {
+    read_until_valid(msg, None, |input| {
+        check_username(input, rules).map(|_| input.to_string())
+    })
+}
+
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as the input prompt. Must be set to Some("...") or None.
+///
+/// 'candidates' (&[&str]) - values offered to the user besides the ones typed earlier.
+///
+/// # DESCRIPTION #
+/// Prompts the user to type a string of text which will then be returned, listing numbered
+/// suggestions before the prompt. Suggestions are the candidates followed by the answers
+/// previously given during the session to prompts with the same message (most recent first),
+/// without duplicates.
+///
+/// Typing '!' followed by the number of a suggestion (Ex: !2) selects it, so frequently
+/// re-entered values (hostnames, usernames...) can be picked without typing them again.
+/// Anything else is returned as typed.
+///
+/// Provides an information message on the same line as the prompt if Some(...)
+/// is provided, and just the prompt if None is provided.
+///
+/// # RETURNS #
+/// A trimmed String value provided (or selected) by the user.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::read_string_with_suggestions;
+/// let host = read_string_with_suggestions(Some("Host: "), &["localhost", "staging.example.com"]);
+/// ```
+pub fn read_string_with_suggestions(msg: Option<&str>, candidates: &[&str]) -> String {
+    let msg_text = msg.unwrap_or_default();
+    let mut suggestions: Vec<String> = Vec::new();
+    let answers = recent_answers(msg_text);
+    for suggestion in candidates.iter().copied().chain(answers.iter().map(String::as_str)) {
+        if !suggestions.iter().any(|previous| previous == suggestion) {
+            suggestions.push(suggestion.to_string());
+        }
+    }
+    suggestions.truncate(MAX_SUGGESTIONS);
+
+    if !suggestions.is_empty() {
+        let listed: Vec<String> = suggestions
+            .iter()
+            .enumerate()
+            .map(|(index, suggestion)| format!("[!{}] {suggestion}", index + 1))
+            .collect();
+        outln!("Suggestions: {}", listed.join("  "));
+    }
+
+    let input = read_string(msg);
+    let answer = input
+        .strip_prefix('!')
+        .and_then(|number| number.parse::<usize>().ok())
+        .and_then(|number| number.checked_sub(1))
+        .and_then(|index| suggestions.get(index))
+        .cloned()
+        .unwrap_or(input);
+
+    record_answer(msg_text, &answer, false);
+    answer
+}
+
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as the input prompt. Must be set to Some("...") or None.
+///
+/// 'err_msg' (Option<&str>) - an optional error message which will be printed
+/// if the user inputs an invalid value. Must be set to Some("...") or None.
+///
+/// # DESCRIPTION #
+/// Prompts the user to type the path of a key inside a configuration, which will then be
+/// split into its segments. Two syntaxes are accepted:
+/// - Dotted paths (Ex: server.tls.cert), whose segments can't be empty or contain spaces.
+/// - JSON pointers starting with '/' (Ex: /servers/0/name), where '~1' stands for '/'
+///   and '~0' for '~' inside a segment.
+///
+/// In case the user writes an invalid value, they will be prompted to try again.
+///
+/// Provides an information message on the same line as the prompt if Some("...")
+/// is provided, and just the prompt if None is provided.
+///
+/// If err_msg is set to None, a default message pointing out the problem will be shown.
+///
+/// # RETURNS #
+/// A Vec<String> with the segments of the path provided by the user.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::read_key_path;
+/// let user_path_with_msg = read_key_path(Some("Setting to change: "), None);
+///
+/// let user_path: Vec<String> = read_key_path(None, None);
+/// ```
+pub fn read_key_path(msg: Option<&str>, err_msg: Option<&str>) -> Vec<String> {
+    read_until_valid(msg, err_msg, parse_key_path)
+}
+
+fn parse_key_path(input: &str) -> Result<Vec<String>, String> {
+    if input.is_empty() {
+        return Err(String::from(
+            "Please enter a key path (Ex: server.tls.cert or /servers/0/name).",
+        ));
+    }
+
+    if let Some(pointer) = input.strip_prefix('/') {
+        return pointer.split('/').map(unescape_pointer_segment).collect();
+    }
+
+    input
+        .split('.')
+        .enumerate()
+        .map(|(index, segment)| match segment {
+            "" => Err(format!("Segment {} of the path is empty.", index + 1)),
+            segment if segment.contains(char::is_whitespace) => {
+                Err(format!("The segment '{segment}' contains spaces."))
+            }
+            segment => Ok(segment.to_string()),
+        })
+        .collect()
+}
+
+fn unescape_pointer_segment(segment: &str) -> Result<String, String> {
+    let mut unescaped = String::new();
+    let mut chars = segment.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '~' {
+            unescaped.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            Some('0') => unescaped.push('~'),
+            Some('1') => unescaped.push('/'),
+            _ => {
+                return Err(format!(
+                    "Invalid escape in '{segment}': '~' must be followed by 0 or 1."
+                ));
+            }
+        }
+    }
+
+    Ok(unescaped)
+}
+
+fn check_length(input: &str, min_len: usize, max_len: usize) -> Result<(), String> {
+    let len = input.chars().count();
+
+    if len == 0 && min_len > 0 {
+        return Err(String::from("Please enter a non-empty text."));
+    }
+
+    if len < min_len {
+        return Err(format!("The text must be at least {min_len} characters long."));
+    }
+
+    if len > max_len {
+        return Err(format!("The text must be at most {max_len} characters long."));
+    }
+
+    Ok(())
+}
+
+fn check_username(input: &str, rules: &UsernameRules) -> Result<(), String> {
+    let len = input.chars().count();
+
+    if len < rules.min_len {
+        return Err(format!(
+            "The username must be at least {} characters long.",
+            rules.min_len
+        ));
+    }
+
+    if len > rules.max_len {
+        return Err(format!(
+            "The username must be at most {} characters long.",
+            rules.max_len
+        ));
+    }
+
+    if rules.must_start_with_letter && !input.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        return Err(String::from("The username must start with a letter."));
+    }
+
+    if let Some(invalid) = input
+        .chars()
+        .find(|c| !c.is_ascii_alphanumeric() && !rules.allowed_symbols.contains(*c))
+    {
+        return Err(match rules.allowed_symbols {
+            "" => {
+                format!("The username contains '{invalid}', only letters and digits are allowed.")
+            }
+            symbols => format!(
+                "The username contains '{invalid}', only letters, digits and \"{symbols}\" are allowed."
+            ),
+        });
+    }
+
+    if rules
+        .reserved
+        .iter()
+        .any(|name| name.eq_ignore_ascii_case(input))
+    {
+        return Err(format!("The username '{input}' is reserved."));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Prompt;
+    use crate::testing::MockInput;
+
+    #[test]
+    fn key_paths_are_split() {
+        assert_eq!(
+            parse_key_path("server.tls.cert").unwrap(),
+            ["server", "tls", "cert"]
+        );
+        assert_eq!(parse_key_path("/a~1b/0/~0x").unwrap(), ["a/b", "0", "~x"]);
+        assert!(parse_key_path("server..cert").is_err());
+        assert!(parse_key_path("/a/~2").is_err());
+    }
+
+    #[test]
+    fn lengths_are_counted_in_characters() {
+        assert_eq!(check_length("ñandú", 5, 5), Ok(()));
+        assert_eq!(
+            check_length("", 1, 10),
+            Err(String::from("Please enter a non-empty text."))
+        );
+        assert_eq!(
+            check_length("ab", 3, 10),
+            Err(String::from("The text must be at least 3 characters long."))
+        );
+        assert_eq!(
+            check_length("abcd", 0, 3),
+            Err(String::from("The text must be at most 3 characters long."))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "The minimum length (5) is greater than the maximum length (3).")]
+    fn inverted_lengths_are_rejected() {
+        read_string_bounded(None, None, 5, 3);
+    }
+
+    #[test]
+    fn suggestions_come_from_the_same_prompt() {
+        let mut mock = MockInput::new(["8080", "db.internal", "!1", "!2"]);
+        mock.run(|| Prompt::new("Suggested port: ").text().read());
+        mock.run(|| read_string_with_suggestions(Some("Suggested host: "), &[]));
+
+        let first =
+            mock.run(|| read_string_with_suggestions(Some("Suggested host: "), &["localhost"]));
+        let second =
+            mock.run(|| read_string_with_suggestions(Some("Suggested host: "), &["localhost"]));
+
+        assert_eq!(first, "localhost");
+        assert_eq!(second, "db.internal");
+        assert!(mock.output().contains("Suggestions: [!1] localhost  [!2] db.internal\n"));
+        assert!(!mock.output().contains("] 8080"));
+        mock.assert_all_answered();
+    }
+
+    #[test]
+    fn username_rules_are_checked() {
+        let rules = UsernameRules {
+            reserved: &["root"],
+            ..UsernameRules::default()
+        };
+
+        assert!(check_username("kevin_c", &rules).is_ok());
+        assert!(
+            check_username("kc", &rules)
+                .unwrap_err()
+                .contains("at least 3")
+        );
+        assert!(
+            check_username("1kevin", &rules)
+                .unwrap_err()
+                .contains("start with a letter")
+        );
+        assert!(
+            check_username("kevin!", &rules)
+                .unwrap_err()
+                .contains("'!'")
+        );
+        assert!(
+            check_username("ROOT", &rules)
+                .unwrap_err()
+                .contains("reserved")
+        );
+    }
+}
@@ -0,0 +1,95 @@
+//! Readers for time related values.
+
+use chrono_tz::{TZ_VARIANTS, Tz};
+
+use crate::read_until_valid;
+use crate::reader::outln;
+use crate::suggest::{closest_matches, did_you_mean};
+
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as the input prompt. Must be set to Some("...") or None.
+///
+/// 'err_msg' (Option<&str>) - an optional error message which will be printed
+/// if the user inputs an invalid value. Must be set to Some("...") or None.
+///
+/// # DESCRIPTION #
+/// Prompts the user to type an IANA timezone identifier (Ex: Europe/Madrid) which will then be returned.
+/// This function is not case-sensitive, and partial names (Ex: madrid) are accepted as long as
+/// they only match one timezone, in which case the full name is shown to the user.
+/// In case the user writes an invalid or ambiguous value, they will be prompted to try again.
+///
+/// Provides an information message on the same line as the prompt if Some("...")
+/// is provided, and just the prompt if None is provided.
+///
+/// If err_msg is set to None, a default message listing similar timezones will be shown.
+///
+/// # RETURNS #
+/// A timezone (chrono_tz::Tz) provided by the user.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::read_timezone;
+/// let user_tz_with_msg = read_timezone(Some("Timezone: "), Some("Please input a valid timezone."));
+///
+/// let user_tz: chrono_tz::Tz = read_timezone(None, None);
+/// ```
+pub fn read_timezone(msg: Option<&str>, err_msg: Option<&str>) -> Tz {
+    // The closure may run more than once per answer, so the full name is shown afterwards.
+    let (tz, partial) = read_until_valid(msg, err_msg, |input| {
+        parse_timezone(input).map(|tz| (tz, !tz.name().eq_ignore_ascii_case(input)))
+    });
+
+    if partial {
+        outln!("Using {}.", tz.name());
+    }
+
+    tz
+}
+
+fn parse_timezone(input: &str) -> Result<Tz, String> {
+    if input.is_empty() {
+        return Err(String::from("Please enter a timezone (Ex: Europe/Madrid)."));
+    }
+
+    if let Some(tz) = TZ_VARIANTS
+        .iter()
+        .find(|tz| tz.name().eq_ignore_ascii_case(input))
+    {
+        return Ok(*tz);
+    }
+
+    let needle = input.to_lowercase().replace(' ', "_");
+    let partial: Vec<&Tz> = TZ_VARIANTS
+        .iter()
+        .filter(|tz| tz.name().to_lowercase().contains(&needle))
+        .collect();
+
+    match partial.as_slice() {
+        [tz] => Ok(**tz),
+        [] => {
+            let suggestions = closest_matches(input, TZ_VARIANTS.iter().map(|tz| tz.name()), 3);
+            Err(format!("Unknown timezone.{}", did_you_mean(&suggestions)))
+        }
+        several => {
+            let names: Vec<&str> = several.iter().take(5).map(|tz| tz.name()).collect();
+            Err(format!("Ambiguous timezone.{}", did_you_mean(&names)))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::MockInput;
+
+    #[test]
+    fn partial_names_are_completed_once() {
+        let mut mock = MockInput::new(["madrid", "Europe/Paris"]);
+
+        assert_eq!(mock.run(|| read_timezone(None, None)), Tz::Europe__Madrid);
+        assert_eq!(mock.run(|| read_timezone(None, None)), Tz::Europe__Paris);
+        assert_eq!(mock.output().matches("Using ").count(), 1);
+        assert!(mock.output().contains("Using Europe/Madrid.\n"));
+    }
+}
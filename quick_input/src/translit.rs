@@ -0,0 +1,175 @@
+//! Transliteration of answers to plain ASCII, and readers built on it.
+
+use crate::Prompt;
+
+/// # ARGUMENTS #
+/// 'text' (&str) - text to transliterate.
+///
+/// # DESCRIPTION #
+/// Converts the text to plain ASCII: letters with diacritics lose them (Ex: 'é' becomes 'e'),
+/// letters with a common ASCII spelling are replaced by it (Ex: 'ß' becomes "ss" and 'Æ'
+/// becomes "AE") and any other non-ASCII character is removed.
+///
+/// It can be plugged into a text Prompt with normalize or suggest_correction.
+///
+/// # RETURNS #
+/// The transliterated String.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::{Prompt, to_ascii};
+/// let author: String = Prompt::new("Author: ").text().normalize(to_ascii).read();
+/// ```
+pub fn to_ascii(text: &str) -> String {
+    let mut ascii = String::with_capacity(text.len());
+    for c in text.chars() {
+        match transliterate_char(c) {
+            Some(replacement) => ascii.push_str(replacement),
+            None if c.is_ascii() => ascii.push(c),
+            None => {}
+        }
+    }
+    ascii
+}
+
+/// # ARGUMENTS #
+/// 'text' (&str) - text to convert.
+///
+/// # DESCRIPTION #
+/// Converts the text to a slug which is safe to use in URLs and file names: it is
+/// transliterated with to_ascii and lowercased, and every run of characters other than
+/// letters and digits becomes a single '-', never at the start or the end
+/// (Ex: "Crème Brûlée: 2nd try!" becomes "creme-brulee-2nd-try").
+///
+/// # RETURNS #
+/// The slug, which is empty if the text has no letters or digits.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::slugify;
+/// assert_eq!(slugify("Hello, World!"), "hello-world");
+/// ```
+pub fn slugify(text: &str) -> String {
+    let mut slug = String::with_capacity(text.len());
+    for c in to_ascii(text).chars() {
+        if c.is_ascii_alphanumeric() {
+            slug.push(c.to_ascii_lowercase());
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+
+    let len = slug.trim_end_matches('-').len();
+    slug.truncate(len);
+    slug
+}
+
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as the input prompt. Must be set to Some("...") or None.
+///
+/// # DESCRIPTION #
+/// Prompts the user to type an identifier (Ex: the name of a blog post or a project), which
+/// is converted with slugify. If the slug differs from what the user typed, it is shown and
+/// the user is asked to confirm it ("Did you mean 'my-project'? [Y/n]"). Declining it, or
+/// typing something with no letters or digits, prompts the user to try again.
+///
+/// # RETURNS #
+/// A slug made of lowercase ASCII letters, digits and single '-' between them.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::read_slug;
+/// let slug = read_slug(Some("Post title: "));
+///
+/// let file_name = format!("{slug}.md");
+/// ```
+pub fn read_slug(msg: Option<&str>) -> String {
+    Prompt::new(msg.unwrap_or_default())
+        .text()
+        .suggest_correction(|text| match slugify(text) {
+            slug if slug.is_empty() => text.to_string(),
+            slug => slug,
+        })
+        .validate(|slug| match slug.is_empty() || *slug != slugify(slug) {
+            true => Err(String::from(
+                "Please enter a name with letters or digits, or accept the suggested one.",
+            )),
+            false => Ok(()),
+        })
+        .read()
+}
+
+/// Returns the ASCII spelling of a letter written with diacritics or a ligature, if it has one.
+fn transliterate_char(c: char) -> Option<&'static str> {
+    let replacement = match c {
+        'À' | 'Á' | 'Â' | 'Ã' | 'Ä' | 'Å' | 'Ā' | 'Ă' | 'Ą' => "A",
+        'à' | 'á' | 'â' | 'ã' | 'ä' | 'å' | 'ā' | 'ă' | 'ą' => "a",
+        'Ç' | 'Ć' | 'Ĉ' | 'Ċ' | 'Č' => "C",
+        'ç' | 'ć' | 'ĉ' | 'ċ' | 'č' => "c",
+        'Ď' | 'Đ' | 'Ð' => "D",
+        'ď' | 'đ' | 'ð' => "d",
+        'È' | 'É' | 'Ê' | 'Ë' | 'Ē' | 'Ĕ' | 'Ė' | 'Ę' | 'Ě' => "E",
+        'è' | 'é' | 'ê' | 'ë' | 'ē' | 'ĕ' | 'ė' | 'ę' | 'ě' => "e",
+        'Ĝ' | 'Ğ' | 'Ġ' | 'Ģ' => "G",
+        'ĝ' | 'ğ' | 'ġ' | 'ģ' => "g",
+        'Ĥ' | 'Ħ' => "H",
+        'ĥ' | 'ħ' => "h",
+        'Ì' | 'Í' | 'Î' | 'Ï' | 'Ĩ' | 'Ī' | 'Ĭ' | 'Į' | 'İ' => "I",
+        'ì' | 'í' | 'î' | 'ï' | 'ĩ' | 'ī' | 'ĭ' | 'į' | 'ı' => "i",
+        'Ĵ' => "J",
+        'ĵ' => "j",
+        'Ķ' => "K",
+        'ķ' => "k",
+        'Ĺ' | 'Ļ' | 'Ľ' | 'Ŀ' | 'Ł' => "L",
+        'ĺ' | 'ļ' | 'ľ' | 'ŀ' | 'ł' => "l",
+        'Ñ' | 'Ń' | 'Ņ' | 'Ň' => "N",
+        'ñ' | 'ń' | 'ņ' | 'ň' => "n",
+        'Ò' | 'Ó' | 'Ô' | 'Õ' | 'Ö' | 'Ø' | 'Ō' | 'Ŏ' | 'Ő' => "O",
+        'ò' | 'ó' | 'ô' | 'õ' | 'ö' | 'ø' | 'ō' | 'ŏ' | 'ő' => "o",
+        'Ŕ' | 'Ŗ' | 'Ř' => "R",
+        'ŕ' | 'ŗ' | 'ř' => "r",
+        'Ś' | 'Ŝ' | 'Ş' | 'Š' | 'Ș' => "S",
+        'ś' | 'ŝ' | 'ş' | 'š' | 'ș' => "s",
+        'Ţ' | 'Ť' | 'Ŧ' | 'Ț' => "T",
+        'ţ' | 'ť' | 'ŧ' | 'ț' => "t",
+        'Ù' | 'Ú' | 'Û' | 'Ü' | 'Ũ' | 'Ū' | 'Ŭ' | 'Ů' | 'Ű' | 'Ų' => "U",
+        'ù' | 'ú' | 'û' | 'ü' | 'ũ' | 'ū' | 'ŭ' | 'ů' | 'ű' | 'ų' => "u",
+        'Ŵ' => "W",
+        'ŵ' => "w",
+        'Ý' | 'Ÿ' | 'Ŷ' => "Y",
+        'ý' | 'ÿ' | 'ŷ' => "y",
+        'Ź' | 'Ż' | 'Ž' => "Z",
+        'ź' | 'ż' | 'ž' => "z",
+        'Æ' => "AE",
+        'æ' => "ae",
+        'Œ' => "OE",
+        'œ' => "oe",
+        'Þ' => "TH",
+        'þ' => "th",
+        'ß' => "ss",
+        _ => return None,
+    };
+    Some(replacement)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn text_is_transliterated() {
+        assert_eq!(
+            to_ascii("Ångström, Łódź & Straße"),
+            "Angstrom, Lodz & Strasse"
+        );
+        assert_eq!(to_ascii("日本 café"), " cafe");
+    }
+
+    #[test]
+    fn slugs_have_single_dashes() {
+        assert_eq!(slugify("Crème Brûlée: 2nd try!"), "creme-brulee-2nd-try");
+        assert_eq!(slugify("  --Hello__World--  "), "hello-world");
+        assert_eq!(slugify("!!!"), "");
+    }
+}
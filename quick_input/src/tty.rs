@@ -0,0 +1,544 @@
+//! Low level terminal helpers.
+
+#[cfg(unix)]
+use std::cell::UnsafeCell;
+use std::io::{self, Write};
+#[cfg(unix)]
+use std::mem::MaybeUninit;
+#[cfg(unix)]
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, Once};
+
+use crate::{reader, term};
+
+/// Settings of the terminal attached to stdin.
+#[cfg(unix)]
+type TerminalState = libc::termios;
+#[cfg(windows)]
+type TerminalState = u32;
+#[cfg(not(any(unix, windows)))]
+type TerminalState = ();
+
+/// Settings saved by the outermost TerminalGuard alive, restored by the panic hook.
+static SAVED_STATE: Mutex<Option<SavedState>> = Mutex::new(None);
+
+static PANIC_HOOK: Once = Once::new();
+
+struct SavedState {
+    terminal: Option<TerminalState>,
+    cursor_hidden: bool,
+}
+
+/// Restores the terminal when dropped: the settings of the terminal attached to stdin (echo,
+/// raw mode...) go back to how they were when the guard was created, and the cursor is shown
+/// again if it was hidden with hide_cursor.
+///
+/// The settings are restored as well if the program panics while the guard is alive, even
+/// when panics abort instead of unwinding, or (on Unix) if it is ended by a signal such as
+/// SIGINT (Ctrl+C) or SIGTERM, so a crashing program never leaves the user's terminal
+/// unusable. Signals the program handles or ignores on its own are left untouched.
+///
+/// Every feature of this crate changing the terminal settings uses one internally, and
+/// programs changing them on their own (Ex: to draw a full-screen interface) can create one
+/// first.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::TerminalGuard;
+/// let guard = TerminalGuard::new();
+/// guard.hide_cursor();
+///
+/// // Draw a progress bar, change the terminal settings...
+///
+/// drop(guard); // The terminal is back to normal, even if the code above panicked.
+/// ```
+pub struct TerminalGuard {
+    original: Option<TerminalState>,
+    outermost: bool,
+}
+
+impl TerminalGuard {
+    /// Saves the current settings of the terminal, which will be restored once the guard
+    /// is dropped. Nothing is saved if stdin is not a terminal.
+    pub fn new() -> TerminalGuard {
+        PANIC_HOOK.call_once(install_panic_hook);
+
+        let original = current_state();
+        let mut saved = lock_saved_state();
+        let outermost = saved.is_none();
+        if outermost {
+            *saved = Some(SavedState {
+                terminal: original,
+                cursor_hidden: false,
+            });
+            #[cfg(unix)]
+            if let Some(terminal) = &original {
+                SIGNAL_HANDLERS.call_once(install_signal_handlers);
+                SIGNAL_STATE.save(terminal);
+            }
+        }
+
+        TerminalGuard {
+            original,
+            outermost,
+        }
+    }
+
+    /// Hides the cursor of the terminal attached to stdout until the guard (or the outermost
+    /// guard alive, if they are nested) is dropped.
+    /// Nothing happens if the cursor can't be moved (see term::supports_cursor_movement).
+    pub fn hide_cursor(&self) {
+        if !term::supports_cursor_movement() {
+            return;
+        }
+
+        if let Some(saved) = lock_saved_state().as_mut() {
+            saved.cursor_hidden = true;
+        }
+        #[cfg(unix)]
+        SIGNAL_STATE.cursor_hidden.store(true, Ordering::SeqCst);
+        let mut stdout = io::stdout();
+        let _ = write!(stdout, "\x1b[?25l").and_then(|_| stdout.flush());
+    }
+
+    /// Settings saved when the guard was created, or None if stdin is not a terminal.
+    fn original(&self) -> Option<TerminalState> {
+        self.original
+    }
+}
+
+impl Default for TerminalGuard {
+    fn default() -> Self {
+        TerminalGuard::new()
+    }
+}
+
+impl Drop for TerminalGuard {
+    fn drop(&mut self) {
+        if let Some(original) = &self.original {
+            apply_state(original);
+        }
+
+        if self.outermost {
+            #[cfg(unix)]
+            SIGNAL_STATE.clear();
+            let saved = lock_saved_state().take();
+            if saved.is_some_and(|saved| saved.cursor_hidden) {
+                show_cursor();
+            }
+        }
+    }
+}
+
+fn lock_saved_state() -> std::sync::MutexGuard<'static, Option<SavedState>> {
+    SAVED_STATE
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Restores the saved settings before the panic message is printed, keeping the
+/// previous hook (usually the default one) to print it.
+fn install_panic_hook() {
+    let previous = std::panic::take_hook();
+
+    std::panic::set_hook(Box::new(move |info| {
+        // try_lock: the panic may have happened while the state was locked.
+        if let Some(saved) = SAVED_STATE
+            .try_lock()
+            .ok()
+            .and_then(|mut saved| saved.take())
+        {
+            if let Some(terminal) = &saved.terminal {
+                apply_state(terminal);
+            }
+            if saved.cursor_hidden {
+                show_cursor();
+            }
+        }
+
+        previous(info);
+    }));
+}
+
+/// Copy of the settings saved by the outermost TerminalGuard alive, read by the signal
+/// handler, which can't lock SAVED_STATE (a signal may arrive while it is locked).
+#[cfg(unix)]
+static SIGNAL_STATE: SignalState = SignalState {
+    terminal: UnsafeCell::new(MaybeUninit::uninit()),
+    saved: AtomicBool::new(false),
+    cursor_hidden: AtomicBool::new(false),
+};
+
+#[cfg(unix)]
+static SIGNAL_HANDLERS: Once = Once::new();
+
+/// Signals ending the process by default which are common while waiting for the user.
+#[cfg(unix)]
+const RESTORING_SIGNALS: [libc::c_int; 4] =
+    [libc::SIGINT, libc::SIGTERM, libc::SIGQUIT, libc::SIGHUP];
+
+#[cfg(unix)]
+struct SignalState {
+    terminal: UnsafeCell<MaybeUninit<libc::termios>>,
+    /// Whether 'terminal' holds settings, only true once they are fully written.
+    saved: AtomicBool,
+    cursor_hidden: AtomicBool,
+}
+
+// SAFETY: 'terminal' is only written while SAVED_STATE is locked and 'saved' is false,
+// and only read (by the signal handler) while 'saved' is true.
+#[cfg(unix)]
+unsafe impl Sync for SignalState {}
+
+#[cfg(unix)]
+impl SignalState {
+    /// Must be called while SAVED_STATE is locked.
+    fn save(&self, terminal: &libc::termios) {
+        self.saved.store(false, Ordering::SeqCst);
+        // SAFETY: see the Sync implementation.
+        unsafe { (*self.terminal.get()).write(*terminal) };
+        self.saved.store(true, Ordering::SeqCst);
+    }
+
+    fn clear(&self) {
+        self.saved.store(false, Ordering::SeqCst);
+        self.cursor_hidden.store(false, Ordering::SeqCst);
+    }
+}
+
+/// Installs restore_on_signal for the signals which would end the process right away,
+/// leaving alone those the program handles or ignores.
+#[cfg(unix)]
+fn install_signal_handlers() {
+    use std::{mem, ptr};
+
+    for signal in RESTORING_SIGNALS {
+        // SAFETY: sigaction only reads 'action' and writes into 'previous', both valid
+        // structs, and restore_on_signal only does async-signal-safe calls.
+        unsafe {
+            let mut previous: libc::sigaction = mem::zeroed();
+            if libc::sigaction(signal, ptr::null(), &mut previous) != 0
+                || previous.sa_sigaction != libc::SIG_DFL
+            {
+                continue;
+            }
+
+            let mut action: libc::sigaction = mem::zeroed();
+            action.sa_sigaction = restore_on_signal as extern "C" fn(libc::c_int) as usize;
+            libc::sigemptyset(&mut action.sa_mask);
+            libc::sigaction(signal, &action, ptr::null_mut());
+        }
+    }
+}
+
+/// Restores the saved settings and shows the cursor, and then lets the signal end the
+/// process as it would have without the handler.
+#[cfg(unix)]
+extern "C" fn restore_on_signal(signal: libc::c_int) {
+    // SAFETY: only async-signal-safe functions are called, and the saved settings are only
+    // read once fully written (see SignalState).
+    unsafe {
+        if SIGNAL_STATE.saved.load(Ordering::SeqCst) {
+            let terminal = (*SIGNAL_STATE.terminal.get()).as_ptr();
+            libc::tcsetattr(libc::STDIN_FILENO, libc::TCSANOW, terminal);
+        }
+        if SIGNAL_STATE.cursor_hidden.load(Ordering::SeqCst) {
+            let show = b"\x1b[?25h";
+            libc::write(libc::STDOUT_FILENO, show.as_ptr().cast(), show.len());
+        }
+
+        // The signal is blocked while it is handled, so it is delivered again (with the
+        // default action) as soon as this handler returns.
+        libc::signal(signal, libc::SIG_DFL);
+        libc::raise(signal);
+    }
+}
+
+fn show_cursor() {
+    let mut stdout = io::stdout();
+    let _ = write!(stdout, "\x1b[?25h").and_then(|_| stdout.flush());
+}
+
+/// # Returns #
+/// The current settings of the terminal attached to stdin, or None if stdin is not a terminal.
+#[cfg(unix)]
+fn current_state() -> Option<TerminalState> {
+    let mut termios = MaybeUninit::<libc::termios>::uninit();
+
+    // SAFETY: tcgetattr only writes into the provided termios struct, which is
+    // only read after checking that the call succeeded.
+    if unsafe { libc::tcgetattr(libc::STDIN_FILENO, termios.as_mut_ptr()) } != 0 {
+        return None;
+    }
+
+    // SAFETY: tcgetattr succeeded, so the struct has been initialised.
+    Some(unsafe { termios.assume_init() })
+}
+
+#[cfg(unix)]
+fn apply_state(state: &TerminalState) {
+    // SAFETY: the termios struct holds settings read from this same terminal.
+    unsafe { libc::tcsetattr(libc::STDIN_FILENO, libc::TCSANOW, state) };
+}
+
+/// # Returns #
+/// The current mode of the console attached to stdin, or None if stdin is not a console.
+#[cfg(windows)]
+fn current_state() -> Option<TerminalState> {
+    use windows_sys::Win32::System::Console::{GetConsoleMode, GetStdHandle, STD_INPUT_HANDLE};
+
+    let mut mode = 0;
+
+    // SAFETY: GetStdHandle has no preconditions and GetConsoleMode only writes into 'mode'.
+    let handle = unsafe { GetStdHandle(STD_INPUT_HANDLE) };
+    if unsafe { GetConsoleMode(handle, &mut mode) } == 0 {
+        return None;
+    }
+
+    Some(mode)
+}
+
+#[cfg(windows)]
+fn apply_state(state: &TerminalState) {
+    use windows_sys::Win32::System::Console::{GetStdHandle, STD_INPUT_HANDLE, SetConsoleMode};
+
+    // SAFETY: 'state' holds a mode read from this same console.
+    unsafe { SetConsoleMode(GetStdHandle(STD_INPUT_HANDLE), *state) };
+}
+
+#[cfg(not(any(unix, windows)))]
+fn current_state() -> Option<TerminalState> {
+    None
+}
+
+#[cfg(not(any(unix, windows)))]
+fn apply_state(_state: &TerminalState) {}
+
+/// Keeps the echo of typed characters disabled while it is alive,
+/// restoring the previous terminal settings once dropped.
+pub(crate) struct HiddenInput {
+    _guard: TerminalGuard,
+}
+
+impl HiddenInput {
+    /// # Description #
+    /// Disables the echo of typed characters on the terminal attached to stdin.
+    ///
+    /// # Returns #
+    /// None if stdin is not a terminal (Ex: input piped from a file), in which case
+    /// there is nothing to hide.
+    pub(crate) fn start() -> Option<HiddenInput> {
+        if reader::is_active() {
+            return None;
+        }
+
+        let guard = TerminalGuard::new();
+        let original = guard.original()?;
+
+        #[cfg(unix)]
+        {
+            let mut hidden = original;
+            hidden.c_lflag &= !libc::ECHO;
+            hidden.c_lflag |= libc::ECHONL;
+            apply_state(&hidden);
+        }
+
+        #[cfg(windows)]
+        {
+            use windows_sys::Win32::System::Console::ENABLE_ECHO_INPUT;
+            apply_state(&(original & !ENABLE_ECHO_INPUT));
+        }
+
+        #[cfg(not(any(unix, windows)))]
+        let () = original;
+
+        Some(HiddenInput { _guard: guard })
+    }
+}
+
+impl Drop for HiddenInput {
+    #[cfg(windows)]
+    fn drop(&mut self) {
+        // The console does not echo the Enter key while echo is disabled.
+        reader::outln!();
+    }
+
+    #[cfg(not(windows))]
+    fn drop(&mut self) {}
+}
+
+/// Puts the terminal attached to stdin in raw mode while it is alive, so every key is
+/// received as soon as it is pressed and nothing is echoed. The previous terminal settings
+/// are restored once dropped.
+pub(crate) struct RawMode {
+    _guard: TerminalGuard,
+}
+
+impl RawMode {
+    /// # Returns #
+    /// None if stdin is not a terminal, or raw mode is not supported on this platform.
+    #[cfg(unix)]
+    pub(crate) fn start() -> Option<RawMode> {
+        if reader::is_active() {
+            return None;
+        }
+
+        let guard = TerminalGuard::new();
+
+        let mut raw = guard.original()?;
+        raw.c_lflag &= !(libc::ICANON | libc::ECHO | libc::ISIG | libc::IEXTEN);
+        raw.c_iflag &= !(libc::IXON | libc::ICRNL);
+        raw.c_cc[libc::VMIN] = 1;
+        raw.c_cc[libc::VTIME] = 0;
+        apply_state(&raw);
+
+        Some(RawMode { _guard: guard })
+    }
+
+    #[cfg(not(unix))]
+    pub(crate) fn start() -> Option<RawMode> {
+        None
+    }
+}
+
+/// # Description #
+/// Sends SIGINT to the current process, as the terminal would when Ctrl+C is pressed
+/// outside of raw mode. Does nothing on platforms without signals.
+pub(crate) fn raise_interrupt() {
+    #[cfg(unix)]
+    // SAFETY: raise has no preconditions.
+    unsafe {
+        libc::raise(libc::SIGINT);
+    }
+}
+
+/// # Returns #
+/// The number of columns of the terminal attached to stdout, or None if stdout is not
+/// a terminal. The COLUMNS environment variable takes precedence when it is set.
+pub(crate) fn terminal_width() -> Option<usize> {
+    if let Some(columns) = std::env::var("COLUMNS")
+        .ok()
+        .and_then(|columns| columns.parse().ok())
+    {
+        return Some(columns);
+    }
+
+    query_terminal_width().filter(|&width| width > 0)
+}
+
+#[cfg(unix)]
+fn query_terminal_width() -> Option<usize> {
+    let mut size = std::mem::MaybeUninit::<libc::winsize>::uninit();
+
+    // SAFETY: TIOCGWINSZ only writes into the provided winsize struct, which is
+    // only read after checking that the call succeeded.
+    if unsafe { libc::ioctl(libc::STDOUT_FILENO, libc::TIOCGWINSZ, size.as_mut_ptr()) } != 0 {
+        return None;
+    }
+
+    // SAFETY: ioctl succeeded, so the struct has been initialised.
+    Some(usize::from(unsafe { size.assume_init() }.ws_col))
+}
+
+#[cfg(windows)]
+fn query_terminal_width() -> Option<usize> {
+    use windows_sys::Win32::System::Console::{
+        CONSOLE_SCREEN_BUFFER_INFO, GetConsoleScreenBufferInfo, GetStdHandle, STD_OUTPUT_HANDLE,
+    };
+
+    let mut info = std::mem::MaybeUninit::<CONSOLE_SCREEN_BUFFER_INFO>::uninit();
+
+    // SAFETY: GetConsoleScreenBufferInfo only writes into 'info', which is
+    // only read after checking that the call succeeded.
+    if unsafe { GetConsoleScreenBufferInfo(GetStdHandle(STD_OUTPUT_HANDLE), info.as_mut_ptr()) }
+        == 0
+    {
+        return None;
+    }
+
+    // SAFETY: the call succeeded, so the struct has been initialised.
+    let window = unsafe { info.assume_init() }.srWindow;
+    usize::try_from(window.Right - window.Left + 1).ok()
+}
+
+#[cfg(not(any(unix, windows)))]
+fn query_terminal_width() -> Option<usize> {
+    None
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use std::ffi::CStr;
+    use std::fs::{File, OpenOptions};
+    use std::os::fd::AsRawFd;
+    use std::os::unix::process::ExitStatusExt;
+    use std::process::{Command, Stdio};
+
+    /// Set when the test binary is started again by interrupted_hidden_input_restores_echo.
+    const CHILD_VAR: &str = "QUICK_INPUT_INTERRUPTED_CHILD";
+
+    /// Opens a new pseudo-terminal, returning its controller and the terminal itself.
+    fn open_pty() -> (File, File) {
+        use std::os::fd::FromRawFd;
+
+        // SAFETY: the returned descriptor is checked before being owned by a File, and
+        // ptsname returns a valid C string once grantpt and unlockpt succeeded.
+        unsafe {
+            let controller = libc::posix_openpt(libc::O_RDWR | libc::O_NOCTTY);
+            assert!(controller >= 0, "Unable to open a pseudo-terminal.");
+            let controller = File::from_raw_fd(controller);
+            assert_eq!(libc::grantpt(controller.as_raw_fd()), 0);
+            assert_eq!(libc::unlockpt(controller.as_raw_fd()), 0);
+            let path = CStr::from_ptr(libc::ptsname(controller.as_raw_fd()));
+
+            let terminal = OpenOptions::new()
+                .read(true)
+                .write(true)
+                .open(path.to_str().unwrap())
+                .unwrap();
+            (controller, terminal)
+        }
+    }
+
+    fn echo_enabled(terminal: &File) -> bool {
+        let mut termios = MaybeUninit::<libc::termios>::uninit();
+
+        // SAFETY: tcgetattr only writes into the struct, read once the call succeeded.
+        unsafe {
+            assert_eq!(
+                libc::tcgetattr(terminal.as_raw_fd(), termios.as_mut_ptr()),
+                0
+            );
+            termios.assume_init().c_lflag & libc::ECHO != 0
+        }
+    }
+
+    #[test]
+    fn interrupted_hidden_input_restores_echo() {
+        if std::env::var_os(CHILD_VAR).is_some() {
+            // Runs in the child process, whose stdin is the pseudo-terminal.
+            let _hidden = HiddenInput::start().expect("stdin is not a terminal");
+            raise_interrupt();
+            unreachable!("SIGINT did not end the process");
+        }
+
+        let (_controller, terminal) = open_pty();
+        assert!(echo_enabled(&terminal));
+
+        let status = Command::new(std::env::current_exe().unwrap())
+            .args([
+                "--exact",
+                "tty::tests::interrupted_hidden_input_restores_echo",
+            ])
+            .env(CHILD_VAR, "1")
+            .stdin(terminal.try_clone().unwrap())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status()
+            .unwrap();
+
+        assert_eq!(status.signal(), Some(libc::SIGINT));
+        assert!(echo_enabled(&terminal));
+    }
+}
@@ -0,0 +1,154 @@
+//! Readers asking for the same value twice, to catch typing mistakes.
+
+use std::str::FromStr;
+
+use crate::tty::HiddenInput;
+use crate::{read_hidden_until_valid, read_until_valid, show_error_message};
+
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as the first input prompt. Must be set to Some("...") or None.
+///
+/// 'confirm_msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as the second input prompt. Must be set to Some("...") or None.
+///
+/// 'mismatch_msg' (Option<&str>) - an optional error message which will be printed
+/// if both values are different. Must be set to Some("...") or None.
+///
+/// # DESCRIPTION #
+/// Prompts the user to type a value twice (Ex: an account number), looping on each prompt
+/// until the input can be parsed as T. If both values are different, the mismatch message is
+/// shown and the user is asked for both values again. Values are compared once parsed, so
+/// 0042 and 42 match when T is a number.
+///
+/// If mismatch_msg is set to None, a default message will be shown.
+///
+/// # RETURNS #
+/// The value of type T typed twice by the user.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::read_twice;
+/// let account: u64 = read_twice(
+///     Some("Account number: "),
+///     Some("Repeat the account number: "),
+///     Some("The account numbers do not match."),
+/// );
+/// ```
+pub fn read_twice<T: FromStr + PartialEq>(
+    msg: Option<&str>,
+    confirm_msg: Option<&str>,
+    mismatch_msg: Option<&str>,
+) -> T {
+    read_both(msg, confirm_msg, mismatch_msg, false)
+}
+
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as the first input prompt. Must be set to Some("...") or None.
+///
+/// 'confirm_msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as the second input prompt. Must be set to Some("...") or None.
+///
+/// 'mismatch_msg' (Option<&str>) - an optional error message which will be printed
+/// if both values are different. Must be set to Some("...") or None.
+///
+/// # DESCRIPTION #
+/// Works like read_twice, but the typed characters are not echoed to the terminal,
+/// which suits new PINs. The answers are sensitive, so they never go through the
+/// post-processors, and they are not trimmed, since spaces may be part of them. For new passwords, read_password_confirmed also zeroes the passwords
+/// which did not match.
+///
+/// # RETURNS #
+/// The value of type T typed twice by the user.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::read_hidden_twice;
+/// let password: String = read_hidden_twice(Some("New password: "), Some("Repeat it: "), None);
+///
+/// let pin: u16 = read_hidden_twice(Some("PIN: "), Some("Repeat the PIN: "), None);
+/// ```
+pub fn read_hidden_twice<T: FromStr + PartialEq>(
+    msg: Option<&str>,
+    confirm_msg: Option<&str>,
+    mismatch_msg: Option<&str>,
+) -> T {
+    let _hidden = HiddenInput::start();
+    read_both(msg, confirm_msg, mismatch_msg, true)
+}
+
+fn read_both<T: FromStr + PartialEq>(
+    msg: Option<&str>,
+    confirm_msg: Option<&str>,
+    mismatch_msg: Option<&str>,
+    hidden: bool,
+) -> T {
+    loop {
+        let first = read_value(msg, hidden);
+        let second = read_value(confirm_msg, hidden);
+
+        if first == second {
+            return first;
+        }
+        show_error_message(mismatch_msg, "The values do not match. Please try again.");
+    }
+}
+
+fn read_value<T: FromStr>(msg: Option<&str>, hidden: bool) -> T {
+    let parse = |input: &str| {
+        input
+            .parse()
+            .map_err(|_| String::from("Please enter a valid value."))
+    };
+
+    match hidden {
+        true => read_hidden_until_valid(msg, None, parse),
+        false => read_until_valid(msg, None, parse),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::add_post_processor;
+    use crate::testing::MockInput;
+    use std::sync::Mutex;
+
+    #[test]
+    fn both_values_are_asked_again_until_they_match() {
+        let mut mock = MockInput::new(["1", "2", "x", "3", "3"]);
+        let value: u32 = mock.run(|| read_twice(Some("Value: "), Some("Repeat it: "), None));
+
+        assert_eq!(value, 3);
+        mock.assert_prompted_times("Value: ", 3);
+        mock.assert_rejected("The values do not match. Please try again.");
+        mock.assert_rejected("Please enter a valid value.");
+    }
+
+    #[test]
+    fn values_are_compared_once_parsed() {
+        let mut mock = MockInput::new(["0042", "42"]);
+        let value: u16 = mock.run(|| read_twice(None, None, None));
+
+        assert_eq!(value, 42);
+        mock.assert_all_answered();
+    }
+
+    #[test]
+    fn hidden_values_never_reach_the_processors() {
+        static SEEN: Mutex<Vec<String>> = Mutex::new(Vec::new());
+        add_post_processor(|prompt, answer| {
+            if prompt.message == "New PIN: " {
+                SEEN.lock().unwrap().push(answer.clone());
+            }
+            answer
+        });
+
+        let mut mock = MockInput::new(["1234", "1234"]);
+        let pin: u16 = mock.run(|| read_hidden_twice(Some("New PIN: "), Some("New PIN: "), None));
+
+        assert_eq!(pin, 1234);
+        assert!(SEEN.lock().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,301 @@
+//! Semantic versions and an interactive helper to bump them.
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::{dry_run, read_choice_map_with_default, read_until_valid};
+use crate::reader::outln;
+
+/// A semantic version (Ex: 1.4.2 or 2.0.0-rc.1), as described at semver.org.
+///
+/// Versions are ordered by precedence: a pre-release comes before the release it precedes
+/// (Ex: 2.0.0-rc.1 < 2.0.0). Build metadata (Ex: +build.5) is not supported.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Version {
+    pub major: u64,
+    pub minor: u64,
+    pub patch: u64,
+    /// Pre-release identifiers separated by '.' (Ex: "rc.1"), or None for a release.
+    pub pre: Option<String>,
+}
+
+impl Version {
+    /// Creates the release version major.minor.patch.
+    pub fn new(major: u64, minor: u64, patch: u64) -> Self {
+        Version {
+            major,
+            minor,
+            patch,
+            pre: None,
+        }
+    }
+
+    /// # RETURNS #
+    /// The next major version (Ex: 1.4.2 becomes 2.0.0). The pre-release of a major
+    /// version is released instead (Ex: 2.0.0-rc.1 becomes 2.0.0).
+    pub fn bump_major(&self) -> Version {
+        match (&self.pre, self.minor, self.patch) {
+            (Some(_), 0, 0) => Version::new(self.major, 0, 0),
+            _ => Version::new(self.major + 1, 0, 0),
+        }
+    }
+
+    /// # RETURNS #
+    /// The next minor version (Ex: 1.4.2 becomes 1.5.0). The pre-release of a minor
+    /// version is released instead (Ex: 1.5.0-beta becomes 1.5.0).
+    pub fn bump_minor(&self) -> Version {
+        match (&self.pre, self.patch) {
+            (Some(_), 0) => Version::new(self.major, self.minor, 0),
+            _ => Version::new(self.major, self.minor + 1, 0),
+        }
+    }
+
+    /// # RETURNS #
+    /// The next patch version (Ex: 1.4.2 becomes 1.4.3). A pre-release is released
+    /// instead (Ex: 1.4.3-rc.2 becomes 1.4.3).
+    pub fn bump_patch(&self) -> Version {
+        match &self.pre {
+            Some(_) => Version::new(self.major, self.minor, self.patch),
+            None => Version::new(self.major, self.minor, self.patch + 1),
+        }
+    }
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)?;
+        match &self.pre {
+            Some(pre) => write!(f, "-{pre}"),
+            None => Ok(()),
+        }
+    }
+}
+
+impl FromStr for Version {
+    type Err = String;
+
+    /// Parses a version written as MAJOR.MINOR.PATCH[-PRE], optionally preceded by 'v'.
+    fn from_str(input: &str) -> Result<Version, String> {
+        let format_error = || {
+            format!(
+                "'{input}' is not a version in MAJOR.MINOR.PATCH format (Ex: 1.4.2 or 2.0.0-rc.1)."
+            )
+        };
+
+        let version = input.strip_prefix(['v', 'V']).unwrap_or(input);
+        let (numbers, pre) = match version.split_once('-') {
+            Some((numbers, pre)) => (numbers, Some(pre)),
+            None => (version, None),
+        };
+
+        let numbers: Vec<u64> = numbers
+            .split('.')
+            .map(parse_number)
+            .collect::<Option<_>>()
+            .ok_or_else(format_error)?;
+        let [major, minor, patch] = numbers[..] else {
+            return Err(format_error());
+        };
+
+        if let Some(pre) = pre
+            && !pre.split('.').all(is_identifier)
+        {
+            return Err(format!(
+                "'{pre}' is not a valid pre-release. Use letters, digits and '-', separated by '.' (Ex: rc.1)."
+            ));
+        }
+
+        Ok(Version {
+            major,
+            minor,
+            patch,
+            pre: pre.map(str::to_string),
+        })
+    }
+}
+
+impl Ord for Version {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.major, self.minor, self.patch)
+            .cmp(&(other.major, other.minor, other.patch))
+            .then_with(|| match (&self.pre, &other.pre) {
+                (None, None) => Ordering::Equal,
+                (None, Some(_)) => Ordering::Greater,
+                (Some(_), None) => Ordering::Less,
+                (Some(pre), Some(other_pre)) => compare_pre(pre, other_pre),
+            })
+    }
+}
+
+impl PartialOrd for Version {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// # ARGUMENTS #
+/// 'msg' (Option<&str>) - an optional message which will be printed at
+/// the same line as the input prompt. Must be set to Some("...") or None.
+///
+/// 'current' (&Version) - version being bumped.
+///
+/// # DESCRIPTION #
+/// Shows the current version and what the next major, minor and patch versions would be,
+/// and asks the user which one to release ([p]atch is chosen if Enter is pressed). Choosing
+/// [c]ustom prompts for any version greater than the current one (Ex: 2.0.0-rc.1).
+///
+/// Provides an information message on the same line as the prompt if Some("...")
+/// is provided, and just the prompt if None is provided.
+///
+/// # RETURNS #
+/// The new Version chosen by the user.
+///
+/// # EXAMPLES #
+/// ```no_run
+/// use quick_input::{read_version_bump, Version};
+/// let current: Version = env!("CARGO_PKG_VERSION").parse().unwrap();
+/// let next = read_version_bump(Some("Release: "), &current);
+///
+/// println!("Tagging v{next}");
+/// ```
+pub fn read_version_bump(msg: Option<&str>, current: &Version) -> Version {
+    let (major, minor, patch) = (
+        current.bump_major(),
+        current.bump_minor(),
+        current.bump_patch(),
+    );
+
+    outln!("Current version: {current}");
+    outln!("  major  -> {major}");
+    outln!("  minor  -> {minor}");
+    outln!("  patch  -> {patch}");
+
+    let options = [
+        ('a', "major"),
+        ('i', "minor"),
+        ('p', "patch"),
+        ('c', "custom"),
+    ];
+    match read_choice_map_with_default(msg, None, &options, 'p') {
+        'a' => major,
+        'i' => minor,
+        'p' => patch,
+        _ => read_custom_version(current),
+    }
+}
+
+fn read_custom_version(current: &Version) -> Version {
+    let parse = |input: &str| {
+        let version: Version = input.parse()?;
+        match version > *current {
+            true => Ok(version),
+            false => Err(format!("The new version must be greater than {current}.")),
+        }
+    };
+
+    if dry_run::is_dry_run() {
+        let hint = current.bump_patch().to_string();
+        return dry_run::placeholder(Some("New version: "), &[hint], parse);
+    }
+
+    read_until_valid(Some("New version: "), None, parse)
+}
+
+/// Parses a numeric component, which cannot have leading zeros.
+fn parse_number(text: &str) -> Option<u64> {
+    let digits = !text.is_empty() && text.bytes().all(|byte| byte.is_ascii_digit());
+    match digits && (text == "0" || !text.starts_with('0')) {
+        true => text.parse().ok(),
+        false => None,
+    }
+}
+
+fn is_identifier(identifier: &str) -> bool {
+    let numeric = identifier.bytes().all(|byte| byte.is_ascii_digit());
+
+    !identifier.is_empty()
+        && identifier
+            .bytes()
+            .all(|byte| byte.is_ascii_alphanumeric() || byte == b'-')
+        && (!numeric || parse_number(identifier).is_some())
+}
+
+/// Compares two pre-releases identifier by identifier: numbers are compared numerically and
+/// come before text, and a pre-release with fewer identifiers comes first if all are equal.
+fn compare_pre(pre: &str, other: &str) -> Ordering {
+    let mut identifiers = pre.split('.');
+    let mut other_identifiers = other.split('.');
+
+    loop {
+        let ordering = match (identifiers.next(), other_identifiers.next()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(a), Some(b)) => match (a.parse::<u64>(), b.parse::<u64>()) {
+                (Ok(a), Ok(b)) => a.cmp(&b),
+                (Ok(_), Err(_)) => Ordering::Less,
+                (Err(_), Ok(_)) => Ordering::Greater,
+                (Err(_), Err(_)) => a.cmp(b),
+            },
+        };
+
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn version(text: &str) -> Version {
+        text.parse().unwrap()
+    }
+
+    #[test]
+    fn versions_are_parsed_and_ordered() {
+        assert_eq!(version("v1.4.2"), Version::new(1, 4, 2));
+        assert_eq!(version("2.0.0-rc.1").to_string(), "2.0.0-rc.1");
+        for input in [
+            "1.4",
+            "1.4.2.0",
+            "01.4.2",
+            "1.4.2-",
+            "1.4.2-rc..1",
+            "1.4.2+build",
+        ] {
+            assert!(input.parse::<Version>().is_err(), "{input} was accepted");
+        }
+
+        let ordered = [
+            "1.0.0-alpha",
+            "1.0.0-alpha.1",
+            "1.0.0-alpha.beta",
+            "1.0.0-beta.2",
+            "1.0.0-beta.11",
+            "1.0.0-rc.1",
+            "1.0.0",
+            "1.0.1",
+        ];
+        assert!(
+            ordered
+                .windows(2)
+                .all(|pair| version(pair[0]) < version(pair[1]))
+        );
+    }
+
+    #[test]
+    fn bumps_release_pre_releases() {
+        let current = version("1.4.2");
+        assert_eq!(current.bump_major(), version("2.0.0"));
+        assert_eq!(current.bump_minor(), version("1.5.0"));
+        assert_eq!(current.bump_patch(), version("1.4.3"));
+
+        assert_eq!(version("2.0.0-rc.1").bump_major(), version("2.0.0"));
+        assert_eq!(version("1.5.0-beta").bump_minor(), version("1.5.0"));
+        assert_eq!(version("1.5.1-beta").bump_minor(), version("1.6.0"));
+        assert_eq!(version("1.4.3-rc.2").bump_patch(), version("1.4.3"));
+    }
+}
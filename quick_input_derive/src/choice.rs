@@ -0,0 +1,91 @@
+//! Expansion of #[derive(Choice)].
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Fields, LitStr};
+
+/// # Arguments #
+/// 'input' (&DeriveInput) - Item the derive is applied to.
+///
+/// # Description #
+/// Generates the implementation of Choice, or the error explaining why it can't be derived.
+pub(crate) fn expand_choice(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let Data::Enum(data) = &input.data else {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "Choice can only be derived for enums.",
+        ));
+    };
+    if data.variants.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "Choice needs at least one variant.",
+        ));
+    }
+
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &input.generics,
+            "Choice can't be derived for generic enums.",
+        ));
+    }
+
+    let name = &input.ident;
+
+    let mut variants = Vec::new();
+    let mut labels = Vec::new();
+    for variant in &data.variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(syn::Error::new_spanned(
+                variant,
+                "Choice can only be derived for variants without fields.",
+            ));
+        }
+
+        let ident = &variant.ident;
+        variants.push(quote!(#name::#ident));
+        labels.push(label(variant)?.unwrap_or_else(|| ident.to_string()));
+    }
+
+    Ok(quote! {
+        impl ::quick_input::Choice for #name {
+            fn variants() -> &'static [Self] {
+                const VARIANTS: &[#name] = &[#(#variants),*];
+                VARIANTS
+            }
+
+            fn label(&self) -> &str {
+                match self {
+                    #(#variants => #labels,)*
+                }
+            }
+        }
+    })
+}
+
+/// # Arguments #
+/// 'variant' (&syn::Variant) - Variant whose attributes are read.
+///
+/// # Description #
+/// Returns the label set with #[choice(label = "...")], if any.
+fn label(variant: &syn::Variant) -> syn::Result<Option<String>> {
+    let mut label = None;
+
+    for attr in variant
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("choice"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if !meta.path.is_ident("label") {
+                return Err(meta.error("Unknown choice attribute, expected label = \"...\"."));
+            }
+
+            let value: LitStr = meta.value()?.parse()?;
+            label = Some(value.value());
+            Ok(())
+        })?;
+    }
+
+    Ok(label)
+}
@@ -0,0 +1,77 @@
+//! # Quick Input Derive
+//!
+//! Derive macros for quick_input, enabled through its `derive` feature. They should be used
+//! through quick_input (Ex: `use quick_input::{Choice, PromptForm};`) rather than depending
+//! on this crate.
+
+mod choice;
+mod prompt_form;
+
+use proc_macro::TokenStream;
+use syn::{DeriveInput, parse_macro_input};
+
+/// Implements quick_input::Choice for an enum whose variants have no fields.
+///
+/// The label of every variant is its name, unless it is changed with
+/// `#[choice(label = "...")]`. Variants are listed in the order they are declared.
+///
+/// # EXAMPLES #
+/// ```ignore
+/// use quick_input::{Choice, read_choice};
+///
+/// #[derive(Clone, Choice)]
+/// enum Difficulty {
+///     Easy,
+///     Normal,
+///     #[choice(label = "nightmare")]
+///     Hard,
+/// }
+///
+/// let difficulty: Difficulty = read_choice(Some("Difficulty: "), None);
+/// ```
+#[proc_macro_derive(Choice, attributes(choice))]
+pub fn derive_choice(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    choice::expand_choice(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Implements quick_input::PromptForm for a struct with named fields, so `Config::prompt()`
+/// asks for every field in the order they are declared with a quick_input::Prompt.
+///
+/// String fields are read as text and every other field as a value of its type, which must
+/// implement FromStr. Every field accepts the attribute `#[prompt(...)]` with:
+///
+/// - `msg = "..."`: message of the prompt (by default, the name of the field, Ex: "Max size: ").
+/// - `help = "..."`: help shown when the user types '?'.
+/// - `err_msg = "..."`: error message shown after an invalid answer.
+/// - `default = expr`: value used when the user presses Enter.
+/// - `range = expr`: range the value must be in (Ex: `range = 1..=65535`).
+/// - `validate = expr`: validator, a function or closure taking &T and returning
+///   Result<(), String>.
+/// - `hidden`: hides the answer while typed (String fields only).
+///
+/// # EXAMPLES #
+/// ```ignore
+/// use quick_input::PromptForm;
+///
+/// #[derive(PromptForm)]
+/// struct Config {
+///     #[prompt(msg = "Host: ", default = "localhost")]
+///     host: String,
+///     #[prompt(range = 1..=65535, default = 8080)]
+///     port: u32,
+/// }
+///
+/// let config = Config::prompt();
+/// ```
+#[proc_macro_derive(PromptForm, attributes(prompt))]
+pub fn derive_prompt_form(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    prompt_form::expand_prompt_form(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
@@ -0,0 +1,179 @@
+//! Expansion of #[derive(PromptForm)].
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Data, DeriveInput, Expr, Field, Fields, LitStr, Type};
+
+/// Settings of a field, read from its #[prompt(...)] attributes.
+#[derive(Default)]
+struct FieldSettings {
+    msg: Option<LitStr>,
+    help: Option<LitStr>,
+    err_msg: Option<LitStr>,
+    default: Option<Expr>,
+    range: Option<Expr>,
+    validate: Option<Expr>,
+    hidden: bool,
+}
+
+/// # Arguments #
+/// 'input' (&DeriveInput) - Item the derive is applied to.
+///
+/// # Description #
+/// Generates the implementation of PromptForm, or the error explaining why it can't be derived.
+pub(crate) fn expand_prompt_form(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "PromptForm can only be derived for structs.",
+        ));
+    };
+    let Fields::Named(fields) = &data.fields else {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "PromptForm can only be derived for structs with named fields.",
+        ));
+    };
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &input.generics,
+            "PromptForm can't be derived for generic structs.",
+        ));
+    }
+
+    let name = &input.ident;
+    let idents: Vec<_> = fields.named.iter().map(|field| &field.ident).collect();
+    let prompts = fields
+        .named
+        .iter()
+        .map(prompt_field)
+        .collect::<syn::Result<Vec<_>>>()?;
+
+    Ok(quote! {
+        impl ::quick_input::PromptForm for #name {
+            fn prompt() -> Self {
+                #(let #idents = #prompts;)*
+                #name { #(#idents),* }
+            }
+        }
+    })
+}
+
+/// # Arguments #
+/// 'field' (&Field) - Named field of the struct.
+///
+/// # Description #
+/// Generates the expression asking for the field with a Prompt.
+fn prompt_field(field: &Field) -> syn::Result<TokenStream> {
+    let settings = settings(field)?;
+    let ident = field.ident.as_ref().expect("The fields are named.");
+    let ty = &field.ty;
+    let text = is_string(ty);
+
+    let msg = settings
+        .msg
+        .map_or_else(|| default_message(&ident.to_string()), |msg| msg.value());
+    let mut prompt = quote!(::quick_input::Prompt::new(#msg));
+
+    if let Some(help) = settings.help {
+        prompt = quote!(#prompt.help(#help));
+    }
+    if let Some(err_msg) = settings.err_msg {
+        prompt = quote!(#prompt.err_msg(#err_msg));
+    }
+
+    prompt = match text {
+        true => quote!(#prompt.text()),
+        false => quote!(#prompt.value::<#ty>()),
+    };
+
+    if settings.hidden {
+        if !text {
+            return Err(syn::Error::new_spanned(
+                ty,
+                "Only String fields can be hidden.",
+            ));
+        }
+        prompt = quote!(#prompt.hidden());
+    }
+    if let Some(range) = settings.range {
+        if text {
+            return Err(syn::Error::new_spanned(
+                range,
+                "String fields can't have a range.",
+            ));
+        }
+        prompt = quote!(#prompt.range(#range));
+    }
+    if let Some(validate) = settings.validate {
+        prompt = quote!(#prompt.validate(#validate));
+    }
+    if let Some(default) = settings.default {
+        prompt = match text {
+            true => quote!(#prompt.default(::std::string::String::from(#default))),
+            false => quote!(#prompt.default(#default)),
+        };
+    }
+
+    Ok(quote!(#prompt.read()))
+}
+
+/// Reads the #[prompt(...)] attributes of a field.
+fn settings(field: &Field) -> syn::Result<FieldSettings> {
+    let mut settings = FieldSettings::default();
+
+    for attr in field
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("prompt"))
+    {
+        attr.parse_nested_meta(|meta| {
+            let path = &meta.path;
+            if path.is_ident("hidden") {
+                settings.hidden = true;
+            } else if path.is_ident("msg") {
+                settings.msg = Some(meta.value()?.parse()?);
+            } else if path.is_ident("help") {
+                settings.help = Some(meta.value()?.parse()?);
+            } else if path.is_ident("err_msg") {
+                settings.err_msg = Some(meta.value()?.parse()?);
+            } else if path.is_ident("default") {
+                settings.default = Some(meta.value()?.parse()?);
+            } else if path.is_ident("range") {
+                settings.range = Some(meta.value()?.parse()?);
+            } else if path.is_ident("validate") {
+                settings.validate = Some(meta.value()?.parse()?);
+            } else {
+                return Err(meta.error(
+                    "Unknown prompt attribute, expected msg, help, err_msg, default, range, validate or hidden.",
+                ));
+            }
+            Ok(())
+        })?;
+    }
+
+    Ok(settings)
+}
+
+/// Whether the type is written as String, in which case the field is read as text.
+fn is_string(ty: &Type) -> bool {
+    match ty {
+        Type::Path(path) => path
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "String" && segment.arguments.is_empty()),
+        _ => false,
+    }
+}
+
+/// Builds the message of a field without one (Ex: "max_size" becomes "Max size: ").
+fn default_message(field: &str) -> String {
+    let words = field.trim_start_matches("r#").replace('_', " ");
+    let mut chars = words.trim().chars();
+
+    match chars.next() {
+        Some(first) => format!("{}{}: ", first.to_uppercase(), chars.as_str()),
+        None => String::from(": "),
+    }
+}